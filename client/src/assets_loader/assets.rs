@@ -5,9 +5,6 @@ use crate::shaders::DetailedMaterialExtension;
 
 #[derive(Resource, AssetCollection, Default, Debug)]
 pub struct ModelAssets {
-    #[asset(path = "models/ships/bomber_01.glb#Scene0")]
-    pub ship_bomber_01: Handle<Scene>,
-
     #[asset(path = "models/ships/station_01.glb#Scene0")]
     pub ship_station_01: Handle<Scene>,
     #[asset(path = "models/ships/station_02.glb#Scene0")]
@@ -31,12 +28,65 @@ pub struct ModelAssets {
     pub asteroid_04: Handle<Scene>,
     #[asset(path = "models/env/astroid_05.glb#Scene0")]
     pub asteroid_05: Handle<Scene>,
+    #[asset(path = "models/env/asteroid_01.glb#Scene0")]
+    pub asteroid_rock_01: Handle<Scene>,
+    #[asset(path = "models/env/asteroid_02.glb#Scene0")]
+    pub asteroid_rock_02: Handle<Scene>,
+    #[asset(path = "models/env/asteroid_03.glb#Scene0")]
+    pub asteroid_rock_03: Handle<Scene>,
+    #[asset(path = "models/env/asteroid_04.glb#Scene0")]
+    pub asteroid_rock_04: Handle<Scene>,
+    #[asset(path = "models/env/asteroid_05.glb#Scene0")]
+    pub asteroid_rock_05: Handle<Scene>,
+    #[asset(path = "models/env/asteroid_06.glb#Scene0")]
+    pub asteroid_rock_06: Handle<Scene>,
+    #[asset(path = "models/env/asteroid_07.glb#Scene0")]
+    pub asteroid_rock_07: Handle<Scene>,
+    #[asset(path = "models/env/astroid_holes_01.glb#Scene0")]
+    pub asteroid_holes_01: Handle<Scene>,
+    #[asset(path = "models/env/astroid_holes_02.glb#Scene0")]
+    pub asteroid_holes_02: Handle<Scene>,
+    #[asset(path = "models/env/astroid_holes_03.glb#Scene0")]
+    pub asteroid_holes_03: Handle<Scene>,
+    #[asset(path = "models/env/astroid_holes_04.glb#Scene0")]
+    pub asteroid_holes_04: Handle<Scene>,
+    #[asset(path = "models/env/astroid_holes_05.glb#Scene0")]
+    pub asteroid_holes_05: Handle<Scene>,
+    #[asset(path = "models/env/astroid_large_01.glb#Scene0")]
+    pub asteroid_large_01: Handle<Scene>,
+    #[asset(path = "models/env/astroid_large_02.glb#Scene0")]
+    pub asteroid_large_02: Handle<Scene>,
+    #[asset(path = "models/env/astroid_large_holes_01.glb#Scene0")]
+    pub asteroid_large_holes_01: Handle<Scene>,
+    #[asset(path = "models/env/astroid_large_holes_02.glb#Scene0")]
+    pub asteroid_large_holes_02: Handle<Scene>,
+
+    #[asset(path = "models/env/debris_shell_01.glb#Scene0")]
+    pub debris_shell_01: Handle<Scene>,
+    #[asset(path = "models/env/debris_shell_02.glb#Scene0")]
+    pub debris_shell_02: Handle<Scene>,
+    #[asset(path = "models/env/debris_shell_03.glb#Scene0")]
+    pub debris_shell_03: Handle<Scene>,
+    #[asset(path = "models/env/debris_shell_04.glb#Scene0")]
+    pub debris_shell_04: Handle<Scene>,
+    #[asset(path = "models/env/debris_shell_05.glb#Scene0")]
+    pub debris_shell_05: Handle<Scene>,
+    #[asset(path = "models/env/debris_shell_06.glb#Scene0")]
+    pub debris_shell_06: Handle<Scene>,
+    #[asset(path = "models/env/debris_shell_07.glb#Scene0")]
+    pub debris_shell_07: Handle<Scene>,
+    #[asset(path = "models/env/debris_shell_08.glb#Scene0")]
+    pub debris_shell_08: Handle<Scene>,
+    #[asset(path = "models/env/debris_structure_01.glb#Scene0")]
+    pub debris_structure_01: Handle<Scene>,
+    #[asset(path = "models/env/debris_structure_02.glb#Scene0")]
+    pub debris_structure_02: Handle<Scene>,
+    #[asset(path = "models/env/debris_structure_03.glb#Scene0")]
+    pub debris_structure_03: Handle<Scene>,
 }
 
 #[derive(Resource, AssetCollection, Default, Debug)]
 pub struct CollisionAssets {
-    #[asset(path = "colliders/ships/bomber_01_collision.glb#Mesh0/Primitive0")]
-    pub ship_bomber_01: Handle<Mesh>,
     #[asset(path = "colliders/ships/station_01_collision.glb#Mesh0/Primitive0")]
     pub ship_station_01: Handle<Mesh>,
     #[asset(path = "colliders/ships/station_02_collision.glb#Mesh0/Primitive0")]
@@ -19,6 +19,10 @@ pub struct Asteroid {
     pub rot_w: f32,
     pub asteroid_type: u8,
     pub scale: f32,
+    pub remaining: u32,
+    pub sector_x: i32,
+    pub sector_y: i32,
+    pub sector_z: i32,
 }
 
 impl __sdk::InModule for Asteroid {
@@ -6,72 +6,409 @@
 #![allow(unused, clippy::all)]
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
+pub mod admin_audit_table;
+pub mod admin_audit_type;
+pub mod admin_ban_reducer;
+pub mod admin_broadcast_reducer;
+pub mod admin_delete_asteroid_reducer;
+pub mod admin_delete_ship_reducer;
+pub mod admin_delete_station_reducer;
+pub mod admin_grant_reducer;
+pub mod admin_kick_reducer;
+pub mod admin_mute_reducer;
+pub mod admin_revoke_reducer;
+pub mod admin_set_rate_limit_reducer;
+pub mod admin_set_station_rotation_speed_reducer;
+pub mod admin_spawn_asteroid_reducer;
+pub mod admin_spawn_ship_reducer;
+pub mod admin_spawn_station_reducer;
+pub mod admin_table;
+pub mod admin_teleport_player_reducer;
+pub mod admin_type;
+pub mod admin_unban_reducer;
+pub mod admin_unmute_reducer;
+pub mod asteroid_resource_table;
+pub mod asteroid_resource_type;
+pub mod asteroid_respawn_table;
+pub mod asteroid_respawn_type;
 pub mod asteroid_table;
 pub mod asteroid_type;
+pub mod ban_table;
+pub mod ban_type;
+pub mod buy_reducer;
+pub mod cargo_table;
+pub mod cargo_type;
+pub mod chat_channel_type;
+pub mod chat_cleanup_table;
+pub mod chat_cleanup_type;
+pub mod chat_message_table;
+pub mod chat_message_type;
+pub mod damage_type_type;
+pub mod docking_bay_table;
+pub mod docking_bay_type;
+pub mod faction_create_reducer;
+pub mod faction_decline_invite_reducer;
+pub mod faction_invite_player_reducer;
+pub mod faction_invite_table;
+pub mod faction_invite_type;
+pub mod faction_join_reducer;
+pub mod faction_kick_reducer;
+pub mod faction_leave_reducer;
+pub mod faction_member_table;
+pub mod faction_member_type;
+pub mod faction_rank_type;
+pub mod faction_set_rank_reducer;
+pub mod faction_table;
+pub mod faction_transfer_ship_reducer;
+pub mod faction_type;
+pub mod fire_weapon_reducer;
+pub mod flight_controls_type;
+pub mod hardpoint_table;
+pub mod hardpoint_type;
+pub mod home_station_table;
+pub mod home_station_type;
+pub mod item_type_table;
+pub mod item_type_type;
+pub mod jettison_cargo_reducer;
+pub mod launch_missile_reducer;
+pub mod market_price_history_table;
+pub mod market_price_history_type;
+pub mod market_table;
+pub mod market_type;
+pub mod market_update_table;
+pub mod market_update_type;
+pub mod mine_asteroid_reducer;
+pub mod missile_table;
+pub mod missile_type;
+pub mod missile_type_table;
+pub mod missile_type_type;
+pub mod missile_update_table;
+pub mod missile_update_type;
+pub mod mute_table;
+pub mod mute_type;
 pub mod on_connected_reducer;
 pub mod on_disconnected_reducer;
+pub mod parked_ships_despawn_table;
+pub mod parked_ships_despawn_type;
 pub mod player_enter_ship_reducer;
+pub mod player_grant_ship_access_reducer;
 pub mod player_leave_ship_reducer;
 pub mod player_location_table;
 pub mod player_location_type;
+pub mod player_move_reducer;
 pub mod player_move_ship_reducer;
 pub mod player_ready_reducer;
+pub mod player_revoke_ship_access_reducer;
+pub mod player_set_flight_controls_reducer;
+pub mod player_set_flight_mode_reducer;
 pub mod player_spawn_ship_reducer;
 pub mod player_table;
 pub mod player_type;
+pub mod rate_limit_bucket_table;
+pub mod rate_limit_bucket_type;
+pub mod rate_limit_table;
+pub mod rate_limit_type;
+pub mod refuel_ship_reducer;
+pub mod rejected_call_table;
+pub mod rejected_call_type;
+pub mod repair_ship_reducer;
+pub mod request_docking_reducer;
+pub mod respawn_at_home_reducer;
+pub mod rotation_axis_type;
+pub mod sell_reducer;
+pub mod send_message_reducer;
+pub mod set_home_station_reducer;
+pub mod ship_access_table;
+pub mod ship_access_type;
+pub mod ship_docking_table;
+pub mod ship_docking_type;
+pub mod ship_flight_table;
+pub mod ship_flight_type;
+pub mod ship_flight_update_table;
+pub mod ship_flight_update_type;
 pub mod ship_location_table;
 pub mod ship_location_type;
+pub mod ship_mining_table;
+pub mod ship_mining_type;
 pub mod ship_pilot_table;
 pub mod ship_pilot_type;
+pub mod ship_status_table;
+pub mod ship_status_type;
 pub mod ship_table;
 pub mod ship_type;
 pub mod ship_type_table;
 pub mod ship_type_type;
+pub mod ships_despawn_parked_reducer;
+pub mod station_rotation_table;
+pub mod station_rotation_type;
 pub mod station_rotation_update_table;
 pub mod station_rotation_update_type;
 pub mod station_table;
 pub mod station_type;
+pub mod transfer_cargo_reducer;
+pub mod undock_reducer;
+pub mod wallet_table;
+pub mod wallet_type;
+pub mod weapon_fire_table;
+pub mod weapon_fire_type;
+pub mod weapon_type_table;
+pub mod weapon_type_type;
+pub mod world_clear_chat_reducer;
+pub mod world_clear_wrecks_reducer;
+pub mod world_respawn_asteroid_reducer;
+pub mod world_update_markets_reducer;
+pub mod world_update_missiles_reducer;
+pub mod world_update_ship_flights_reducer;
 pub mod world_update_stations_rotation_reducer;
-
+pub mod wreck_cleanup_table;
+pub mod wreck_cleanup_type;
+pub mod wreck_table;
+pub mod wreck_type;
+
+pub use admin_audit_table::*;
+pub use admin_audit_type::AdminAudit;
+pub use admin_ban_reducer::{admin_ban, set_flags_for_admin_ban, AdminBanCallbackId};
+pub use admin_broadcast_reducer::{
+    admin_broadcast, set_flags_for_admin_broadcast, AdminBroadcastCallbackId,
+};
+pub use admin_delete_asteroid_reducer::{
+    admin_delete_asteroid, set_flags_for_admin_delete_asteroid, AdminDeleteAsteroidCallbackId,
+};
+pub use admin_delete_ship_reducer::{
+    admin_delete_ship, set_flags_for_admin_delete_ship, AdminDeleteShipCallbackId,
+};
+pub use admin_delete_station_reducer::{
+    admin_delete_station, set_flags_for_admin_delete_station, AdminDeleteStationCallbackId,
+};
+pub use admin_grant_reducer::{admin_grant, set_flags_for_admin_grant, AdminGrantCallbackId};
+pub use admin_kick_reducer::{admin_kick, set_flags_for_admin_kick, AdminKickCallbackId};
+pub use admin_mute_reducer::{admin_mute, set_flags_for_admin_mute, AdminMuteCallbackId};
+pub use admin_revoke_reducer::{admin_revoke, set_flags_for_admin_revoke, AdminRevokeCallbackId};
+pub use admin_set_rate_limit_reducer::{
+    admin_set_rate_limit, set_flags_for_admin_set_rate_limit, AdminSetRateLimitCallbackId,
+};
+pub use admin_set_station_rotation_speed_reducer::{
+    admin_set_station_rotation_speed, set_flags_for_admin_set_station_rotation_speed,
+    AdminSetStationRotationSpeedCallbackId,
+};
+pub use admin_spawn_asteroid_reducer::{
+    admin_spawn_asteroid, set_flags_for_admin_spawn_asteroid, AdminSpawnAsteroidCallbackId,
+};
+pub use admin_spawn_ship_reducer::{
+    admin_spawn_ship, set_flags_for_admin_spawn_ship, AdminSpawnShipCallbackId,
+};
+pub use admin_spawn_station_reducer::{
+    admin_spawn_station, set_flags_for_admin_spawn_station, AdminSpawnStationCallbackId,
+};
+pub use admin_table::*;
+pub use admin_teleport_player_reducer::{
+    admin_teleport_player, set_flags_for_admin_teleport_player, AdminTeleportPlayerCallbackId,
+};
+pub use admin_type::Admin;
+pub use admin_unban_reducer::{admin_unban, set_flags_for_admin_unban, AdminUnbanCallbackId};
+pub use admin_unmute_reducer::{admin_unmute, set_flags_for_admin_unmute, AdminUnmuteCallbackId};
+pub use asteroid_resource_table::*;
+pub use asteroid_resource_type::AsteroidResource;
+pub use asteroid_respawn_table::*;
+pub use asteroid_respawn_type::AsteroidRespawn;
 pub use asteroid_table::*;
 pub use asteroid_type::Asteroid;
+pub use ban_table::*;
+pub use ban_type::Ban;
+pub use buy_reducer::{buy, set_flags_for_buy, BuyCallbackId};
+pub use cargo_table::*;
+pub use cargo_type::Cargo;
+pub use chat_channel_type::ChatChannel;
+pub use chat_cleanup_table::*;
+pub use chat_cleanup_type::ChatCleanup;
+pub use chat_message_table::*;
+pub use chat_message_type::ChatMessage;
+pub use damage_type_type::DamageType;
+pub use docking_bay_table::*;
+pub use docking_bay_type::DockingBay;
+pub use faction_create_reducer::{
+    faction_create, set_flags_for_faction_create, FactionCreateCallbackId,
+};
+pub use faction_decline_invite_reducer::{
+    faction_decline_invite, set_flags_for_faction_decline_invite, FactionDeclineInviteCallbackId,
+};
+pub use faction_invite_player_reducer::{
+    faction_invite_player, set_flags_for_faction_invite_player, FactionInvitePlayerCallbackId,
+};
+pub use faction_invite_table::*;
+pub use faction_invite_type::FactionInvite;
+pub use faction_join_reducer::{faction_join, set_flags_for_faction_join, FactionJoinCallbackId};
+pub use faction_kick_reducer::{faction_kick, set_flags_for_faction_kick, FactionKickCallbackId};
+pub use faction_leave_reducer::{
+    faction_leave, set_flags_for_faction_leave, FactionLeaveCallbackId,
+};
+pub use faction_member_table::*;
+pub use faction_member_type::FactionMember;
+pub use faction_rank_type::FactionRank;
+pub use faction_set_rank_reducer::{
+    faction_set_rank, set_flags_for_faction_set_rank, FactionSetRankCallbackId,
+};
+pub use faction_table::*;
+pub use faction_transfer_ship_reducer::{
+    faction_transfer_ship, set_flags_for_faction_transfer_ship, FactionTransferShipCallbackId,
+};
+pub use faction_type::Faction;
+pub use fire_weapon_reducer::{fire_weapon, set_flags_for_fire_weapon, FireWeaponCallbackId};
+pub use flight_controls_type::FlightControls;
+pub use hardpoint_table::*;
+pub use hardpoint_type::Hardpoint;
+pub use home_station_table::*;
+pub use home_station_type::HomeStation;
+pub use item_type_table::*;
+pub use item_type_type::ItemType;
+pub use jettison_cargo_reducer::{
+    jettison_cargo, set_flags_for_jettison_cargo, JettisonCargoCallbackId,
+};
+pub use launch_missile_reducer::{
+    launch_missile, set_flags_for_launch_missile, LaunchMissileCallbackId,
+};
+pub use market_price_history_table::*;
+pub use market_price_history_type::MarketPriceHistory;
+pub use market_table::*;
+pub use market_type::Market;
+pub use market_update_table::*;
+pub use market_update_type::MarketUpdate;
+pub use mine_asteroid_reducer::{
+    mine_asteroid, set_flags_for_mine_asteroid, MineAsteroidCallbackId,
+};
+pub use missile_table::*;
+pub use missile_type::Missile;
+pub use missile_type_table::*;
+pub use missile_type_type::MissileType;
+pub use missile_update_table::*;
+pub use missile_update_type::MissileUpdate;
+pub use mute_table::*;
+pub use mute_type::Mute;
 pub use on_connected_reducer::{on_connected, set_flags_for_on_connected, OnConnectedCallbackId};
 pub use on_disconnected_reducer::{
     on_disconnected, set_flags_for_on_disconnected, OnDisconnectedCallbackId,
 };
+pub use parked_ships_despawn_table::*;
+pub use parked_ships_despawn_type::ParkedShipsDespawn;
 pub use player_enter_ship_reducer::{
     player_enter_ship, set_flags_for_player_enter_ship, PlayerEnterShipCallbackId,
 };
+pub use player_grant_ship_access_reducer::{
+    player_grant_ship_access, set_flags_for_player_grant_ship_access,
+    PlayerGrantShipAccessCallbackId,
+};
 pub use player_leave_ship_reducer::{
     player_leave_ship, set_flags_for_player_leave_ship, PlayerLeaveShipCallbackId,
 };
 pub use player_location_table::*;
 pub use player_location_type::PlayerLocation;
+pub use player_move_reducer::{player_move, set_flags_for_player_move, PlayerMoveCallbackId};
 pub use player_move_ship_reducer::{
     player_move_ship, set_flags_for_player_move_ship, PlayerMoveShipCallbackId,
 };
 pub use player_ready_reducer::{player_ready, set_flags_for_player_ready, PlayerReadyCallbackId};
+pub use player_revoke_ship_access_reducer::{
+    player_revoke_ship_access, set_flags_for_player_revoke_ship_access,
+    PlayerRevokeShipAccessCallbackId,
+};
+pub use player_set_flight_controls_reducer::{
+    player_set_flight_controls, set_flags_for_player_set_flight_controls,
+    PlayerSetFlightControlsCallbackId,
+};
+pub use player_set_flight_mode_reducer::{
+    player_set_flight_mode, set_flags_for_player_set_flight_mode, PlayerSetFlightModeCallbackId,
+};
 pub use player_spawn_ship_reducer::{
     player_spawn_ship, set_flags_for_player_spawn_ship, PlayerSpawnShipCallbackId,
 };
 pub use player_table::*;
 pub use player_type::Player;
+pub use rate_limit_bucket_table::*;
+pub use rate_limit_bucket_type::RateLimitBucket;
+pub use rate_limit_table::*;
+pub use rate_limit_type::RateLimit;
+pub use refuel_ship_reducer::{refuel_ship, set_flags_for_refuel_ship, RefuelShipCallbackId};
+pub use rejected_call_table::*;
+pub use rejected_call_type::RejectedCall;
+pub use repair_ship_reducer::{repair_ship, set_flags_for_repair_ship, RepairShipCallbackId};
+pub use request_docking_reducer::{
+    request_docking, set_flags_for_request_docking, RequestDockingCallbackId,
+};
+pub use respawn_at_home_reducer::{
+    respawn_at_home, set_flags_for_respawn_at_home, RespawnAtHomeCallbackId,
+};
+pub use rotation_axis_type::RotationAxis;
+pub use sell_reducer::{sell, set_flags_for_sell, SellCallbackId};
+pub use send_message_reducer::{send_message, set_flags_for_send_message, SendMessageCallbackId};
+pub use set_home_station_reducer::{
+    set_flags_for_set_home_station, set_home_station, SetHomeStationCallbackId,
+};
+pub use ship_access_table::*;
+pub use ship_access_type::ShipAccess;
+pub use ship_docking_table::*;
+pub use ship_docking_type::ShipDocking;
+pub use ship_flight_table::*;
+pub use ship_flight_type::ShipFlight;
+pub use ship_flight_update_table::*;
+pub use ship_flight_update_type::ShipFlightUpdate;
 pub use ship_location_table::*;
 pub use ship_location_type::ShipLocation;
+pub use ship_mining_table::*;
+pub use ship_mining_type::ShipMining;
 pub use ship_pilot_table::*;
 pub use ship_pilot_type::ShipPilot;
+pub use ship_status_table::*;
+pub use ship_status_type::ShipStatus;
 pub use ship_table::*;
 pub use ship_type::Ship;
 pub use ship_type_table::*;
 pub use ship_type_type::ShipType;
+pub use ships_despawn_parked_reducer::{
+    set_flags_for_ships_despawn_parked, ships_despawn_parked, ShipsDespawnParkedCallbackId,
+};
+pub use station_rotation_table::*;
+pub use station_rotation_type::StationRotation;
 pub use station_rotation_update_table::*;
 pub use station_rotation_update_type::StationRotationUpdate;
 pub use station_table::*;
 pub use station_type::Station;
+pub use transfer_cargo_reducer::{
+    set_flags_for_transfer_cargo, transfer_cargo, TransferCargoCallbackId,
+};
+pub use undock_reducer::{set_flags_for_undock, undock, UndockCallbackId};
+pub use wallet_table::*;
+pub use wallet_type::Wallet;
+pub use weapon_fire_table::*;
+pub use weapon_fire_type::WeaponFire;
+pub use weapon_type_table::*;
+pub use weapon_type_type::WeaponType;
+pub use world_clear_chat_reducer::{
+    set_flags_for_world_clear_chat, world_clear_chat, WorldClearChatCallbackId,
+};
+pub use world_clear_wrecks_reducer::{
+    set_flags_for_world_clear_wrecks, world_clear_wrecks, WorldClearWrecksCallbackId,
+};
+pub use world_respawn_asteroid_reducer::{
+    set_flags_for_world_respawn_asteroid, world_respawn_asteroid, WorldRespawnAsteroidCallbackId,
+};
+pub use world_update_markets_reducer::{
+    set_flags_for_world_update_markets, world_update_markets, WorldUpdateMarketsCallbackId,
+};
+pub use world_update_missiles_reducer::{
+    set_flags_for_world_update_missiles, world_update_missiles, WorldUpdateMissilesCallbackId,
+};
+pub use world_update_ship_flights_reducer::{
+    set_flags_for_world_update_ship_flights, world_update_ship_flights,
+    WorldUpdateShipFlightsCallbackId,
+};
 pub use world_update_stations_rotation_reducer::{
     set_flags_for_world_update_stations_rotation, world_update_stations_rotation,
     WorldUpdateStationsRotationCallbackId,
 };
+pub use wreck_cleanup_table::*;
+pub use wreck_cleanup_type::WreckCleanup;
+pub use wreck_table::*;
+pub use wreck_type::Wreck;
 
 #[derive(Clone, PartialEq, Debug)]
 
@@ -81,12 +418,140 @@ pub use world_update_stations_rotation_reducer::{
 /// to indicate which reducer caused the event.
 
 pub enum Reducer {
+    AdminBan {
+        player_id: __sdk::Identity,
+        reason: String,
+        duration_secs: Option<u64>,
+    },
+    AdminBroadcast {
+        text: String,
+    },
+    AdminDeleteAsteroid {
+        asteroid_id: u64,
+    },
+    AdminDeleteShip {
+        ship_id: u64,
+    },
+    AdminDeleteStation {
+        station_id: u64,
+    },
+    AdminGrant {
+        identity: __sdk::Identity,
+    },
+    AdminKick {
+        player_id: __sdk::Identity,
+        reason: String,
+    },
+    AdminMute {
+        player_id: __sdk::Identity,
+        duration_secs: Option<u64>,
+    },
+    AdminRevoke {
+        identity: __sdk::Identity,
+    },
+    AdminSetRateLimit {
+        reducer: String,
+        capacity: f32,
+        refill_rate: f32,
+    },
+    AdminSetStationRotationSpeed {
+        station_id: u64,
+        speed: f32,
+    },
+    AdminSpawnAsteroid {
+        x: f32,
+        y: f32,
+        z: f32,
+        asteroid_type: u8,
+        scale: f32,
+    },
+    AdminSpawnShip {
+        ship_type_id: u64,
+        owner_id: __sdk::Identity,
+        x: f32,
+        y: f32,
+        z: f32,
+    },
+    AdminSpawnStation {
+        name: String,
+        x: f32,
+        y: f32,
+        z: f32,
+    },
+    AdminTeleportPlayer {
+        player_id: __sdk::Identity,
+        x: f32,
+        y: f32,
+        z: f32,
+    },
+    AdminUnban {
+        player_id: __sdk::Identity,
+    },
+    AdminUnmute {
+        player_id: __sdk::Identity,
+    },
+    Buy {
+        station_id: u64,
+        item_type_id: u64,
+        quantity: u32,
+    },
+    FactionCreate {
+        name: String,
+        tag: String,
+    },
+    FactionDeclineInvite {
+        faction_id: __sdk::Identity,
+    },
+    FactionInvitePlayer {
+        player_id: __sdk::Identity,
+    },
+    FactionJoin {
+        faction_id: __sdk::Identity,
+    },
+    FactionKick {
+        player_id: __sdk::Identity,
+    },
+    FactionLeave,
+    FactionSetRank {
+        player_id: __sdk::Identity,
+        rank: FactionRank,
+    },
+    FactionTransferShip {
+        ship_id: u64,
+    },
+    FireWeapon {
+        hardpoint_id: u64,
+    },
+    JettisonCargo {
+        item_type_id: u64,
+        quantity: u32,
+    },
+    LaunchMissile {
+        hardpoint_id: u64,
+        target_ship_id: u64,
+    },
+    MineAsteroid {
+        asteroid_id: u64,
+    },
     OnConnected,
     OnDisconnected,
     PlayerEnterShip {
         ship_id: u64,
     },
+    PlayerGrantShipAccess {
+        ship_id: u64,
+        player_id: __sdk::Identity,
+    },
     PlayerLeaveShip,
+    PlayerMove {
+        x: f32,
+        y: f32,
+        z: f32,
+        rot_x: f32,
+        rot_y: f32,
+        rot_z: f32,
+        rot_w: f32,
+    },
     PlayerMoveShip {
         x: f32,
         y: f32,
@@ -97,7 +562,18 @@ pub enum Reducer {
         rot_w: f32,
     },
     PlayerReady,
+    PlayerRevokeShipAccess {
+        ship_id: u64,
+        player_id: __sdk::Identity,
+    },
+    PlayerSetFlightControls {
+        controls: FlightControls,
+    },
+    PlayerSetFlightMode {
+        server_flown: bool,
+    },
     PlayerSpawnShip {
+        ship_type_id: u64,
         x: f32,
         y: f32,
         z: f32,
@@ -106,6 +582,50 @@ pub enum Reducer {
         rot_z: f32,
         rot_w: f32,
     },
+    RefuelShip,
+    RepairShip,
+    RequestDocking {
+        station_id: u64,
+    },
+    RespawnAtHome,
+    Sell {
+        station_id: u64,
+        item_type_id: u64,
+        quantity: u32,
+    },
+    SendMessage {
+        channel: ChatChannel,
+        recipient_id: Option<__sdk::Identity>,
+        text: String,
+    },
+    SetHomeStation,
+    ShipsDespawnParked {
+        despawn: ParkedShipsDespawn,
+    },
+    TransferCargo {
+        target_ship_id: u64,
+        item_type_id: u64,
+        quantity: u32,
+    },
+    Undock,
+    WorldClearChat {
+        cleanup: ChatCleanup,
+    },
+    WorldClearWrecks {
+        cleanup: WreckCleanup,
+    },
+    WorldRespawnAsteroid {
+        respawn: AsteroidRespawn,
+    },
+    WorldUpdateMarkets {
+        update: MarketUpdate,
+    },
+    WorldUpdateMissiles {
+        update: MissileUpdate,
+    },
+    WorldUpdateShipFlights {
+        update: ShipFlightUpdate,
+    },
     WorldUpdateStationsRotation {
         update: StationRotationUpdate,
     },
@@ -118,13 +638,64 @@ impl __sdk::InModule for Reducer {
 impl __sdk::Reducer for Reducer {
     fn reducer_name(&self) -> &'static str {
         match self {
+            Reducer::AdminBan { .. } => "admin_ban",
+            Reducer::AdminBroadcast { .. } => "admin_broadcast",
+            Reducer::AdminDeleteAsteroid { .. } => "admin_delete_asteroid",
+            Reducer::AdminDeleteShip { .. } => "admin_delete_ship",
+            Reducer::AdminDeleteStation { .. } => "admin_delete_station",
+            Reducer::AdminGrant { .. } => "admin_grant",
+            Reducer::AdminKick { .. } => "admin_kick",
+            Reducer::AdminMute { .. } => "admin_mute",
+            Reducer::AdminRevoke { .. } => "admin_revoke",
+            Reducer::AdminSetRateLimit { .. } => "admin_set_rate_limit",
+            Reducer::AdminSetStationRotationSpeed { .. } => "admin_set_station_rotation_speed",
+            Reducer::AdminSpawnAsteroid { .. } => "admin_spawn_asteroid",
+            Reducer::AdminSpawnShip { .. } => "admin_spawn_ship",
+            Reducer::AdminSpawnStation { .. } => "admin_spawn_station",
+            Reducer::AdminTeleportPlayer { .. } => "admin_teleport_player",
+            Reducer::AdminUnban { .. } => "admin_unban",
+            Reducer::AdminUnmute { .. } => "admin_unmute",
+            Reducer::Buy { .. } => "buy",
+            Reducer::FactionCreate { .. } => "faction_create",
+            Reducer::FactionDeclineInvite { .. } => "faction_decline_invite",
+            Reducer::FactionInvitePlayer { .. } => "faction_invite_player",
+            Reducer::FactionJoin { .. } => "faction_join",
+            Reducer::FactionKick { .. } => "faction_kick",
+            Reducer::FactionLeave => "faction_leave",
+            Reducer::FactionSetRank { .. } => "faction_set_rank",
+            Reducer::FactionTransferShip { .. } => "faction_transfer_ship",
+            Reducer::FireWeapon { .. } => "fire_weapon",
+            Reducer::JettisonCargo { .. } => "jettison_cargo",
+            Reducer::LaunchMissile { .. } => "launch_missile",
+            Reducer::MineAsteroid { .. } => "mine_asteroid",
             Reducer::OnConnected => "on_connected",
             Reducer::OnDisconnected => "on_disconnected",
             Reducer::PlayerEnterShip { .. } => "player_enter_ship",
+            Reducer::PlayerGrantShipAccess { .. } => "player_grant_ship_access",
             Reducer::PlayerLeaveShip => "player_leave_ship",
+            Reducer::PlayerMove { .. } => "player_move",
             Reducer::PlayerMoveShip { .. } => "player_move_ship",
             Reducer::PlayerReady => "player_ready",
+            Reducer::PlayerRevokeShipAccess { .. } => "player_revoke_ship_access",
+            Reducer::PlayerSetFlightControls { .. } => "player_set_flight_controls",
+            Reducer::PlayerSetFlightMode { .. } => "player_set_flight_mode",
             Reducer::PlayerSpawnShip { .. } => "player_spawn_ship",
+            Reducer::RefuelShip => "refuel_ship",
+            Reducer::RepairShip => "repair_ship",
+            Reducer::RequestDocking { .. } => "request_docking",
+            Reducer::RespawnAtHome => "respawn_at_home",
+            Reducer::Sell { .. } => "sell",
+            Reducer::SendMessage { .. } => "send_message",
+            Reducer::SetHomeStation => "set_home_station",
+            Reducer::ShipsDespawnParked { .. } => "ships_despawn_parked",
+            Reducer::TransferCargo { .. } => "transfer_cargo",
+            Reducer::Undock => "undock",
+            Reducer::WorldClearChat { .. } => "world_clear_chat",
+            Reducer::WorldClearWrecks { .. } => "world_clear_wrecks",
+            Reducer::WorldRespawnAsteroid { .. } => "world_respawn_asteroid",
+            Reducer::WorldUpdateMarkets { .. } => "world_update_markets",
+            Reducer::WorldUpdateMissiles { .. } => "world_update_missiles",
+            Reducer::WorldUpdateShipFlights { .. } => "world_update_ship_flights",
             Reducer::WorldUpdateStationsRotation { .. } => "world_update_stations_rotation",
         }
     }
@@ -133,6 +704,157 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
     type Error = __sdk::Error;
     fn try_from(value: __ws::ReducerCallInfo<__ws::BsatnFormat>) -> __sdk::Result<Self> {
         match &value.reducer_name[..] {
+            "admin_ban" => Ok(
+                __sdk::parse_reducer_args::<admin_ban_reducer::AdminBanArgs>(
+                    "admin_ban",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "admin_broadcast" => Ok(__sdk::parse_reducer_args::<
+                admin_broadcast_reducer::AdminBroadcastArgs,
+            >("admin_broadcast", &value.args)?
+            .into()),
+            "admin_delete_asteroid" => Ok(__sdk::parse_reducer_args::<
+                admin_delete_asteroid_reducer::AdminDeleteAsteroidArgs,
+            >("admin_delete_asteroid", &value.args)?
+            .into()),
+            "admin_delete_ship" => Ok(__sdk::parse_reducer_args::<
+                admin_delete_ship_reducer::AdminDeleteShipArgs,
+            >("admin_delete_ship", &value.args)?
+            .into()),
+            "admin_delete_station" => Ok(__sdk::parse_reducer_args::<
+                admin_delete_station_reducer::AdminDeleteStationArgs,
+            >("admin_delete_station", &value.args)?
+            .into()),
+            "admin_grant" => Ok(
+                __sdk::parse_reducer_args::<admin_grant_reducer::AdminGrantArgs>(
+                    "admin_grant",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "admin_kick" => Ok(
+                __sdk::parse_reducer_args::<admin_kick_reducer::AdminKickArgs>(
+                    "admin_kick",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "admin_mute" => Ok(
+                __sdk::parse_reducer_args::<admin_mute_reducer::AdminMuteArgs>(
+                    "admin_mute",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "admin_revoke" => Ok(
+                __sdk::parse_reducer_args::<admin_revoke_reducer::AdminRevokeArgs>(
+                    "admin_revoke",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "admin_set_rate_limit" => Ok(__sdk::parse_reducer_args::<
+                admin_set_rate_limit_reducer::AdminSetRateLimitArgs,
+            >("admin_set_rate_limit", &value.args)?
+            .into()),
+            "admin_set_station_rotation_speed" => {
+                Ok(__sdk::parse_reducer_args::<
+                    admin_set_station_rotation_speed_reducer::AdminSetStationRotationSpeedArgs,
+                >("admin_set_station_rotation_speed", &value.args)?
+                .into())
+            }
+            "admin_spawn_asteroid" => Ok(__sdk::parse_reducer_args::<
+                admin_spawn_asteroid_reducer::AdminSpawnAsteroidArgs,
+            >("admin_spawn_asteroid", &value.args)?
+            .into()),
+            "admin_spawn_ship" => Ok(__sdk::parse_reducer_args::<
+                admin_spawn_ship_reducer::AdminSpawnShipArgs,
+            >("admin_spawn_ship", &value.args)?
+            .into()),
+            "admin_spawn_station" => Ok(__sdk::parse_reducer_args::<
+                admin_spawn_station_reducer::AdminSpawnStationArgs,
+            >("admin_spawn_station", &value.args)?
+            .into()),
+            "admin_teleport_player" => Ok(__sdk::parse_reducer_args::<
+                admin_teleport_player_reducer::AdminTeleportPlayerArgs,
+            >("admin_teleport_player", &value.args)?
+            .into()),
+            "admin_unban" => Ok(
+                __sdk::parse_reducer_args::<admin_unban_reducer::AdminUnbanArgs>(
+                    "admin_unban",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "admin_unmute" => Ok(
+                __sdk::parse_reducer_args::<admin_unmute_reducer::AdminUnmuteArgs>(
+                    "admin_unmute",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "buy" => {
+                Ok(__sdk::parse_reducer_args::<buy_reducer::BuyArgs>("buy", &value.args)?.into())
+            }
+            "faction_create" => Ok(__sdk::parse_reducer_args::<
+                faction_create_reducer::FactionCreateArgs,
+            >("faction_create", &value.args)?
+            .into()),
+            "faction_decline_invite" => Ok(__sdk::parse_reducer_args::<
+                faction_decline_invite_reducer::FactionDeclineInviteArgs,
+            >("faction_decline_invite", &value.args)?
+            .into()),
+            "faction_invite_player" => Ok(__sdk::parse_reducer_args::<
+                faction_invite_player_reducer::FactionInvitePlayerArgs,
+            >("faction_invite_player", &value.args)?
+            .into()),
+            "faction_join" => Ok(
+                __sdk::parse_reducer_args::<faction_join_reducer::FactionJoinArgs>(
+                    "faction_join",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "faction_kick" => Ok(
+                __sdk::parse_reducer_args::<faction_kick_reducer::FactionKickArgs>(
+                    "faction_kick",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "faction_leave" => Ok(__sdk::parse_reducer_args::<
+                faction_leave_reducer::FactionLeaveArgs,
+            >("faction_leave", &value.args)?
+            .into()),
+            "faction_set_rank" => Ok(__sdk::parse_reducer_args::<
+                faction_set_rank_reducer::FactionSetRankArgs,
+            >("faction_set_rank", &value.args)?
+            .into()),
+            "faction_transfer_ship" => Ok(__sdk::parse_reducer_args::<
+                faction_transfer_ship_reducer::FactionTransferShipArgs,
+            >("faction_transfer_ship", &value.args)?
+            .into()),
+            "fire_weapon" => Ok(
+                __sdk::parse_reducer_args::<fire_weapon_reducer::FireWeaponArgs>(
+                    "fire_weapon",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "jettison_cargo" => Ok(__sdk::parse_reducer_args::<
+                jettison_cargo_reducer::JettisonCargoArgs,
+            >("jettison_cargo", &value.args)?
+            .into()),
+            "launch_missile" => Ok(__sdk::parse_reducer_args::<
+                launch_missile_reducer::LaunchMissileArgs,
+            >("launch_missile", &value.args)?
+            .into()),
+            "mine_asteroid" => Ok(__sdk::parse_reducer_args::<
+                mine_asteroid_reducer::MineAsteroidArgs,
+            >("mine_asteroid", &value.args)?
+            .into()),
             "on_connected" => Ok(
                 __sdk::parse_reducer_args::<on_connected_reducer::OnConnectedArgs>(
                     "on_connected",
@@ -148,10 +870,23 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 player_enter_ship_reducer::PlayerEnterShipArgs,
             >("player_enter_ship", &value.args)?
             .into()),
+            "player_grant_ship_access" => {
+                Ok(__sdk::parse_reducer_args::<
+                    player_grant_ship_access_reducer::PlayerGrantShipAccessArgs,
+                >("player_grant_ship_access", &value.args)?
+                .into())
+            }
             "player_leave_ship" => Ok(__sdk::parse_reducer_args::<
                 player_leave_ship_reducer::PlayerLeaveShipArgs,
             >("player_leave_ship", &value.args)?
             .into()),
+            "player_move" => Ok(
+                __sdk::parse_reducer_args::<player_move_reducer::PlayerMoveArgs>(
+                    "player_move",
+                    &value.args,
+                )?
+                .into(),
+            ),
             "player_move_ship" => Ok(__sdk::parse_reducer_args::<
                 player_move_ship_reducer::PlayerMoveShipArgs,
             >("player_move_ship", &value.args)?
@@ -163,10 +898,101 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 )?
                 .into(),
             ),
+            "player_revoke_ship_access" => {
+                Ok(__sdk::parse_reducer_args::<
+                    player_revoke_ship_access_reducer::PlayerRevokeShipAccessArgs,
+                >("player_revoke_ship_access", &value.args)?
+                .into())
+            }
+            "player_set_flight_controls" => {
+                Ok(__sdk::parse_reducer_args::<
+                    player_set_flight_controls_reducer::PlayerSetFlightControlsArgs,
+                >("player_set_flight_controls", &value.args)?
+                .into())
+            }
+            "player_set_flight_mode" => Ok(__sdk::parse_reducer_args::<
+                player_set_flight_mode_reducer::PlayerSetFlightModeArgs,
+            >("player_set_flight_mode", &value.args)?
+            .into()),
             "player_spawn_ship" => Ok(__sdk::parse_reducer_args::<
                 player_spawn_ship_reducer::PlayerSpawnShipArgs,
             >("player_spawn_ship", &value.args)?
             .into()),
+            "refuel_ship" => Ok(
+                __sdk::parse_reducer_args::<refuel_ship_reducer::RefuelShipArgs>(
+                    "refuel_ship",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "repair_ship" => Ok(
+                __sdk::parse_reducer_args::<repair_ship_reducer::RepairShipArgs>(
+                    "repair_ship",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "request_docking" => Ok(__sdk::parse_reducer_args::<
+                request_docking_reducer::RequestDockingArgs,
+            >("request_docking", &value.args)?
+            .into()),
+            "respawn_at_home" => Ok(__sdk::parse_reducer_args::<
+                respawn_at_home_reducer::RespawnAtHomeArgs,
+            >("respawn_at_home", &value.args)?
+            .into()),
+            "sell" => Ok(
+                __sdk::parse_reducer_args::<sell_reducer::SellArgs>("sell", &value.args)?.into(),
+            ),
+            "send_message" => Ok(
+                __sdk::parse_reducer_args::<send_message_reducer::SendMessageArgs>(
+                    "send_message",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "set_home_station" => Ok(__sdk::parse_reducer_args::<
+                set_home_station_reducer::SetHomeStationArgs,
+            >("set_home_station", &value.args)?
+            .into()),
+            "ships_despawn_parked" => Ok(__sdk::parse_reducer_args::<
+                ships_despawn_parked_reducer::ShipsDespawnParkedArgs,
+            >("ships_despawn_parked", &value.args)?
+            .into()),
+            "transfer_cargo" => Ok(__sdk::parse_reducer_args::<
+                transfer_cargo_reducer::TransferCargoArgs,
+            >("transfer_cargo", &value.args)?
+            .into()),
+            "undock" => Ok(__sdk::parse_reducer_args::<undock_reducer::UndockArgs>(
+                "undock",
+                &value.args,
+            )?
+            .into()),
+            "world_clear_chat" => Ok(__sdk::parse_reducer_args::<
+                world_clear_chat_reducer::WorldClearChatArgs,
+            >("world_clear_chat", &value.args)?
+            .into()),
+            "world_clear_wrecks" => Ok(__sdk::parse_reducer_args::<
+                world_clear_wrecks_reducer::WorldClearWrecksArgs,
+            >("world_clear_wrecks", &value.args)?
+            .into()),
+            "world_respawn_asteroid" => Ok(__sdk::parse_reducer_args::<
+                world_respawn_asteroid_reducer::WorldRespawnAsteroidArgs,
+            >("world_respawn_asteroid", &value.args)?
+            .into()),
+            "world_update_markets" => Ok(__sdk::parse_reducer_args::<
+                world_update_markets_reducer::WorldUpdateMarketsArgs,
+            >("world_update_markets", &value.args)?
+            .into()),
+            "world_update_missiles" => Ok(__sdk::parse_reducer_args::<
+                world_update_missiles_reducer::WorldUpdateMissilesArgs,
+            >("world_update_missiles", &value.args)?
+            .into()),
+            "world_update_ship_flights" => {
+                Ok(__sdk::parse_reducer_args::<
+                    world_update_ship_flights_reducer::WorldUpdateShipFlightsArgs,
+                >("world_update_ship_flights", &value.args)?
+                .into())
+            }
             "world_update_stations_rotation" => {
                 Ok(__sdk::parse_reducer_args::<
                     world_update_stations_rotation_reducer::WorldUpdateStationsRotationArgs,
@@ -187,15 +1013,53 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
 #[allow(non_snake_case)]
 #[doc(hidden)]
 pub struct DbUpdate {
+    admin: __sdk::TableUpdate<Admin>,
+    admin_audit: __sdk::TableUpdate<AdminAudit>,
     asteroid: __sdk::TableUpdate<Asteroid>,
+    asteroid_resource: __sdk::TableUpdate<AsteroidResource>,
+    asteroid_respawn: __sdk::TableUpdate<AsteroidRespawn>,
+    ban: __sdk::TableUpdate<Ban>,
+    cargo: __sdk::TableUpdate<Cargo>,
+    chat_cleanup: __sdk::TableUpdate<ChatCleanup>,
+    chat_message: __sdk::TableUpdate<ChatMessage>,
+    docking_bay: __sdk::TableUpdate<DockingBay>,
+    faction: __sdk::TableUpdate<Faction>,
+    faction_invite: __sdk::TableUpdate<FactionInvite>,
+    faction_member: __sdk::TableUpdate<FactionMember>,
+    hardpoint: __sdk::TableUpdate<Hardpoint>,
+    home_station: __sdk::TableUpdate<HomeStation>,
+    item_type: __sdk::TableUpdate<ItemType>,
+    market: __sdk::TableUpdate<Market>,
+    market_price_history: __sdk::TableUpdate<MarketPriceHistory>,
+    market_update: __sdk::TableUpdate<MarketUpdate>,
+    missile: __sdk::TableUpdate<Missile>,
+    missile_type: __sdk::TableUpdate<MissileType>,
+    missile_update: __sdk::TableUpdate<MissileUpdate>,
+    mute: __sdk::TableUpdate<Mute>,
+    parked_ships_despawn: __sdk::TableUpdate<ParkedShipsDespawn>,
     player: __sdk::TableUpdate<Player>,
     player_location: __sdk::TableUpdate<PlayerLocation>,
+    rate_limit: __sdk::TableUpdate<RateLimit>,
+    rate_limit_bucket: __sdk::TableUpdate<RateLimitBucket>,
+    rejected_call: __sdk::TableUpdate<RejectedCall>,
     ship: __sdk::TableUpdate<Ship>,
+    ship_access: __sdk::TableUpdate<ShipAccess>,
+    ship_docking: __sdk::TableUpdate<ShipDocking>,
+    ship_flight: __sdk::TableUpdate<ShipFlight>,
+    ship_flight_update: __sdk::TableUpdate<ShipFlightUpdate>,
     ship_location: __sdk::TableUpdate<ShipLocation>,
+    ship_mining: __sdk::TableUpdate<ShipMining>,
     ship_pilot: __sdk::TableUpdate<ShipPilot>,
+    ship_status: __sdk::TableUpdate<ShipStatus>,
     ship_type: __sdk::TableUpdate<ShipType>,
     station: __sdk::TableUpdate<Station>,
+    station_rotation: __sdk::TableUpdate<StationRotation>,
     station_rotation_update: __sdk::TableUpdate<StationRotationUpdate>,
+    wallet: __sdk::TableUpdate<Wallet>,
+    weapon_fire: __sdk::TableUpdate<WeaponFire>,
+    weapon_type: __sdk::TableUpdate<WeaponType>,
+    wreck: __sdk::TableUpdate<Wreck>,
+    wreck_cleanup: __sdk::TableUpdate<WreckCleanup>,
 }
 
 impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
@@ -204,33 +1068,147 @@ impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
         let mut db_update = DbUpdate::default();
         for table_update in raw.tables {
             match &table_update.table_name[..] {
+                "admin" => db_update
+                    .admin
+                    .append(admin_table::parse_table_update(table_update)?),
+                "admin_audit" => db_update
+                    .admin_audit
+                    .append(admin_audit_table::parse_table_update(table_update)?),
                 "asteroid" => db_update
                     .asteroid
                     .append(asteroid_table::parse_table_update(table_update)?),
+                "asteroid_resource" => db_update
+                    .asteroid_resource
+                    .append(asteroid_resource_table::parse_table_update(table_update)?),
+                "asteroid_respawn" => db_update
+                    .asteroid_respawn
+                    .append(asteroid_respawn_table::parse_table_update(table_update)?),
+                "ban" => db_update
+                    .ban
+                    .append(ban_table::parse_table_update(table_update)?),
+                "cargo" => db_update
+                    .cargo
+                    .append(cargo_table::parse_table_update(table_update)?),
+                "chat_cleanup" => db_update
+                    .chat_cleanup
+                    .append(chat_cleanup_table::parse_table_update(table_update)?),
+                "chat_message" => db_update
+                    .chat_message
+                    .append(chat_message_table::parse_table_update(table_update)?),
+                "docking_bay" => db_update
+                    .docking_bay
+                    .append(docking_bay_table::parse_table_update(table_update)?),
+                "faction" => db_update
+                    .faction
+                    .append(faction_table::parse_table_update(table_update)?),
+                "faction_invite" => db_update
+                    .faction_invite
+                    .append(faction_invite_table::parse_table_update(table_update)?),
+                "faction_member" => db_update
+                    .faction_member
+                    .append(faction_member_table::parse_table_update(table_update)?),
+                "hardpoint" => db_update
+                    .hardpoint
+                    .append(hardpoint_table::parse_table_update(table_update)?),
+                "home_station" => db_update
+                    .home_station
+                    .append(home_station_table::parse_table_update(table_update)?),
+                "item_type" => db_update
+                    .item_type
+                    .append(item_type_table::parse_table_update(table_update)?),
+                "market" => db_update
+                    .market
+                    .append(market_table::parse_table_update(table_update)?),
+                "market_price_history" => db_update.market_price_history.append(
+                    market_price_history_table::parse_table_update(table_update)?,
+                ),
+                "market_update" => db_update
+                    .market_update
+                    .append(market_update_table::parse_table_update(table_update)?),
+                "missile" => db_update
+                    .missile
+                    .append(missile_table::parse_table_update(table_update)?),
+                "missile_type" => db_update
+                    .missile_type
+                    .append(missile_type_table::parse_table_update(table_update)?),
+                "missile_update" => db_update
+                    .missile_update
+                    .append(missile_update_table::parse_table_update(table_update)?),
+                "mute" => db_update
+                    .mute
+                    .append(mute_table::parse_table_update(table_update)?),
+                "parked_ships_despawn" => db_update.parked_ships_despawn.append(
+                    parked_ships_despawn_table::parse_table_update(table_update)?,
+                ),
                 "player" => db_update
                     .player
                     .append(player_table::parse_table_update(table_update)?),
                 "player_location" => db_update
                     .player_location
                     .append(player_location_table::parse_table_update(table_update)?),
+                "rate_limit" => db_update
+                    .rate_limit
+                    .append(rate_limit_table::parse_table_update(table_update)?),
+                "rate_limit_bucket" => db_update
+                    .rate_limit_bucket
+                    .append(rate_limit_bucket_table::parse_table_update(table_update)?),
+                "rejected_call" => db_update
+                    .rejected_call
+                    .append(rejected_call_table::parse_table_update(table_update)?),
                 "ship" => db_update
                     .ship
                     .append(ship_table::parse_table_update(table_update)?),
+                "ship_access" => db_update
+                    .ship_access
+                    .append(ship_access_table::parse_table_update(table_update)?),
+                "ship_docking" => db_update
+                    .ship_docking
+                    .append(ship_docking_table::parse_table_update(table_update)?),
+                "ship_flight" => db_update
+                    .ship_flight
+                    .append(ship_flight_table::parse_table_update(table_update)?),
+                "ship_flight_update" => db_update
+                    .ship_flight_update
+                    .append(ship_flight_update_table::parse_table_update(table_update)?),
                 "ship_location" => db_update
                     .ship_location
                     .append(ship_location_table::parse_table_update(table_update)?),
+                "ship_mining" => db_update
+                    .ship_mining
+                    .append(ship_mining_table::parse_table_update(table_update)?),
                 "ship_pilot" => db_update
                     .ship_pilot
                     .append(ship_pilot_table::parse_table_update(table_update)?),
+                "ship_status" => db_update
+                    .ship_status
+                    .append(ship_status_table::parse_table_update(table_update)?),
                 "ship_type" => db_update
                     .ship_type
                     .append(ship_type_table::parse_table_update(table_update)?),
                 "station" => db_update
                     .station
                     .append(station_table::parse_table_update(table_update)?),
+                "station_rotation" => db_update
+                    .station_rotation
+                    .append(station_rotation_table::parse_table_update(table_update)?),
                 "station_rotation_update" => db_update.station_rotation_update.append(
                     station_rotation_update_table::parse_table_update(table_update)?,
                 ),
+                "wallet" => db_update
+                    .wallet
+                    .append(wallet_table::parse_table_update(table_update)?),
+                "weapon_fire" => db_update
+                    .weapon_fire
+                    .append(weapon_fire_table::parse_table_update(table_update)?),
+                "weapon_type" => db_update
+                    .weapon_type
+                    .append(weapon_type_table::parse_table_update(table_update)?),
+                "wreck" => db_update
+                    .wreck
+                    .append(wreck_table::parse_table_update(table_update)?),
+                "wreck_cleanup" => db_update
+                    .wreck_cleanup
+                    .append(wreck_cleanup_table::parse_table_update(table_update)?),
 
                 unknown => {
                     return Err(__sdk::InternalError::unknown_name(
@@ -257,36 +1235,156 @@ impl __sdk::DbUpdate for DbUpdate {
     ) -> AppliedDiff<'_> {
         let mut diff = AppliedDiff::default();
 
+        diff.admin = cache
+            .apply_diff_to_table::<Admin>("admin", &self.admin)
+            .with_updates_by_pk(|row| &row.id);
+        diff.admin_audit = cache
+            .apply_diff_to_table::<AdminAudit>("admin_audit", &self.admin_audit)
+            .with_updates_by_pk(|row| &row.id);
         diff.asteroid = cache
             .apply_diff_to_table::<Asteroid>("asteroid", &self.asteroid)
             .with_updates_by_pk(|row| &row.id);
+        diff.asteroid_resource = cache
+            .apply_diff_to_table::<AsteroidResource>("asteroid_resource", &self.asteroid_resource)
+            .with_updates_by_pk(|row| &row.id);
+        diff.asteroid_respawn = cache
+            .apply_diff_to_table::<AsteroidRespawn>("asteroid_respawn", &self.asteroid_respawn)
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.ban = cache
+            .apply_diff_to_table::<Ban>("ban", &self.ban)
+            .with_updates_by_pk(|row| &row.player_id);
+        diff.cargo = cache
+            .apply_diff_to_table::<Cargo>("cargo", &self.cargo)
+            .with_updates_by_pk(|row| &row.id);
+        diff.chat_cleanup = cache
+            .apply_diff_to_table::<ChatCleanup>("chat_cleanup", &self.chat_cleanup)
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.chat_message = cache
+            .apply_diff_to_table::<ChatMessage>("chat_message", &self.chat_message)
+            .with_updates_by_pk(|row| &row.id);
+        diff.docking_bay = cache
+            .apply_diff_to_table::<DockingBay>("docking_bay", &self.docking_bay)
+            .with_updates_by_pk(|row| &row.id);
+        diff.faction = cache
+            .apply_diff_to_table::<Faction>("faction", &self.faction)
+            .with_updates_by_pk(|row| &row.id);
+        diff.faction_invite = cache
+            .apply_diff_to_table::<FactionInvite>("faction_invite", &self.faction_invite)
+            .with_updates_by_pk(|row| &row.id);
+        diff.faction_member = cache
+            .apply_diff_to_table::<FactionMember>("faction_member", &self.faction_member)
+            .with_updates_by_pk(|row| &row.player_id);
+        diff.hardpoint = cache
+            .apply_diff_to_table::<Hardpoint>("hardpoint", &self.hardpoint)
+            .with_updates_by_pk(|row| &row.id);
+        diff.home_station = cache
+            .apply_diff_to_table::<HomeStation>("home_station", &self.home_station)
+            .with_updates_by_pk(|row| &row.player_id);
+        diff.item_type = cache
+            .apply_diff_to_table::<ItemType>("item_type", &self.item_type)
+            .with_updates_by_pk(|row| &row.id);
+        diff.market = cache
+            .apply_diff_to_table::<Market>("market", &self.market)
+            .with_updates_by_pk(|row| &row.id);
+        diff.market_price_history = cache
+            .apply_diff_to_table::<MarketPriceHistory>(
+                "market_price_history",
+                &self.market_price_history,
+            )
+            .with_updates_by_pk(|row| &row.id);
+        diff.market_update = cache
+            .apply_diff_to_table::<MarketUpdate>("market_update", &self.market_update)
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.missile = cache
+            .apply_diff_to_table::<Missile>("missile", &self.missile)
+            .with_updates_by_pk(|row| &row.id);
+        diff.missile_type = cache
+            .apply_diff_to_table::<MissileType>("missile_type", &self.missile_type)
+            .with_updates_by_pk(|row| &row.weapon_type_id);
+        diff.missile_update = cache
+            .apply_diff_to_table::<MissileUpdate>("missile_update", &self.missile_update)
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.mute = cache
+            .apply_diff_to_table::<Mute>("mute", &self.mute)
+            .with_updates_by_pk(|row| &row.player_id);
+        diff.parked_ships_despawn = cache
+            .apply_diff_to_table::<ParkedShipsDespawn>(
+                "parked_ships_despawn",
+                &self.parked_ships_despawn,
+            )
+            .with_updates_by_pk(|row| &row.scheduled_id);
         diff.player = cache
             .apply_diff_to_table::<Player>("player", &self.player)
             .with_updates_by_pk(|row| &row.id);
         diff.player_location = cache
             .apply_diff_to_table::<PlayerLocation>("player_location", &self.player_location)
             .with_updates_by_pk(|row| &row.player_id);
+        diff.rate_limit = cache
+            .apply_diff_to_table::<RateLimit>("rate_limit", &self.rate_limit)
+            .with_updates_by_pk(|row| &row.id);
+        diff.rate_limit_bucket = cache
+            .apply_diff_to_table::<RateLimitBucket>("rate_limit_bucket", &self.rate_limit_bucket)
+            .with_updates_by_pk(|row| &row.id);
+        diff.rejected_call = cache
+            .apply_diff_to_table::<RejectedCall>("rejected_call", &self.rejected_call)
+            .with_updates_by_pk(|row| &row.id);
         diff.ship = cache
             .apply_diff_to_table::<Ship>("ship", &self.ship)
             .with_updates_by_pk(|row| &row.id);
+        diff.ship_access = cache
+            .apply_diff_to_table::<ShipAccess>("ship_access", &self.ship_access)
+            .with_updates_by_pk(|row| &row.id);
+        diff.ship_docking = cache
+            .apply_diff_to_table::<ShipDocking>("ship_docking", &self.ship_docking)
+            .with_updates_by_pk(|row| &row.ship_id);
+        diff.ship_flight = cache
+            .apply_diff_to_table::<ShipFlight>("ship_flight", &self.ship_flight)
+            .with_updates_by_pk(|row| &row.ship_id);
+        diff.ship_flight_update = cache
+            .apply_diff_to_table::<ShipFlightUpdate>("ship_flight_update", &self.ship_flight_update)
+            .with_updates_by_pk(|row| &row.scheduled_id);
         diff.ship_location = cache
             .apply_diff_to_table::<ShipLocation>("ship_location", &self.ship_location)
             .with_updates_by_pk(|row| &row.ship_id);
+        diff.ship_mining = cache
+            .apply_diff_to_table::<ShipMining>("ship_mining", &self.ship_mining)
+            .with_updates_by_pk(|row| &row.ship_id);
         diff.ship_pilot = cache
             .apply_diff_to_table::<ShipPilot>("ship_pilot", &self.ship_pilot)
             .with_updates_by_pk(|row| &row.ship_id);
+        diff.ship_status = cache
+            .apply_diff_to_table::<ShipStatus>("ship_status", &self.ship_status)
+            .with_updates_by_pk(|row| &row.ship_id);
         diff.ship_type = cache
             .apply_diff_to_table::<ShipType>("ship_type", &self.ship_type)
             .with_updates_by_pk(|row| &row.id);
         diff.station = cache
             .apply_diff_to_table::<Station>("station", &self.station)
             .with_updates_by_pk(|row| &row.id);
+        diff.station_rotation = cache
+            .apply_diff_to_table::<StationRotation>("station_rotation", &self.station_rotation)
+            .with_updates_by_pk(|row| &row.station_id);
         diff.station_rotation_update = cache
             .apply_diff_to_table::<StationRotationUpdate>(
                 "station_rotation_update",
                 &self.station_rotation_update,
             )
             .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.wallet = cache
+            .apply_diff_to_table::<Wallet>("wallet", &self.wallet)
+            .with_updates_by_pk(|row| &row.player_id);
+        diff.weapon_fire = cache
+            .apply_diff_to_table::<WeaponFire>("weapon_fire", &self.weapon_fire)
+            .with_updates_by_pk(|row| &row.id);
+        diff.weapon_type = cache
+            .apply_diff_to_table::<WeaponType>("weapon_type", &self.weapon_type)
+            .with_updates_by_pk(|row| &row.id);
+        diff.wreck = cache
+            .apply_diff_to_table::<Wreck>("wreck", &self.wreck)
+            .with_updates_by_pk(|row| &row.id);
+        diff.wreck_cleanup = cache
+            .apply_diff_to_table::<WreckCleanup>("wreck_cleanup", &self.wreck_cleanup)
+            .with_updates_by_pk(|row| &row.scheduled_id);
 
         diff
     }
@@ -296,15 +1394,53 @@ impl __sdk::DbUpdate for DbUpdate {
 #[allow(non_snake_case)]
 #[doc(hidden)]
 pub struct AppliedDiff<'r> {
+    admin: __sdk::TableAppliedDiff<'r, Admin>,
+    admin_audit: __sdk::TableAppliedDiff<'r, AdminAudit>,
     asteroid: __sdk::TableAppliedDiff<'r, Asteroid>,
+    asteroid_resource: __sdk::TableAppliedDiff<'r, AsteroidResource>,
+    asteroid_respawn: __sdk::TableAppliedDiff<'r, AsteroidRespawn>,
+    ban: __sdk::TableAppliedDiff<'r, Ban>,
+    cargo: __sdk::TableAppliedDiff<'r, Cargo>,
+    chat_cleanup: __sdk::TableAppliedDiff<'r, ChatCleanup>,
+    chat_message: __sdk::TableAppliedDiff<'r, ChatMessage>,
+    docking_bay: __sdk::TableAppliedDiff<'r, DockingBay>,
+    faction: __sdk::TableAppliedDiff<'r, Faction>,
+    faction_invite: __sdk::TableAppliedDiff<'r, FactionInvite>,
+    faction_member: __sdk::TableAppliedDiff<'r, FactionMember>,
+    hardpoint: __sdk::TableAppliedDiff<'r, Hardpoint>,
+    home_station: __sdk::TableAppliedDiff<'r, HomeStation>,
+    item_type: __sdk::TableAppliedDiff<'r, ItemType>,
+    market: __sdk::TableAppliedDiff<'r, Market>,
+    market_price_history: __sdk::TableAppliedDiff<'r, MarketPriceHistory>,
+    market_update: __sdk::TableAppliedDiff<'r, MarketUpdate>,
+    missile: __sdk::TableAppliedDiff<'r, Missile>,
+    missile_type: __sdk::TableAppliedDiff<'r, MissileType>,
+    missile_update: __sdk::TableAppliedDiff<'r, MissileUpdate>,
+    mute: __sdk::TableAppliedDiff<'r, Mute>,
+    parked_ships_despawn: __sdk::TableAppliedDiff<'r, ParkedShipsDespawn>,
     player: __sdk::TableAppliedDiff<'r, Player>,
     player_location: __sdk::TableAppliedDiff<'r, PlayerLocation>,
+    rate_limit: __sdk::TableAppliedDiff<'r, RateLimit>,
+    rate_limit_bucket: __sdk::TableAppliedDiff<'r, RateLimitBucket>,
+    rejected_call: __sdk::TableAppliedDiff<'r, RejectedCall>,
     ship: __sdk::TableAppliedDiff<'r, Ship>,
+    ship_access: __sdk::TableAppliedDiff<'r, ShipAccess>,
+    ship_docking: __sdk::TableAppliedDiff<'r, ShipDocking>,
+    ship_flight: __sdk::TableAppliedDiff<'r, ShipFlight>,
+    ship_flight_update: __sdk::TableAppliedDiff<'r, ShipFlightUpdate>,
     ship_location: __sdk::TableAppliedDiff<'r, ShipLocation>,
+    ship_mining: __sdk::TableAppliedDiff<'r, ShipMining>,
     ship_pilot: __sdk::TableAppliedDiff<'r, ShipPilot>,
+    ship_status: __sdk::TableAppliedDiff<'r, ShipStatus>,
     ship_type: __sdk::TableAppliedDiff<'r, ShipType>,
     station: __sdk::TableAppliedDiff<'r, Station>,
+    station_rotation: __sdk::TableAppliedDiff<'r, StationRotation>,
     station_rotation_update: __sdk::TableAppliedDiff<'r, StationRotationUpdate>,
+    wallet: __sdk::TableAppliedDiff<'r, Wallet>,
+    weapon_fire: __sdk::TableAppliedDiff<'r, WeaponFire>,
+    weapon_type: __sdk::TableAppliedDiff<'r, WeaponType>,
+    wreck: __sdk::TableAppliedDiff<'r, Wreck>,
+    wreck_cleanup: __sdk::TableAppliedDiff<'r, WreckCleanup>,
 }
 
 impl __sdk::InModule for AppliedDiff<'_> {
@@ -317,27 +1453,137 @@ impl<'r> __sdk::AppliedDiff<'r> for AppliedDiff<'r> {
         event: &EventContext,
         callbacks: &mut __sdk::DbCallbacks<RemoteModule>,
     ) {
+        callbacks.invoke_table_row_callbacks::<Admin>("admin", &self.admin, event);
+        callbacks.invoke_table_row_callbacks::<AdminAudit>("admin_audit", &self.admin_audit, event);
         callbacks.invoke_table_row_callbacks::<Asteroid>("asteroid", &self.asteroid, event);
+        callbacks.invoke_table_row_callbacks::<AsteroidResource>(
+            "asteroid_resource",
+            &self.asteroid_resource,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<AsteroidRespawn>(
+            "asteroid_respawn",
+            &self.asteroid_respawn,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Ban>("ban", &self.ban, event);
+        callbacks.invoke_table_row_callbacks::<Cargo>("cargo", &self.cargo, event);
+        callbacks.invoke_table_row_callbacks::<ChatCleanup>(
+            "chat_cleanup",
+            &self.chat_cleanup,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<ChatMessage>(
+            "chat_message",
+            &self.chat_message,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<DockingBay>("docking_bay", &self.docking_bay, event);
+        callbacks.invoke_table_row_callbacks::<Faction>("faction", &self.faction, event);
+        callbacks.invoke_table_row_callbacks::<FactionInvite>(
+            "faction_invite",
+            &self.faction_invite,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<FactionMember>(
+            "faction_member",
+            &self.faction_member,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Hardpoint>("hardpoint", &self.hardpoint, event);
+        callbacks.invoke_table_row_callbacks::<HomeStation>(
+            "home_station",
+            &self.home_station,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<ItemType>("item_type", &self.item_type, event);
+        callbacks.invoke_table_row_callbacks::<Market>("market", &self.market, event);
+        callbacks.invoke_table_row_callbacks::<MarketPriceHistory>(
+            "market_price_history",
+            &self.market_price_history,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<MarketUpdate>(
+            "market_update",
+            &self.market_update,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Missile>("missile", &self.missile, event);
+        callbacks.invoke_table_row_callbacks::<MissileType>(
+            "missile_type",
+            &self.missile_type,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<MissileUpdate>(
+            "missile_update",
+            &self.missile_update,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Mute>("mute", &self.mute, event);
+        callbacks.invoke_table_row_callbacks::<ParkedShipsDespawn>(
+            "parked_ships_despawn",
+            &self.parked_ships_despawn,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<Player>("player", &self.player, event);
         callbacks.invoke_table_row_callbacks::<PlayerLocation>(
             "player_location",
             &self.player_location,
             event,
         );
+        callbacks.invoke_table_row_callbacks::<RateLimit>("rate_limit", &self.rate_limit, event);
+        callbacks.invoke_table_row_callbacks::<RateLimitBucket>(
+            "rate_limit_bucket",
+            &self.rate_limit_bucket,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<RejectedCall>(
+            "rejected_call",
+            &self.rejected_call,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<Ship>("ship", &self.ship, event);
+        callbacks.invoke_table_row_callbacks::<ShipAccess>("ship_access", &self.ship_access, event);
+        callbacks.invoke_table_row_callbacks::<ShipDocking>(
+            "ship_docking",
+            &self.ship_docking,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<ShipFlight>("ship_flight", &self.ship_flight, event);
+        callbacks.invoke_table_row_callbacks::<ShipFlightUpdate>(
+            "ship_flight_update",
+            &self.ship_flight_update,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<ShipLocation>(
             "ship_location",
             &self.ship_location,
             event,
         );
+        callbacks.invoke_table_row_callbacks::<ShipMining>("ship_mining", &self.ship_mining, event);
         callbacks.invoke_table_row_callbacks::<ShipPilot>("ship_pilot", &self.ship_pilot, event);
+        callbacks.invoke_table_row_callbacks::<ShipStatus>("ship_status", &self.ship_status, event);
         callbacks.invoke_table_row_callbacks::<ShipType>("ship_type", &self.ship_type, event);
         callbacks.invoke_table_row_callbacks::<Station>("station", &self.station, event);
+        callbacks.invoke_table_row_callbacks::<StationRotation>(
+            "station_rotation",
+            &self.station_rotation,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<StationRotationUpdate>(
             "station_rotation_update",
             &self.station_rotation_update,
             event,
         );
+        callbacks.invoke_table_row_callbacks::<Wallet>("wallet", &self.wallet, event);
+        callbacks.invoke_table_row_callbacks::<WeaponFire>("weapon_fire", &self.weapon_fire, event);
+        callbacks.invoke_table_row_callbacks::<WeaponType>("weapon_type", &self.weapon_type, event);
+        callbacks.invoke_table_row_callbacks::<Wreck>("wreck", &self.wreck, event);
+        callbacks.invoke_table_row_callbacks::<WreckCleanup>(
+            "wreck_cleanup",
+            &self.wreck_cleanup,
+            event,
+        );
     }
 }
 
@@ -913,14 +2159,52 @@ impl __sdk::SpacetimeModule for RemoteModule {
     type SubscriptionHandle = SubscriptionHandle;
 
     fn register_tables(client_cache: &mut __sdk::ClientCache<Self>) {
+        admin_table::register_table(client_cache);
+        admin_audit_table::register_table(client_cache);
         asteroid_table::register_table(client_cache);
+        asteroid_resource_table::register_table(client_cache);
+        asteroid_respawn_table::register_table(client_cache);
+        ban_table::register_table(client_cache);
+        cargo_table::register_table(client_cache);
+        chat_cleanup_table::register_table(client_cache);
+        chat_message_table::register_table(client_cache);
+        docking_bay_table::register_table(client_cache);
+        faction_table::register_table(client_cache);
+        faction_invite_table::register_table(client_cache);
+        faction_member_table::register_table(client_cache);
+        hardpoint_table::register_table(client_cache);
+        home_station_table::register_table(client_cache);
+        item_type_table::register_table(client_cache);
+        market_table::register_table(client_cache);
+        market_price_history_table::register_table(client_cache);
+        market_update_table::register_table(client_cache);
+        missile_table::register_table(client_cache);
+        missile_type_table::register_table(client_cache);
+        missile_update_table::register_table(client_cache);
+        mute_table::register_table(client_cache);
+        parked_ships_despawn_table::register_table(client_cache);
         player_table::register_table(client_cache);
         player_location_table::register_table(client_cache);
+        rate_limit_table::register_table(client_cache);
+        rate_limit_bucket_table::register_table(client_cache);
+        rejected_call_table::register_table(client_cache);
         ship_table::register_table(client_cache);
+        ship_access_table::register_table(client_cache);
+        ship_docking_table::register_table(client_cache);
+        ship_flight_table::register_table(client_cache);
+        ship_flight_update_table::register_table(client_cache);
         ship_location_table::register_table(client_cache);
+        ship_mining_table::register_table(client_cache);
         ship_pilot_table::register_table(client_cache);
+        ship_status_table::register_table(client_cache);
         ship_type_table::register_table(client_cache);
         station_table::register_table(client_cache);
+        station_rotation_table::register_table(client_cache);
         station_rotation_update_table::register_table(client_cache);
+        wallet_table::register_table(client_cache);
+        weapon_fire_table::register_table(client_cache);
+        weapon_type_table::register_table(client_cache);
+        wreck_table::register_table(client_cache);
+        wreck_cleanup_table::register_table(client_cache);
     }
 }
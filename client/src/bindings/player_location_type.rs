@@ -17,6 +17,10 @@ pub struct PlayerLocation {
     pub rot_y: f32,
     pub rot_z: f32,
     pub rot_w: f32,
+    pub sector_x: i32,
+    pub sector_y: i32,
+    pub sector_z: i32,
+    pub updated_at: __sdk::Timestamp,
 }
 
 impl __sdk::InModule for PlayerLocation {
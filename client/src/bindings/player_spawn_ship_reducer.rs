@@ -9,6 +9,7 @@ use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 #[derive(__lib::ser::Serialize, __lib::de::Deserialize, Clone, PartialEq, Debug)]
 #[sats(crate = __lib)]
 pub(super) struct PlayerSpawnShipArgs {
+    pub ship_type_id: u64,
     pub x: f32,
     pub y: f32,
     pub z: f32,
@@ -21,6 +22,7 @@ pub(super) struct PlayerSpawnShipArgs {
 impl From<PlayerSpawnShipArgs> for super::Reducer {
     fn from(args: PlayerSpawnShipArgs) -> Self {
         Self::PlayerSpawnShip {
+            ship_type_id: args.ship_type_id,
             x: args.x,
             y: args.y,
             z: args.z,
@@ -50,6 +52,7 @@ pub trait player_spawn_ship {
     ///  and its status can be observed by listening for [`Self::on_player_spawn_ship`] callbacks.
     fn player_spawn_ship(
         &self,
+        ship_type_id: u64,
         x: f32,
         y: f32,
         z: f32,
@@ -67,7 +70,7 @@ pub trait player_spawn_ship {
     /// to cancel the callback.
     fn on_player_spawn_ship(
         &self,
-        callback: impl FnMut(&super::ReducerEventContext, &f32, &f32, &f32, &f32, &f32, &f32, &f32)
+        callback: impl FnMut(&super::ReducerEventContext, &u64, &f32, &f32, &f32, &f32, &f32, &f32, &f32)
             + Send
             + 'static,
     ) -> PlayerSpawnShipCallbackId;
@@ -79,6 +82,7 @@ pub trait player_spawn_ship {
 impl player_spawn_ship for super::RemoteReducers {
     fn player_spawn_ship(
         &self,
+        ship_type_id: u64,
         x: f32,
         y: f32,
         z: f32,
@@ -90,6 +94,7 @@ impl player_spawn_ship for super::RemoteReducers {
         self.imp.call_reducer(
             "player_spawn_ship",
             PlayerSpawnShipArgs {
+                ship_type_id,
                 x,
                 y,
                 z,
@@ -102,7 +107,7 @@ impl player_spawn_ship for super::RemoteReducers {
     }
     fn on_player_spawn_ship(
         &self,
-        mut callback: impl FnMut(&super::ReducerEventContext, &f32, &f32, &f32, &f32, &f32, &f32, &f32)
+        mut callback: impl FnMut(&super::ReducerEventContext, &u64, &f32, &f32, &f32, &f32, &f32, &f32, &f32)
             + Send
             + 'static,
     ) -> PlayerSpawnShipCallbackId {
@@ -114,6 +119,7 @@ impl player_spawn_ship for super::RemoteReducers {
                         __sdk::ReducerEvent {
                             reducer:
                                 super::Reducer::PlayerSpawnShip {
+                                    ship_type_id,
                                     x,
                                     y,
                                     z,
@@ -129,7 +135,7 @@ impl player_spawn_ship for super::RemoteReducers {
                 else {
                     unreachable!()
                 };
-                callback(ctx, x, y, z, rot_x, rot_y, rot_z, rot_w)
+                callback(ctx, ship_type_id, x, y, z, rot_x, rot_y, rot_z, rot_w)
             }),
         ))
     }
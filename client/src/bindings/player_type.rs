@@ -17,6 +17,8 @@ pub struct Player {
     pub rot_y: f32,
     pub rot_z: f32,
     pub rot_w: f32,
+    pub online: bool,
+    pub last_seen: __sdk::Timestamp,
 }
 
 impl __sdk::InModule for Player {
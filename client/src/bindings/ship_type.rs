@@ -12,6 +12,7 @@ pub struct Ship {
     pub id: u64,
     pub ship_type_id: u64,
     pub owner_id: __sdk::Identity,
+    pub faction_id: Option<__sdk::Identity>,
 }
 
 impl __sdk::InModule for Ship {
@@ -11,9 +11,14 @@ use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 pub struct ShipType {
     pub id: u64,
     pub name: String,
+    pub model: String,
+    pub collider: String,
     pub camera_offset_x: f32,
     pub camera_offset_y: f32,
     pub camera_offset_z: f32,
+    pub exit_offset_x: f32,
+    pub exit_offset_y: f32,
+    pub exit_offset_z: f32,
     pub mass: f32,
     pub linear_damping: f32,
     pub angular_damping: f32,
@@ -23,6 +28,22 @@ pub struct ShipType {
     pub pitch_torque: f32,
     pub yaw_torque: f32,
     pub roll_torque: f32,
+    pub cargo_capacity: f32,
+    pub radius: f32,
+    pub max_hull: f32,
+    pub max_energy: f32,
+    pub energy_recharge: f32,
+    pub max_shield: f32,
+    pub shield_recharge: f32,
+    pub shield_delay: f32,
+    pub armor_energy: f32,
+    pub armor_kinetic: f32,
+    pub armor_explosive: f32,
+    pub max_fuel: f32,
+    pub fuel_consumption: f32,
+    pub boost_factor: f32,
+    pub boost_energy_drain: f32,
+    pub retired: bool,
 }
 
 impl __sdk::InModule for ShipType {
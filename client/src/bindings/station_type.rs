@@ -17,6 +17,9 @@ pub struct Station {
     pub rotation_speed: f32,
     pub target_angle: f32,
     pub reach_angle_at: u128,
+    pub sector_x: i32,
+    pub sector_y: i32,
+    pub sector_z: i32,
 }
 
 impl __sdk::InModule for Station {
@@ -0,0 +1,196 @@
+use std::sync::mpsc::Sender;
+
+use bevy::prelude::*;
+use bevy_inspector_egui::bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
+use bevy_spacetimedb::AddEventChannelAppExtensions;
+use spacetimedb_sdk::{Identity, Status, Table};
+
+use crate::{
+    GameState,
+    bindings::{ChatChannel, ChatMessageTableAccess, faction_invite_player, send_message},
+    factions::faction_tag,
+    spacetimedb::SpacetimeDB,
+};
+
+/// Most messages shown in the chat panel, the oldest scrolling out.
+const CHAT_SCROLLBACK: usize = 100;
+
+/// Sent when the server refuses a message, with the reason to show in the chat panel.
+#[derive(Event, Debug)]
+struct MessageFailedEvent(String);
+
+#[derive(Resource)]
+struct MessageFailedSender(Sender<MessageFailedEvent>);
+
+/// State of the chat panel between frames.
+#[derive(Resource)]
+struct ChatPanel {
+    /// Message being written.
+    input: String,
+    channel: ChatChannel,
+    /// Who private messages are sent to, picked by clicking on a name in the chat.
+    private_to: Option<Identity>,
+    /// Why the last message was refused, if it was.
+    error: Option<String>,
+}
+
+impl Default for ChatPanel {
+    fn default() -> Self {
+        Self {
+            input: String::new(),
+            channel: ChatChannel::Global,
+            private_to: None,
+            error: None,
+        }
+    }
+}
+
+pub struct ChatPlugin;
+
+impl Plugin for ChatPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ChatPanel>()
+            .add_systems(OnEnter(GameState::InGame), register_chat_callbacks)
+            .add_systems(Update, on_message_failed)
+            .add_systems(
+                EguiPrimaryContextPass,
+                show_chat_panel.run_if(in_state(GameState::InGame)),
+            );
+
+        let (send, recv) = std::sync::mpsc::channel();
+        app.insert_resource(MessageFailedSender(send));
+        app.add_event_channel::<MessageFailedEvent>(recv);
+    }
+}
+
+fn register_chat_callbacks(stdb: SpacetimeDB, sender: Res<MessageFailedSender>) {
+    let sender = sender.0.clone();
+    stdb.reducers().on_send_message(move |ctx, channel, _, _| {
+        if let Status::Failed(err) = &ctx.event.status {
+            debug!("Failed to send message on {channel:?}: {err}");
+            sender.send(MessageFailedEvent(err.to_string())).unwrap();
+        }
+    });
+}
+
+fn on_message_failed(mut events: EventReader<MessageFailedEvent>, mut panel: ResMut<ChatPanel>) {
+    for event in events.read() {
+        panel.error = Some(event.0.clone());
+    }
+}
+
+/// Players have no names yet, they go by the start of their identity.
+pub fn short_identity(identity: &Identity) -> String {
+    identity.to_hex().to_string()[..8].to_string()
+}
+
+/// Shows the messages delivered to the local player, and lets them write their own. Works both on
+/// foot and while piloting, the game ignoring the keyboard while the message is being written.
+fn show_chat_panel(
+    mut panel: ResMut<ChatPanel>,
+    mut contexts: EguiContexts,
+    stdb: SpacetimeDB,
+) -> Result {
+    let mut messages = stdb.db().chat_message().iter().collect::<Vec<_>>();
+    messages.sort_by_key(|message| message.id);
+    let skip = messages.len().saturating_sub(CHAT_SCROLLBACK);
+
+    egui::Window::new("Chat")
+        .resizable(true)
+        .default_width(360.0)
+        .anchor(egui::Align2::RIGHT_BOTTOM, [-10.0, -10.0])
+        .show(contexts.ctx_mut()?, |ui| {
+            egui::ScrollArea::vertical()
+                .max_height(200.0)
+                .stick_to_bottom(true)
+                .show(ui, |ui| {
+                    for message in &messages[skip..] {
+                        ui.horizontal_wrapped(|ui| {
+                            if message.channel == ChatChannel::Announcement {
+                                ui.colored_label(
+                                    egui::Color32::GOLD,
+                                    format!("[Announcement] {}", message.text),
+                                );
+                                return;
+                            }
+
+                            let channel = match (message.channel, message.private_to) {
+                                (ChatChannel::Private, Some(to))
+                                    if message.sender_id == stdb.identity() =>
+                                {
+                                    format!("[To {}]", short_identity(&to))
+                                }
+                                (ChatChannel::Private, _) => "[Private]".to_string(),
+                                (channel, _) => format!("[{channel:?}]"),
+                            };
+                            ui.weak(channel);
+
+                            // Clicking on someone's name replies to them privately, and
+                            // right-clicking it invites them into the local player's faction.
+                            let mut sender = short_identity(&message.sender_id);
+                            if let Some(tag) = faction_tag(&stdb, &message.sender_id) {
+                                sender = format!("[{tag}] {sender}");
+                            }
+                            let link = ui.link(format!("{sender}:"));
+                            if message.sender_id != stdb.identity() {
+                                if link.clicked() {
+                                    panel.channel = ChatChannel::Private;
+                                    panel.private_to = Some(message.sender_id);
+                                }
+                                link.context_menu(|ui| {
+                                    if ui.button("Invite to faction").clicked() {
+                                        stdb.reducers()
+                                            .faction_invite_player(message.sender_id)
+                                            .unwrap();
+                                        ui.close_menu();
+                                    }
+                                });
+                            }
+                            ui.label(&message.text);
+                        });
+                    }
+                });
+            ui.separator();
+
+            ui.horizontal(|ui| {
+                for channel in [ChatChannel::Global, ChatChannel::Local] {
+                    ui.selectable_value(&mut panel.channel, channel, format!("{channel:?}"));
+                }
+                if let Some(private_to) = panel.private_to {
+                    ui.selectable_value(
+                        &mut panel.channel,
+                        ChatChannel::Private,
+                        format!("To {}", short_identity(&private_to)),
+                    );
+                }
+            });
+
+            let input = ui.add(
+                egui::TextEdit::singleline(&mut panel.input)
+                    .hint_text("Press Enter to chat")
+                    .desired_width(f32::INFINITY),
+            );
+            let enter = ui.input(|input| input.key_pressed(egui::Key::Enter));
+            if input.lost_focus() && enter {
+                if !panel.input.trim().is_empty() {
+                    let text = std::mem::take(&mut panel.input);
+                    let recipient_id = match panel.channel {
+                        ChatChannel::Private => panel.private_to,
+                        _ => None,
+                    };
+                    panel.error = None;
+                    stdb.reducers()
+                        .send_message(panel.channel, recipient_id, text)
+                        .unwrap();
+                }
+            } else if enter && !input.has_focus() {
+                input.request_focus();
+            }
+
+            if let Some(error) = &panel.error {
+                ui.colored_label(egui::Color32::LIGHT_RED, error);
+            }
+        });
+
+    Ok(())
+}
@@ -0,0 +1,271 @@
+use std::sync::mpsc::Sender;
+
+use bevy::prelude::*;
+use bevy_inspector_egui::bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
+use bevy_spacetimedb::AddEventChannelAppExtensions;
+use spacetimedb_sdk::{Identity, Status, Table};
+
+use crate::{
+    GameState,
+    bindings::{
+        FactionInviteTableAccess, FactionMemberTableAccess, FactionRank, FactionTableAccess,
+        ShipTableAccess, faction_create, faction_decline_invite, faction_invite_player,
+        faction_join, faction_kick, faction_leave, faction_set_rank, faction_transfer_ship,
+    },
+    chat::short_identity,
+    ships::{ControlledShip, Ship},
+    spacetimedb::SpacetimeDB,
+};
+
+/// Lowest rank allowed to invite players into the faction, and to kick members of a lower rank.
+///
+/// Mirrors `MANAGE_MEMBERS_RANK` in the server's `factions.rs`.
+const MANAGE_MEMBERS_RANK: FactionRank = FactionRank::Officer;
+
+const RANKS: [FactionRank; 4] = [
+    FactionRank::Recruit,
+    FactionRank::Pilot,
+    FactionRank::Officer,
+    FactionRank::Leader,
+];
+
+/// Sent when the server refuses a faction action, with the reason to show in the faction panel.
+#[derive(Event, Debug)]
+struct FactionFailedEvent(String);
+
+#[derive(Resource)]
+struct FactionFailedSender(Sender<FactionFailedEvent>);
+
+/// State of the faction panel between frames.
+#[derive(Resource, Default)]
+struct FactionPanel {
+    /// Name and tag of the faction being created.
+    name: String,
+    tag: String,
+    /// Why the last faction action was refused, if it was.
+    error: Option<String>,
+}
+
+pub struct FactionsPlugin;
+
+impl Plugin for FactionsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FactionPanel>()
+            .add_systems(OnEnter(GameState::InGame), register_faction_callbacks)
+            .add_systems(Update, on_faction_failed)
+            .add_systems(
+                EguiPrimaryContextPass,
+                show_faction_panel.run_if(in_state(GameState::InGame)),
+            );
+
+        let (send, recv) = std::sync::mpsc::channel();
+        app.insert_resource(FactionFailedSender(send));
+        app.add_event_channel::<FactionFailedEvent>(recv);
+    }
+}
+
+fn report_failure(sender: &Sender<FactionFailedEvent>, action: &str, status: &Status) {
+    if let Status::Failed(err) = status {
+        debug!("Failed to {action}: {err}");
+        sender.send(FactionFailedEvent(err.to_string())).unwrap();
+    }
+}
+
+fn register_faction_callbacks(stdb: SpacetimeDB, sender: Res<FactionFailedSender>) {
+    let reducers = stdb.reducers();
+    let create_sender = sender.0.clone();
+    reducers.on_faction_create(move |ctx, _, _| {
+        report_failure(&create_sender, "create faction", &ctx.event.status)
+    });
+    let invite_sender = sender.0.clone();
+    reducers.on_faction_invite_player(move |ctx, _| {
+        report_failure(&invite_sender, "invite player", &ctx.event.status)
+    });
+    let join_sender = sender.0.clone();
+    reducers.on_faction_join(move |ctx, _| {
+        report_failure(&join_sender, "join faction", &ctx.event.status)
+    });
+    let leave_sender = sender.0.clone();
+    reducers.on_faction_leave(move |ctx| {
+        report_failure(&leave_sender, "leave faction", &ctx.event.status)
+    });
+    let kick_sender = sender.0.clone();
+    reducers.on_faction_kick(move |ctx, _| {
+        report_failure(&kick_sender, "kick member", &ctx.event.status)
+    });
+    let rank_sender = sender.0.clone();
+    reducers.on_faction_set_rank(move |ctx, _, _| {
+        report_failure(&rank_sender, "set rank", &ctx.event.status)
+    });
+    let transfer_sender = sender.0.clone();
+    reducers.on_faction_transfer_ship(move |ctx, _| {
+        report_failure(&transfer_sender, "hand ship over", &ctx.event.status)
+    });
+}
+
+fn on_faction_failed(mut events: EventReader<FactionFailedEvent>, mut panel: ResMut<FactionPanel>) {
+    for event in events.read() {
+        panel.error = Some(event.0.clone());
+    }
+}
+
+/// Tag of the faction a player belongs to, if any.
+pub fn faction_tag(stdb: &SpacetimeDB, player_id: &Identity) -> Option<String> {
+    let member = stdb.db().faction_member().player_id().find(player_id)?;
+    let faction = stdb.db().faction().id().find(&member.faction_id)?;
+    Some(faction.tag)
+}
+
+/// Shows the local player's faction and lets them manage it, or the invitations they received
+/// and a form to found their own while they are in none.
+fn show_faction_panel(
+    ship: Option<Single<&Ship, With<ControlledShip>>>,
+    mut panel: ResMut<FactionPanel>,
+    mut contexts: EguiContexts,
+    stdb: SpacetimeDB,
+) -> Result {
+    let local_player = stdb.identity();
+    let membership = stdb.db().faction_member().player_id().find(&local_player);
+    let faction = membership
+        .as_ref()
+        .and_then(|member| stdb.db().faction().id().find(&member.faction_id));
+    let title = match &faction {
+        Some(faction) => format!("[{}] {}", faction.tag, faction.name),
+        None => "Faction".to_string(),
+    };
+
+    egui::Window::new(title)
+        .id(egui::Id::new("faction"))
+        .resizable(false)
+        .default_open(false)
+        .anchor(egui::Align2::RIGHT_TOP, [-10.0, 10.0])
+        .show(contexts.ctx_mut()?, |ui| {
+            match (membership, faction) {
+                (Some(membership), Some(faction)) => {
+                    let rank = membership.rank;
+                    let mut members = stdb
+                        .db()
+                        .faction_member()
+                        .iter()
+                        .filter(|member| member.faction_id == faction.id)
+                        .collect::<Vec<_>>();
+                    members.sort_by_key(|member| {
+                        (std::cmp::Reverse(member.rank as u8), member.joined_at)
+                    });
+
+                    egui::Grid::new("faction_members")
+                        .striped(true)
+                        .show(ui, |ui| {
+                            for member in &members {
+                                ui.label(short_identity(&member.player_id));
+                                let others = member.player_id != local_player;
+
+                                if others && rank == FactionRank::Leader {
+                                    egui::ComboBox::from_id_salt(member.player_id)
+                                        .selected_text(format!("{:?}", member.rank))
+                                        .show_ui(ui, |ui| {
+                                            for new_rank in RANKS {
+                                                if ui
+                                                    .selectable_label(
+                                                        member.rank == new_rank,
+                                                        format!("{new_rank:?}"),
+                                                    )
+                                                    .clicked()
+                                                    && member.rank != new_rank
+                                                {
+                                                    stdb.reducers()
+                                                        .faction_set_rank(
+                                                            member.player_id,
+                                                            new_rank,
+                                                        )
+                                                        .unwrap();
+                                                }
+                                            }
+                                        });
+                                } else {
+                                    ui.label(format!("{:?}", member.rank));
+                                }
+
+                                if others
+                                    && rank as u8 >= MANAGE_MEMBERS_RANK as u8
+                                    && rank as u8 > member.rank as u8
+                                    && ui.button("Kick").clicked()
+                                {
+                                    stdb.reducers().faction_kick(member.player_id).unwrap();
+                                }
+                                ui.end_row();
+                            }
+                        });
+                    if rank as u8 >= MANAGE_MEMBERS_RANK as u8 {
+                        ui.weak("Invite players from their name in the chat.");
+                    }
+                    ui.separator();
+
+                    // Ships handed over to the faction can be flown by its members, see the
+                    // server's `factions::can_pilot_faction_ship`.
+                    if let Some(ship) = ship
+                        && stdb.db().ship().id().find(&ship.id).is_some_and(|ship| {
+                            ship.owner_id == local_player && ship.faction_id.is_none()
+                        })
+                        && ui
+                            .button("Hand controlled ship over to the faction")
+                            .clicked()
+                    {
+                        stdb.reducers().faction_transfer_ship(ship.id).unwrap();
+                    }
+
+                    let leave = if members.len() == 1 {
+                        "Disband faction"
+                    } else {
+                        "Leave faction"
+                    };
+                    if ui.button(leave).clicked() {
+                        stdb.reducers().faction_leave().unwrap();
+                    }
+                }
+                _ => {
+                    for invite in stdb
+                        .db()
+                        .faction_invite()
+                        .iter()
+                        .filter(|invite| invite.player_id == local_player)
+                    {
+                        let Some(faction) = stdb.db().faction().id().find(&invite.faction_id)
+                        else {
+                            continue;
+                        };
+                        ui.horizontal(|ui| {
+                            ui.label(format!("Invited to [{}] {}", faction.tag, faction.name));
+                            if ui.button("Join").clicked() {
+                                stdb.reducers().faction_join(faction.id).unwrap();
+                            }
+                            if ui.button("Decline").clicked() {
+                                stdb.reducers().faction_decline_invite(faction.id).unwrap();
+                            }
+                        });
+                    }
+                    ui.separator();
+
+                    egui::Grid::new("faction_create").show(ui, |ui| {
+                        ui.label("Name");
+                        ui.text_edit_singleline(&mut panel.name);
+                        ui.end_row();
+                        ui.label("Tag");
+                        ui.text_edit_singleline(&mut panel.tag);
+                        ui.end_row();
+                    });
+                    if ui.button("Found faction").clicked() {
+                        panel.error = None;
+                        let (name, tag) = (panel.name.clone(), panel.tag.clone());
+                        stdb.reducers().faction_create(name, tag).unwrap();
+                    }
+                }
+            }
+
+            if let Some(error) = &panel.error {
+                ui.colored_label(egui::Color32::LIGHT_RED, error);
+            }
+        });
+
+    Ok(())
+}
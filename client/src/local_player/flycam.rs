@@ -2,7 +2,7 @@ use bevy::{prelude::*, window::CursorGrabMode};
 use bevy_enhanced_input::prelude::*;
 use log::debug;
 
-use crate::GameState;
+use crate::{GameState, bindings::player_move, spacetimedb::SpacetimeDB};
 
 use super::LocalPlayerState;
 #[derive(InputAction)]
@@ -28,6 +28,27 @@ struct ToggleCaptureCursor;
 #[derive(Component)]
 pub struct PlayerFlyCam;
 
+#[derive(Component, Debug)]
+pub struct PlayerLocationUpdate {
+    timer: Timer,
+    last_position: Vec3,
+    last_rotation: Quat,
+    position_threshold: f32,
+    rotation_threshold: f32,
+}
+
+impl Default for PlayerLocationUpdate {
+    fn default() -> Self {
+        Self {
+            timer: Timer::from_seconds(0.1, TimerMode::Repeating),
+            last_position: Vec3::ZERO,
+            last_rotation: Quat::IDENTITY,
+            position_threshold: 0.1,
+            rotation_threshold: 0.01,
+        }
+    }
+}
+
 pub struct LocalPlayerFlycamPlugin;
 
 impl Plugin for LocalPlayerFlycamPlugin {
@@ -43,6 +64,10 @@ impl Plugin for LocalPlayerFlycamPlugin {
                 Update,
                 apply_movement.run_if(in_state(LocalPlayerState::OnFoot)),
             )
+            .add_systems(
+                PostUpdate,
+                send_location_updates.run_if(in_state(LocalPlayerState::OnFoot)),
+            )
             .add_observer(rotate)
             .add_observer(capture_cursor);
     }
@@ -124,6 +149,34 @@ fn apply_movement(
     transform.translation += rotation * movement * speed * time.delta_secs();
 }
 
+fn send_location_updates(
+    player: Single<(&Transform, &mut PlayerLocationUpdate), With<PlayerFlyCam>>,
+    time: Res<Time>,
+    stdb: SpacetimeDB,
+) -> Result {
+    let (player_transform, mut update) = player.into_inner();
+    if !update.timer.tick(time.delta()).just_finished() {
+        return Ok(());
+    }
+
+    let pos = player_transform.translation;
+    let rot = player_transform.rotation;
+
+    let pos_diff = update.last_position.distance(pos);
+    let rot_diff = update.last_rotation.angle_between(rot);
+    if pos_diff < update.position_threshold && rot_diff < update.rotation_threshold {
+        return Ok(());
+    }
+
+    stdb.reducers()
+        .player_move(pos.x, pos.y, pos.z, rot.x, rot.y, rot.z, rot.w)?;
+
+    update.last_position = pos;
+    update.last_rotation = rot;
+
+    Ok(())
+}
+
 fn rotate(
     trigger: Trigger<Fired<Rotate>>,
     mut players: Query<&mut Transform, With<PlayerFlyCam>>,
@@ -1,6 +1,6 @@
 use avian3d::prelude::*;
 use bevy::prelude::*;
-use bevy_spacetimedb::{InsertEvent, ReadDeleteEvent, ReadInsertEvent};
+use bevy_spacetimedb::{InsertEvent, ReadDeleteEvent, ReadInsertEvent, ReadUpdateEvent};
 use log::debug;
 
 use crate::{
@@ -10,10 +10,17 @@ use crate::{
     spacetimedb::SpacetimeDB,
 };
 
-use super::{LocalPlayerState, flycam::PlayerFlyCam};
+use super::{
+    LocalPlayerState,
+    flycam::{PlayerFlyCam, PlayerLocationUpdate},
+};
 
 const MAX_INTERACTION_DISTANCE: f32 = 40.0;
 
+/// How far from the local player, in world units, a location from the server must be to be
+/// taken as the server moving them rather than an echo of their own moves.
+const TELEPORT_DISTANCE: f32 = 500.0;
+
 #[derive(Component)]
 pub struct LocalPlayer;
 
@@ -23,7 +30,12 @@ impl Plugin for LocalPlayerLifecyclePlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
             PreUpdate,
-            (on_local_player_inserted, on_player_location_inserted).chain(),
+            (
+                on_local_player_inserted,
+                on_player_location_inserted,
+                on_player_location_updated,
+            )
+                .chain(),
         )
         .add_systems(PostUpdate, on_player_location_deleted);
     }
@@ -81,6 +93,7 @@ fn on_player_location_inserted(
         debug!("Inserting player location: {player_location:?}");
         commands.entity(player_entity.entity()).insert((
             PlayerFlyCam,
+            PlayerLocationUpdate::default(),
             Visibility::Visible,
             Transform {
                 translation: Vec3::new(player_location.x, player_location.y, player_location.z),
@@ -97,6 +110,26 @@ fn on_player_location_inserted(
     }
 }
 
+/// Follows the server when it moves the local player, like an admin teleporting them.
+fn on_player_location_updated(
+    mut events: ReadUpdateEvent<PlayerLocation>,
+    player: Option<Single<&mut Transform, (With<LocalPlayer>, With<PlayerFlyCam>)>>,
+    stdb: SpacetimeDB,
+) {
+    let Some(mut transform) = player else {
+        return;
+    };
+
+    for event in events.read().filter(|e| e.new.player_id == stdb.identity()) {
+        let location = &event.new;
+        let position = Vec3::new(location.x, location.y, location.z);
+        if transform.translation.distance(position) > TELEPORT_DISTANCE {
+            debug!("Local player moved by the server to {position}");
+            transform.translation = position;
+        }
+    }
+}
+
 fn on_player_location_deleted(
     mut commands: Commands,
     mut events: ReadDeleteEvent<PlayerLocation>,
@@ -112,6 +145,7 @@ fn on_player_location_deleted(
         commands
             .entity(player_entity.entity())
             .remove::<PlayerFlyCam>()
+            .remove::<PlayerLocationUpdate>()
             .insert(Visibility::Hidden);
         player_state.set(LocalPlayerState::InShip);
     }
@@ -17,6 +17,8 @@ mod lifecycle;
 mod ui;
 mod world_interactions;
 
+pub use lifecycle::LocalPlayer;
+
 #[derive(States, Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
 pub enum LocalPlayerState {
     #[default]
@@ -1,16 +1,39 @@
 use bevy::prelude::*;
 
-use super::LocalPlayerState;
+use crate::{bindings::ShipTypeTableAccess, spacetimedb::SpacetimeDB};
+
+use super::{
+    LocalPlayerState,
+    world_interactions::{InteractionFailedEvent, SelectedShipType},
+};
+
+/// How long an interaction message stays on screen, in seconds.
+const INTERACTION_MESSAGE_DURATION: f32 = 3.0;
 
 #[derive(Component)]
 pub struct OnFootUi;
 
+#[derive(Component)]
+struct InteractionMessage(Timer);
+
+#[derive(Component)]
+struct SelectedShipTypeText;
+
 pub struct LocalPlayerUiPlugin;
 
 impl Plugin for LocalPlayerUiPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(OnEnter(LocalPlayerState::OnFoot), spawn_on_foot_ui)
-            .add_systems(OnExit(LocalPlayerState::OnFoot), despawn_on_foot_ui);
+            .add_systems(OnExit(LocalPlayerState::OnFoot), despawn_on_foot_ui)
+            .add_systems(
+                Update,
+                (
+                    show_interaction_messages,
+                    expire_interaction_messages,
+                    update_selected_ship_type_text,
+                )
+                    .run_if(in_state(LocalPlayerState::OnFoot)),
+            );
     }
 }
 
@@ -24,6 +47,16 @@ fn spawn_on_foot_ui(mut commands: Commands) {
         BackgroundColor(Color::WHITE),
     );
 
+    let selected_ship_type = (
+        SelectedShipTypeText,
+        Text::default(),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(20.0),
+            ..Default::default()
+        },
+    );
+
     commands.spawn((
         OnFootUi,
         Name::new("On Foot UI"),
@@ -36,7 +69,7 @@ fn spawn_on_foot_ui(mut commands: Commands) {
             align_items: AlignItems::Center,
             ..Default::default()
         },
-        children![crosshair],
+        children![crosshair, selected_ship_type],
     ));
 }
 
@@ -45,3 +78,51 @@ fn despawn_on_foot_ui(mut commands: Commands, query: Query<Entity, With<OnFootUi
         commands.entity(entity).despawn();
     }
 }
+
+fn show_interaction_messages(
+    mut commands: Commands,
+    mut events: EventReader<InteractionFailedEvent>,
+    ui: Single<Entity, With<OnFootUi>>,
+) {
+    for event in events.read() {
+        commands.entity(*ui).with_child((
+            InteractionMessage(Timer::from_seconds(
+                INTERACTION_MESSAGE_DURATION,
+                TimerMode::Once,
+            )),
+            Text::new(event.0.clone()),
+            TextColor(Color::srgb(1.0, 0.4, 0.4)),
+            Node {
+                margin: UiRect::top(Val::Px(20.0)),
+                ..Default::default()
+            },
+        ));
+    }
+}
+
+fn expire_interaction_messages(
+    mut commands: Commands,
+    mut messages: Query<(Entity, &mut InteractionMessage)>,
+    time: Res<Time>,
+) {
+    for (entity, mut message) in messages.iter_mut() {
+        if message.0.tick(time.delta()).finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn update_selected_ship_type_text(
+    mut text: Single<&mut Text, With<SelectedShipTypeText>>,
+    selected: Res<SelectedShipType>,
+    stdb: SpacetimeDB,
+) {
+    let name = selected
+        .0
+        .and_then(|id| stdb.db().ship_type().id().find(&id))
+        .map_or_else(|| "None".to_string(), |ship_type| ship_type.name);
+    let value = format!("Ship: {name} (T to change, R to spawn)");
+    if text.0 != value {
+        text.0 = value;
+    }
+}
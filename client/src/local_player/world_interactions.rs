@@ -1,22 +1,99 @@
+use std::sync::mpsc::Sender;
+
 use avian3d::prelude::RayHits;
 use bevy::prelude::*;
+use bevy_spacetimedb::{AddEventChannelAppExtensions, ReadInsertEvent};
+use simulation::ranges::ship_spawn_distance;
+use spacetimedb_sdk::{DbContext, Status, Table};
 
 use crate::{
-    bindings::{player_enter_ship, player_spawn_ship},
+    GameState,
+    bindings::{RejectedCall, ShipTypeTableAccess, player_enter_ship, player_spawn_ship},
     ships::Ship,
     spacetimedb::SpacetimeDB,
 };
 
 use super::{LocalPlayerState, lifecycle::LocalPlayer};
 
+/// Sent when the server refuses an interaction, with the reason to show to the player.
+#[derive(Event, Debug)]
+pub struct InteractionFailedEvent(pub String);
+
+/// Reducers behind the interactions of the player on foot, whose refusals are shown to them.
+const INTERACTION_REDUCERS: [&str; 2] = ["player_enter_ship", "player_spawn_ship"];
+
+/// The ship type spawned by the local player, cycled through with T.
+#[derive(Resource, Debug, Default)]
+pub struct SelectedShipType(pub Option<u64>);
+
+#[derive(Resource)]
+struct InteractionFailedSender(Sender<InteractionFailedEvent>);
+
 pub struct WorldInteractionPlugin;
 
 impl Plugin for WorldInteractionPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            Update,
-            (enter_ship_interaction, spawn_ship).run_if(in_state(LocalPlayerState::OnFoot)),
-        );
+        app.init_resource::<SelectedShipType>()
+            .add_systems(
+                OnEnter(GameState::InGame),
+                (register_interaction_callbacks, select_first_ship_type),
+            )
+            .add_systems(
+                Update,
+                report_rejected_calls.run_if(in_state(GameState::InGame)),
+            )
+            .add_systems(
+                Update,
+                (
+                    enter_ship_interaction,
+                    cycle_ship_type,
+                    spawn_ship.after(cycle_ship_type),
+                )
+                    .run_if(in_state(LocalPlayerState::OnFoot)),
+            );
+
+        let (send, recv) = std::sync::mpsc::channel();
+        app.insert_resource(InteractionFailedSender(send));
+        app.add_event_channel::<InteractionFailedEvent>(recv);
+    }
+}
+
+fn register_interaction_callbacks(stdb: SpacetimeDB, sender: Res<InteractionFailedSender>) {
+    let enter_sender = sender.0.clone();
+    stdb.reducers().on_player_enter_ship(move |ctx, ship_id| {
+        if let Status::Failed(err) = &ctx.event.status {
+            debug!("Failed to enter ship {ship_id}: {err}");
+            enter_sender
+                .send(InteractionFailedEvent(err.to_string()))
+                .unwrap();
+        }
+    });
+
+    let spawn_sender = sender.0.clone();
+    stdb.reducers()
+        .on_player_spawn_ship(move |ctx, ship_type_id, _, _, _, _, _, _, _| {
+            if let Status::Failed(err) = &ctx.event.status {
+                debug!("Failed to spawn ship of type {ship_type_id}: {err}");
+                spawn_sender
+                    .send(InteractionFailedEvent(err.to_string()))
+                    .unwrap();
+            }
+        });
+}
+
+/// Reports the calls of the local player refused for going over their rate limit. Rate-limited
+/// reducers don't fail when they refuse a call, they record why in `rejected_call` instead.
+fn report_rejected_calls(
+    mut events: ReadInsertEvent<RejectedCall>,
+    mut failed: EventWriter<InteractionFailedEvent>,
+    stdb: SpacetimeDB,
+) {
+    for event in events.read().filter(|e| e.row.player_id == stdb.identity()) {
+        let call = &event.row;
+        debug!("Call to {} refused: {}", call.reducer, call.reason);
+        if INTERACTION_REDUCERS.contains(&call.reducer.as_str()) {
+            failed.write(InteractionFailedEvent(call.reason.clone()));
+        }
     }
 }
 
@@ -36,8 +113,40 @@ fn enter_ship_interaction(
     }
 }
 
+fn select_first_ship_type(mut selected: ResMut<SelectedShipType>, stdb: SpacetimeDB) {
+    selected.0 = next_ship_type(&stdb, None);
+}
+
+fn cycle_ship_type(
+    mut selected: ResMut<SelectedShipType>,
+    stdb: SpacetimeDB,
+    input: Res<ButtonInput<KeyCode>>,
+) {
+    if input.just_pressed(KeyCode::KeyT) {
+        selected.0 = next_ship_type(&stdb, selected.0);
+    }
+}
+
+/// Returns the ship type after `current` that can still be spawned, wrapping around to the first.
+fn next_ship_type(stdb: &SpacetimeDB, current: Option<u64>) -> Option<u64> {
+    let mut ship_types = stdb
+        .db()
+        .ship_type()
+        .iter()
+        .filter(|ship_type| !ship_type.retired)
+        .map(|ship_type| ship_type.id)
+        .collect::<Vec<_>>();
+    ship_types.sort_unstable();
+
+    current
+        .and_then(|current| ship_types.iter().find(|id| **id > current))
+        .or(ship_types.first())
+        .copied()
+}
+
 fn spawn_ship(
     player: Single<&Transform, With<LocalPlayer>>,
+    selected: Res<SelectedShipType>,
     stdb: SpacetimeDB,
     input: Res<ButtonInput<KeyCode>>,
 ) {
@@ -45,10 +154,24 @@ fn spawn_ship(
         return;
     }
 
-    // Spawn ship 20 units in front of the player
-    let postion = player.translation + player.forward() * 20.0;
+    let Some(ship_type) = selected
+        .0
+        .and_then(|id| stdb.db().ship_type().id().find(&id))
+    else {
+        warn!("No ship type selected");
+        return;
+    };
+
+    let exit_offset = Vec3::new(
+        ship_type.exit_offset_x,
+        ship_type.exit_offset_y,
+        ship_type.exit_offset_z,
+    );
+    let distance = ship_spawn_distance(exit_offset);
+    let postion = player.translation + player.forward() * distance;
     stdb.reducers()
         .player_spawn_ship(
+            ship_type.id,
             postion.x,
             postion.y,
             postion.z,
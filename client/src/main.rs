@@ -2,9 +2,11 @@ use assets_loader::AssetsLoaderPlugin;
 use avian3d::{PhysicsPlugins, prelude::Gravity};
 use bevy::{prelude::*, window::WindowMode};
 use bevy_enhanced_input::EnhancedInputPlugin;
-use bevy_inspector_egui::bevy_egui::EguiPlugin;
+use bevy_inspector_egui::bevy_egui::{EguiGlobalSettings, EguiPlugin};
 #[cfg(feature = "dev")]
 use bevy_inspector_egui::quick::WorldInspectorPlugin;
+use chat::ChatPlugin;
+use factions::FactionsPlugin;
 use local_player::LocalPlayerPlugin;
 use materials::MaterialsPlugin;
 use shaders::ShadersPlugin;
@@ -14,6 +16,8 @@ use world::WorldPlugin;
 
 mod assets_loader;
 mod bindings;
+mod chat;
+mod factions;
 mod local_player;
 mod materials;
 mod shaders;
@@ -27,6 +31,7 @@ enum GameState {
     Loading,
     WaitingForConnection,
     StaticDataLoading,
+    ShipAssetsLoading,
     InGame,
 }
 
@@ -52,6 +57,11 @@ fn main() -> AppExit {
     .init_state::<GameState>()
     .add_plugins(ShadersPlugin)
     .add_plugins(EguiPlugin::default())
+    // Keep the keyboard from steering the game while text is typed in the UI, e.g. in the chat.
+    .insert_resource(EguiGlobalSettings {
+        enable_absorb_bevy_input_system: true,
+        ..default()
+    })
     .add_plugins((
         EnhancedInputPlugin,
         PhysicsPlugins::default(),
@@ -64,6 +74,8 @@ fn main() -> AppExit {
         LocalPlayerPlugin,
         ShipsPlugin,
         WorldPlugin,
+        ChatPlugin,
+        FactionsPlugin,
     ))
     .insert_resource(Gravity(Vec3::ZERO));
 
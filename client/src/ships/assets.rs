@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use spacetimedb_sdk::Table;
+
+use crate::{GameState, bindings::ShipTypeTableAccess, spacetimedb::SpacetimeDB};
+
+/// Loads the model and collider of every ship type once the static data is available.
+pub struct ShipAssetsPlugin;
+
+impl Plugin for ShipAssetsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ShipAssets>()
+            .add_systems(OnEnter(GameState::ShipAssetsLoading), load_ship_assets)
+            .add_systems(
+                Update,
+                check_ship_assets_loaded.run_if(in_state(GameState::ShipAssetsLoading)),
+            );
+    }
+}
+
+/// Handles to the assets of a ship type, resolved from the paths in its `ShipType` row.
+#[derive(Debug, Clone)]
+pub struct ShipTypeAssets {
+    pub model: Handle<Scene>,
+    pub collider: Handle<Mesh>,
+}
+
+/// The assets of every ship type, keyed by ship type id.
+#[derive(Resource, Default, Debug)]
+pub struct ShipAssets {
+    ship_types: HashMap<u64, ShipTypeAssets>,
+}
+
+impl ShipAssets {
+    pub fn get(&self, ship_type_id: u64) -> Option<&ShipTypeAssets> {
+        self.ship_types.get(&ship_type_id)
+    }
+}
+
+fn load_ship_assets(
+    mut ship_assets: ResMut<ShipAssets>,
+    asset_server: Res<AssetServer>,
+    stdb: SpacetimeDB,
+) {
+    for ship_type in stdb.db().ship_type().iter() {
+        debug!("Loading assets for ship type: {}", ship_type.name);
+        ship_assets.ship_types.insert(
+            ship_type.id,
+            ShipTypeAssets {
+                model: asset_server.load(format!("{}#Scene0", ship_type.model)),
+                collider: asset_server.load(format!("{}#Mesh0/Primitive0", ship_type.collider)),
+            },
+        );
+    }
+}
+
+fn check_ship_assets_loaded(
+    ship_assets: Res<ShipAssets>,
+    asset_server: Res<AssetServer>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let mut loaded = true;
+    for assets in ship_assets.ship_types.values() {
+        for id in [assets.model.id().untyped(), assets.collider.id().untyped()] {
+            if asset_server.load_state(id).is_failed() {
+                panic!("Failed to load ship asset: {:?}", asset_server.get_path(id));
+            }
+            loaded &= asset_server.is_loaded_with_dependencies(id);
+        }
+    }
+
+    if loaded {
+        info!("Ship assets loaded successfully.");
+        next_state.set(GameState::InGame);
+    }
+}
@@ -0,0 +1,88 @@
+use avian3d::prelude::Mass;
+use bevy::prelude::*;
+use bevy_spacetimedb::{ReadDeleteEvent, ReadInsertEvent, ReadUpdateEvent};
+use spacetimedb_sdk::Table;
+
+use crate::{
+    bindings::{
+        Cargo, CargoTableAccess, ItemTypeTableAccess, ShipTableAccess, ShipType,
+        ShipTypeTableAccess,
+    },
+    spacetimedb::SpacetimeDB,
+};
+
+use super::resources::ShipsRegistry;
+
+pub struct ShipCargoPlugin;
+
+impl Plugin for ShipCargoPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, update_ship_mass);
+    }
+}
+
+/// Mass of a ship including everything in its cargo hold, as far as the client knows.
+pub fn ship_mass(stdb: &SpacetimeDB, ship_id: u64, ship_type: &ShipType) -> f32 {
+    let cargo_mass: f32 = stdb
+        .db()
+        .cargo()
+        .iter()
+        .filter(|cargo| cargo.ship_id == ship_id)
+        .filter_map(|cargo| {
+            let item_type = stdb.db().item_type().id().find(&cargo.item_type_id)?;
+            Some(item_type.mass * cargo.quantity as f32)
+        })
+        .sum();
+
+    ship_type.mass + cargo_mass
+}
+
+/// Total volume of the items in a ship's cargo hold, as far as the client knows.
+pub fn cargo_volume(stdb: &SpacetimeDB, ship_id: u64) -> f32 {
+    stdb.db()
+        .cargo()
+        .iter()
+        .filter(|cargo| cargo.ship_id == ship_id)
+        .filter_map(|cargo| {
+            let item_type = stdb.db().item_type().id().find(&cargo.item_type_id)?;
+            Some(item_type.volume * cargo.quantity as f32)
+        })
+        .sum()
+}
+
+/// Keeps the mass of ships in sync with their cargo as it's mined, jettisoned or transferred.
+fn update_ship_mass(
+    mut inserted: ReadInsertEvent<Cargo>,
+    mut updated: ReadUpdateEvent<Cargo>,
+    mut deleted: ReadDeleteEvent<Cargo>,
+    mut masses: Query<&mut Mass>,
+    ships: Res<ShipsRegistry>,
+    stdb: SpacetimeDB,
+) {
+    let ship_ids = inserted
+        .read()
+        .map(|event| event.row.ship_id)
+        .chain(updated.read().map(|event| event.new.ship_id))
+        .chain(deleted.read().map(|event| event.row.ship_id))
+        .collect::<Vec<_>>();
+
+    for ship_id in ship_ids {
+        let Some(ship) = ships.get(ship_id) else {
+            continue;
+        };
+        let Ok(mut mass) = masses.get_mut(ship.entity()) else {
+            continue;
+        };
+        let Some(ship_type) = stdb
+            .db()
+            .ship()
+            .id()
+            .find(&ship_id)
+            .and_then(|ship| stdb.db().ship_type().id().find(&ship.ship_type_id))
+        else {
+            continue;
+        };
+
+        mass.0 = ship_mass(&stdb, ship_id, &ship_type);
+    }
+}
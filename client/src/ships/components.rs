@@ -1,4 +1,7 @@
+use std::time::Duration;
+
 use bevy::prelude::*;
+use simulation::flight::{FlightControls, FlightState};
 
 #[derive(Component, Debug)]
 pub struct Ship {
@@ -11,3 +14,43 @@ pub struct ControlledShip;
 
 #[derive(Component, Debug)]
 pub struct ShipRotationTarget;
+
+/// A ship attached to a station's docking bay, following the station's rotation instead of
+/// flying freely.
+#[derive(Component, Debug)]
+pub struct Docked {
+    pub station_id: u64,
+    /// Position of the ship relative to the station, in the station's rotating frame.
+    pub offset: Vec3,
+    /// Rotation of the ship relative to the station.
+    pub rotation: Quat,
+}
+
+/// A controlled ship flown by the server from the controls the local player sends, rather than
+/// by the physics engine. Its flight is predicted with the server's flight model between the
+/// server's updates.
+#[derive(Component, Debug)]
+pub struct ServerFlown {
+    /// Predicted state of the ship after the last step, and before it, the ship being shown in
+    /// between.
+    pub state: FlightState,
+    pub previous: FlightState,
+    /// Time elapsed since the last step.
+    pub pending: Duration,
+    /// Controls last sent to the server, and when they can be sent again.
+    pub sent: FlightControls,
+    pub send_timer: Timer,
+}
+
+impl ServerFlown {
+    pub fn new(transform: &Transform, send_interval: Duration) -> Self {
+        let state = FlightState::at_rest(transform.translation, transform.rotation);
+        Self {
+            state,
+            previous: state,
+            pending: Duration::ZERO,
+            sent: FlightControls::default(),
+            send_timer: Timer::new(send_interval, TimerMode::Once),
+        }
+    }
+}
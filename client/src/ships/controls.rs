@@ -1,22 +1,35 @@
-use avian3d::prelude::{ExternalForce, ExternalTorque, RigidBody};
+use std::time::Duration;
+
+use avian3d::prelude::{AngularVelocity, ExternalForce, ExternalTorque, LinearVelocity, RigidBody};
 use bevy::{
     prelude::*,
     window::{CursorGrabMode, Window},
 };
 use bevy_enhanced_input::prelude::*;
-use bevy_spacetimedb::{InsertEvent, ReadDeleteEvent, ReadInsertEvent};
+use bevy_spacetimedb::{InsertEvent, ReadDeleteEvent, ReadInsertEvent, ReadUpdateEvent};
+use simulation::flight::{FLIGHT_STEP, Power, ShipDynamics, forces, step};
+use spacetimedb_sdk::Timestamp;
 
 use crate::{
     GameState,
     bindings::{
-        ShipPilot, ShipTableAccess, ShipTypeTableAccess, player_leave_ship, player_move_ship,
+        self, ShipDockingTableAccess, ShipFlight, ShipLocationTableAccess, ShipPilot,
+        ShipPilotTableAccess, ShipStatusTableAccess, ShipTableAccess, ShipType,
+        ShipTypeTableAccess, player_leave_ship, player_move_ship, player_set_flight_controls,
     },
     local_player::PlayerCamera,
-    ships::components::ControlledShip,
+    ships::components::{ControlledShip, Docked, ServerFlown},
     spacetimedb::SpacetimeDB,
 };
 
-use super::{components::Ship, resources::ShipsRegistry};
+use super::{cargo::ship_mass, components::Ship, resources::ShipsRegistry, status::current_energy};
+
+/// How often the controls of a ship flown by the server are sent, when they change.
+const FLIGHT_CONTROLS_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Share of the gap to the server's state a ship flown by the server closes with each of its
+/// updates, the prediction carrying on from there.
+const SERVER_FLIGHT_CORRECTION: f32 = 0.2;
 
 #[derive(Component, Debug, Default, Reflect)]
 struct FlightControls {
@@ -26,6 +39,21 @@ struct FlightControls {
     pub roll: f32,
     pub pitch: f32,
     pub yaw: f32,
+    pub boost: bool,
+}
+
+impl From<&FlightControls> for simulation::flight::FlightControls {
+    fn from(controls: &FlightControls) -> Self {
+        Self {
+            thrust: controls.thrust,
+            lateral_thrust: controls.lateral_thrust,
+            vertical_thrust: controls.vertical_thrust,
+            roll: controls.roll,
+            pitch: controls.pitch,
+            yaw: controls.yaw,
+            boost: controls.boost,
+        }
+    }
 }
 
 #[derive(Component, Debug)]
@@ -60,6 +88,10 @@ struct Roll;
 #[action_output(Vec2)]
 struct PitchYaw;
 
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Boost;
+
 #[derive(InputAction)]
 #[action_output(bool)]
 struct ToggleCaptureCursor;
@@ -76,11 +108,20 @@ impl Plugin for ShipControlsPlugin {
             .add_input_context::<OnPiloting>()
             .add_systems(
                 PreUpdate,
-                (on_ship_pilot_inserted, on_ship_pilot_removed)
+                (
+                    on_ship_pilot_inserted,
+                    on_ship_pilot_removed,
+                    on_ship_flight_inserted,
+                    on_ship_flight_updated,
+                    on_ship_flight_deleted,
+                )
                     .chain()
                     .run_if(in_state(GameState::InGame)),
             )
-            .add_systems(Update, (apply_inputs, apply_movement).chain())
+            .add_systems(
+                Update,
+                (apply_inputs, (apply_movement, fly_server_flown_ship)).chain(),
+            )
             .add_systems(PostUpdate, send_location_updates)
             .add_observer(capture_cursor)
             .add_observer(exit_ship);
@@ -113,11 +154,23 @@ fn on_ship_pilot_inserted(
             window.cursor_options.grab_mode = CursorGrabMode::Locked;
             window.cursor_options.visible = false;
 
+            // A docked ship is carried by its station until it undocks.
+            let docked = stdb
+                .db()
+                .ship_docking()
+                .ship_id()
+                .find(&ship.ship_id)
+                .is_some();
+
             commands.entity(ship_details.entity()).insert((
                 ControlledShip,
                 FlightControls::default(),
                 OnPiloting,
-                RigidBody::Dynamic,
+                if docked {
+                    RigidBody::Kinematic
+                } else {
+                    RigidBody::Dynamic
+                },
                 ShipLocationUpdate {
                     timer: Timer::from_seconds(0.1, TimerMode::Repeating),
                     last_position: Vec3::ZERO,
@@ -162,6 +215,10 @@ fn on_ship_pilot_inserted(
                         Negate::all(),
                         bindings![(Binding::mouse_motion())]
                     ),
+                    (
+                        Action::<Boost>::new(),
+                        bindings![KeyCode::ShiftLeft]
+                    ),
                     (
                         Action::<ToggleCaptureCursor>::new(),
                         Hold::new(0.2),
@@ -217,6 +274,7 @@ fn on_ship_pilot_removed(
                 .remove::<ControlledShip>()
                 .remove::<RigidBody>()
                 .remove::<ShipLocationUpdate>()
+                .remove::<ServerFlown>()
                 .remove_with_requires::<OnPiloting>()
                 .despawn_related::<Actions<OnPiloting>>();
 
@@ -229,12 +287,117 @@ fn on_ship_pilot_removed(
     }
 }
 
+/// Takes the controlled ship out of the physics engine's hands once the server flies it.
+fn on_ship_flight_inserted(
+    mut commands: Commands,
+    mut events: ReadInsertEvent<ShipFlight>,
+    controlled_ships: Query<(&Transform, Has<Docked>), With<ControlledShip>>,
+    ships: Res<ShipsRegistry>,
+) {
+    for event in events.read() {
+        let Some(ship) = ships.get(event.row.ship_id) else {
+            continue;
+        };
+        let Ok((transform, docked)) = controlled_ships.get(ship.entity()) else {
+            continue;
+        };
+
+        debug!("Ship[{}] is now flown by the server", event.row.ship_id);
+        let mut entity = commands.entity(ship.entity());
+        entity.insert(ServerFlown::new(transform, FLIGHT_CONTROLS_INTERVAL));
+        // Docked ships already are, the station carrying them.
+        if !docked {
+            entity.insert((
+                RigidBody::Kinematic,
+                LinearVelocity::ZERO,
+                AngularVelocity::ZERO,
+            ));
+        }
+    }
+}
+
+/// Pulls the predicted flight of the controlled ship towards the state the server reached.
+fn on_ship_flight_updated(
+    mut events: ReadUpdateEvent<ShipFlight>,
+    mut controlled_ships: Query<&mut ServerFlown>,
+    ships: Res<ShipsRegistry>,
+    stdb: SpacetimeDB,
+) {
+    for event in events.read() {
+        let flight = &event.new;
+        let Some(ship) = ships.get(flight.ship_id) else {
+            continue;
+        };
+        let Ok(mut server_flown) = controlled_ships.get_mut(ship.entity()) else {
+            continue;
+        };
+        // Docked ships have no location, they follow their station.
+        let Some(location) = stdb.db().ship_location().ship_id().find(&flight.ship_id) else {
+            continue;
+        };
+
+        let state = &mut server_flown.state;
+        state.position = state.position.lerp(
+            Vec3::new(location.x, location.y, location.z),
+            SERVER_FLIGHT_CORRECTION,
+        );
+        state.rotation = state.rotation.slerp(
+            Quat::from_xyzw(
+                location.rot_x,
+                location.rot_y,
+                location.rot_z,
+                location.rot_w,
+            ),
+            SERVER_FLIGHT_CORRECTION,
+        );
+        state.linear_velocity = Vec3::new(flight.vel_x, flight.vel_y, flight.vel_z);
+        state.angular_velocity = Vec3::new(flight.ang_vel_x, flight.ang_vel_y, flight.ang_vel_z);
+    }
+}
+
+/// Hands the controlled ship back to the physics engine once the server stops flying it.
+fn on_ship_flight_deleted(
+    mut commands: Commands,
+    mut events: ReadDeleteEvent<ShipFlight>,
+    controlled_ships: Query<Has<Docked>, With<ServerFlown>>,
+    ships: Res<ShipsRegistry>,
+    stdb: SpacetimeDB,
+) {
+    for event in events.read() {
+        let Some(ship) = ships.get(event.row.ship_id) else {
+            continue;
+        };
+        let Ok(docked) = controlled_ships.get(ship.entity()) else {
+            continue;
+        };
+
+        debug!(
+            "Ship[{}] is no longer flown by the server",
+            event.row.ship_id
+        );
+        let mut entity = commands.entity(ship.entity());
+        entity.remove::<ServerFlown>();
+
+        // The server also stops flying ships whose pilot left, those have no physics anymore.
+        let piloted = stdb
+            .db()
+            .ship_pilot()
+            .ship_id()
+            .find(&event.row.ship_id)
+            .is_some_and(|pilot| pilot.player_id == stdb.identity());
+        if piloted && !docked {
+            entity.insert(RigidBody::Dynamic);
+        }
+    }
+}
+
 fn apply_inputs(
     thrust_action: Single<&ActionValue, With<Action<Thrust>>>,
     lateral_thrust_action: Single<&ActionValue, With<Action<LateralThrust>>>,
     vertical_thrust_action: Single<&ActionValue, With<Action<VerticalThrust>>>,
     roll_action: Single<&ActionValue, With<Action<Roll>>>,
     pitch_yaw_action: Single<&ActionValue, With<Action<PitchYaw>>>,
+    boost_action: Single<&ActionValue, With<Action<Boost>>>,
     mut flight_controls: Single<&mut FlightControls, With<ControlledShip>>,
 ) -> Result {
     let pitch_yaw_action = pitch_yaw_action.as_axis2d();
@@ -245,6 +408,7 @@ fn apply_inputs(
     flight_controls.roll = roll_action.as_axis1d().clamp(-1.0, 1.0);
     flight_controls.pitch = pitch_yaw_action.y.clamp(-1.0, 1.0);
     flight_controls.yaw = pitch_yaw_action.x.clamp(-1.0, 1.0);
+    flight_controls.boost = boost_action.as_bool();
 
     Ok(())
 }
@@ -258,7 +422,7 @@ fn apply_movement(
             &FlightControls,
             &Ship,
         ),
-        With<ControlledShip>,
+        (With<ControlledShip>, Without<Docked>, Without<ServerFlown>),
     >,
     window: Single<&Window>,
     stdb: SpacetimeDB,
@@ -273,40 +437,152 @@ fn apply_movement(
     let (mut external_torque, mut external_force, transform, flight_controls, ship) =
         query.into_inner();
     let ship_data = stdb.db().ship_type().id().find(&ship.ship_type).unwrap();
+    let controls = flight_controls.into();
+    let power = ship_power(&stdb, ship.id, &ship_data, &controls);
+
+    // The forces don't depend on the mass, the physics engine applies it.
+    let (force, torque) = forces(
+        &ship_dynamics(&ship_data, ship_data.mass),
+        transform.rotation,
+        &controls,
+        power,
+    );
+    external_torque.apply_torque(torque * time.delta_secs());
+    external_force.apply_force(force * time.delta_secs());
 
-    let roll_torque =
-        transform.back() * flight_controls.roll * ship_data.roll_torque * time.delta_secs();
-    external_torque.apply_torque(roll_torque);
+    Ok(())
+}
 
-    let pitch_torque =
-        transform.right() * flight_controls.pitch * ship_data.pitch_torque * time.delta_secs();
-    external_torque.apply_torque(pitch_torque);
+/// The power a ship can put behind its controls. Boosting lasts as long as the tank and the
+/// capacitor.
+fn ship_power(
+    stdb: &SpacetimeDB,
+    ship_id: u64,
+    ship_type: &ShipType,
+    controls: &simulation::flight::FlightControls,
+) -> Power {
+    let Some(status) = stdb.db().ship_status().ship_id().find(&ship_id) else {
+        return Power::FULL;
+    };
 
-    let yaw_torque =
-        transform.up() * flight_controls.yaw * ship_data.yaw_torque * time.delta_secs();
-    external_torque.apply_torque(yaw_torque);
+    let fueled = status.fuel > 0.0;
+    let boosting = fueled
+        && controls.boost
+        && controls.thrust > 0.0
+        && current_energy(&status, ship_type, Timestamp::now()) > 0.0;
+    let boost = if boosting {
+        ship_type.boost_factor
+    } else {
+        1.0
+    };
+    Power::new(status.engines, status.thrusters, fueled, boost)
+}
 
-    let thrust_force =
-        transform.forward() * flight_controls.thrust * ship_data.thrust * time.delta_secs();
-    external_force.apply_force(thrust_force);
+/// How a ship of the given type and mass responds to its controls.
+///
+/// Mirrors `flight::ship_dynamics` on the server.
+fn ship_dynamics(ship_type: &ShipType, mass: f32) -> ShipDynamics {
+    ShipDynamics {
+        mass,
+        radius: ship_type.radius,
+        linear_damping: ship_type.linear_damping,
+        angular_damping: ship_type.angular_damping,
+        thrust: ship_type.thrust,
+        vertical_thrust: ship_type.vertical_thrust,
+        lateral_thrust: ship_type.lateral_thrust,
+        pitch_torque: ship_type.pitch_torque,
+        yaw_torque: ship_type.yaw_torque,
+        roll_torque: ship_type.roll_torque,
+    }
+}
 
-    let vertical_thrust_force = transform.up()
-        * flight_controls.vertical_thrust
-        * ship_data.vertical_thrust
-        * time.delta_secs();
-    external_force.apply_force(vertical_thrust_force);
+/// Sends the local player's controls to the server flying their ship as they change, and
+/// predicts its flight in the meantime with the server's flight model, stepping at the server's
+/// rate.
+fn fly_server_flown_ship(
+    ship: Single<
+        (
+            &mut Transform,
+            &mut ServerFlown,
+            &FlightControls,
+            &Ship,
+            Has<Docked>,
+        ),
+        With<ControlledShip>,
+    >,
+    window: Single<&Window>,
+    stdb: SpacetimeDB,
+    time: Res<Time>,
+) -> Result {
+    let (mut transform, mut server_flown, flight_controls, ship, docked) = ship.into_inner();
+
+    // Docked ships are carried by their station, and leave it at rest.
+    if docked {
+        server_flown.state =
+            simulation::flight::FlightState::at_rest(transform.translation, transform.rotation);
+        server_flown.previous = server_flown.state;
+        server_flown.pending = Duration::ZERO;
+        return Ok(());
+    }
 
-    let lateral_thrust_force = transform.right()
-        * flight_controls.lateral_thrust
-        * ship_data.lateral_thrust
-        * time.delta_secs();
-    external_force.apply_force(lateral_thrust_force);
+    // The ship is left alone while the cursor is free, as when the physics engine flies it.
+    let controls = if window.cursor_options.grab_mode == CursorGrabMode::None {
+        simulation::flight::FlightControls::default()
+    } else {
+        flight_controls.into()
+    };
+    if server_flown.send_timer.tick(time.delta()).finished() && controls != server_flown.sent {
+        stdb.reducers()
+            .player_set_flight_controls(bindings::FlightControls {
+                thrust: controls.thrust,
+                lateral_thrust: controls.lateral_thrust,
+                vertical_thrust: controls.vertical_thrust,
+                roll: controls.roll,
+                pitch: controls.pitch,
+                yaw: controls.yaw,
+                boost: controls.boost,
+            })?;
+        server_flown.sent = controls;
+        server_flown.send_timer.reset();
+    }
+
+    let Some(ship_type) = stdb.db().ship_type().id().find(&ship.ship_type) else {
+        return Ok(());
+    };
+    let dynamics = ship_dynamics(&ship_type, ship_mass(&stdb, ship.id, &ship_type));
+    let power = ship_power(&stdb, ship.id, &ship_type, &controls);
+
+    server_flown.pending += time.delta();
+    while server_flown.pending >= FLIGHT_STEP {
+        server_flown.pending -= FLIGHT_STEP;
+        server_flown.previous = server_flown.state;
+        server_flown.state = step(
+            &dynamics,
+            &server_flown.state,
+            &controls,
+            power,
+            FLIGHT_STEP.as_secs_f32(),
+        );
+    }
+
+    let t = server_flown.pending.as_secs_f32() / FLIGHT_STEP.as_secs_f32();
+    transform.translation = server_flown
+        .previous
+        .position
+        .lerp(server_flown.state.position, t);
+    transform.rotation = server_flown
+        .previous
+        .rotation
+        .slerp(server_flown.state.rotation, t);
 
     Ok(())
 }
 
 fn send_location_updates(
-    ship: Single<(&Transform, &mut ShipLocationUpdate), With<ControlledShip>>,
+    ship: Single<
+        (&Transform, &mut ShipLocationUpdate),
+        (With<ControlledShip>, Without<Docked>, Without<ServerFlown>),
+    >,
     time: Res<Time>,
     stdb: SpacetimeDB,
 ) -> Result {
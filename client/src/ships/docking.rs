@@ -0,0 +1,208 @@
+use avian3d::prelude::{AngularVelocity, LinearVelocity, RigidBody};
+use bevy::{
+    prelude::*,
+    transform::TransformSystem,
+    window::{CursorGrabMode, Window},
+};
+use bevy_spacetimedb::{InsertEvent, ReadDeleteEvent, ReadInsertEvent};
+use simulation::ranges::MAX_DOCKING_DISTANCE;
+use spacetimedb_sdk::Status;
+
+use crate::{
+    GameState,
+    bindings::{DockingBayTableAccess, ShipDocking, StationTableAccess, request_docking, undock},
+    local_player::LocalPlayerState,
+    spacetimedb::SpacetimeDB,
+    world::{Station, station_rotation},
+};
+
+use super::{
+    components::{ControlledShip, Docked, ServerFlown},
+    resources::ShipsRegistry,
+};
+
+pub struct ShipDockingPlugin;
+
+impl Plugin for ShipDockingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::InGame), register_docking_callbacks)
+            .add_systems(
+                Update,
+                (
+                    toggle_docking.run_if(in_state(LocalPlayerState::InShip)),
+                    (on_ship_docking_inserted, on_ship_docking_deleted).chain(),
+                ),
+            )
+            .add_systems(
+                PostUpdate,
+                follow_docking_stations.before(TransformSystem::TransformPropagate),
+            );
+    }
+}
+
+fn register_docking_callbacks(stdb: SpacetimeDB) {
+    stdb.reducers().on_request_docking(|ctx, station_id| {
+        if let Status::Failed(err) = &ctx.event.status {
+            warn!("Failed to dock at station {station_id}: {err}");
+        }
+    });
+    stdb.reducers().on_undock(|ctx| {
+        if let Status::Failed(err) = &ctx.event.status {
+            warn!("Failed to undock: {err}");
+        }
+    });
+}
+
+/// Docks at the nearest station in range when G is pressed, or undocks if already docked.
+fn toggle_docking(
+    ship: Single<(&GlobalTransform, Has<Docked>), With<ControlledShip>>,
+    stations: Query<(&GlobalTransform, &Station)>,
+    input: Res<ButtonInput<KeyCode>>,
+    stdb: SpacetimeDB,
+) {
+    if !input.just_pressed(KeyCode::KeyG) {
+        return;
+    }
+
+    let (transform, docked) = ship.into_inner();
+    if docked {
+        stdb.reducers().undock().unwrap();
+        return;
+    }
+
+    let nearest = stations
+        .iter()
+        .map(|(station_transform, station)| {
+            let distance = station_transform
+                .translation()
+                .distance(transform.translation());
+            (distance, station.id)
+        })
+        .filter(|(distance, _)| *distance <= MAX_DOCKING_DISTANCE)
+        .min_by(|(a, _), (b, _)| a.total_cmp(b));
+
+    if let Some((_, station_id)) = nearest {
+        debug!("Requesting docking at station: {station_id}");
+        stdb.reducers().request_docking(station_id).unwrap();
+    } else {
+        debug!("No station in docking range");
+    }
+}
+
+fn on_ship_docking_inserted(
+    mut commands: Commands,
+    mut events: ReadInsertEvent<ShipDocking>,
+    controlled_ships: Query<(), With<ControlledShip>>,
+    ships: Res<ShipsRegistry>,
+    mut window: Single<&mut Window>,
+    stdb: SpacetimeDB,
+) {
+    for event in events.read() {
+        let docking = &event.row;
+
+        let Some(ship) = ships.get(docking.ship_id) else {
+            warn!(
+                "Ship[{}] not found in registry for docking",
+                docking.ship_id
+            );
+            commands.send_event(InsertEvent {
+                row: docking.clone(),
+            });
+            continue;
+        };
+        let Some(bay) = stdb.db().docking_bay().id().find(&docking.docking_bay_id) else {
+            warn!("Docking bay {} not found", docking.docking_bay_id);
+            continue;
+        };
+
+        debug!("Docking ship: {:?}", docking);
+        let mut entity = commands.entity(ship.entity());
+        entity.insert(Docked {
+            station_id: docking.station_id,
+            offset: Vec3::new(bay.offset_x, bay.offset_y, bay.offset_z),
+            rotation: Quat::from_rotation_y(bay.heading),
+        });
+
+        // The station carries the ship around from now on, physics must not move it.
+        if controlled_ships.contains(ship.entity()) {
+            entity.insert((
+                RigidBody::Kinematic,
+                LinearVelocity::ZERO,
+                AngularVelocity::ZERO,
+            ));
+
+            // Free the cursor for the station's trading terminal.
+            window.cursor_options.grab_mode = CursorGrabMode::None;
+            window.cursor_options.visible = true;
+        }
+    }
+}
+
+fn on_ship_docking_deleted(
+    mut commands: Commands,
+    mut events: ReadDeleteEvent<ShipDocking>,
+    controlled_ships: Query<(&Transform, Has<ServerFlown>), With<ControlledShip>>,
+    ships: Res<ShipsRegistry>,
+    mut window: Single<&mut Window>,
+    stdb: SpacetimeDB,
+) {
+    for event in events.read() {
+        let docking = &event.row;
+
+        // The ship itself may be gone, such as when it leaves the area of interest.
+        let Some(ship) = ships.get(docking.ship_id) else {
+            continue;
+        };
+
+        debug!("Undocking ship: {:?}", docking);
+        let mut entity = commands.entity(ship.entity());
+        entity.remove::<Docked>();
+
+        let Ok((transform, server_flown)) = controlled_ships.get(ship.entity()) else {
+            continue;
+        };
+        window.cursor_options.grab_mode = CursorGrabMode::Locked;
+        window.cursor_options.visible = false;
+
+        // Ships flown by the server leave at rest, and stay out of the physics engine's hands.
+        if server_flown {
+            continue;
+        }
+
+        // Leave with the velocity the station's rotation was carrying the ship at, so it drifts
+        // away from the bay rather than being hit by it.
+        let velocity = stdb
+            .db()
+            .station()
+            .id()
+            .find(&docking.station_id)
+            .map(|station| {
+                let radius = transform.translation - Vec3::new(station.x, station.y, station.z);
+                station_rotation(&stdb, &station)
+                    .angular_velocity()
+                    .cross(radius)
+            })
+            .unwrap_or_default();
+
+        entity.insert((RigidBody::Dynamic, LinearVelocity(velocity)));
+    }
+}
+
+/// Keeps docked ships attached to their bay as the station rotates.
+fn follow_docking_stations(
+    mut ships: Query<(&mut Transform, &Docked), Without<Station>>,
+    stations: Query<(&Transform, &Station)>,
+) {
+    for (mut transform, docked) in ships.iter_mut() {
+        let Some((station_transform, _)) = stations
+            .iter()
+            .find(|(_, station)| station.id == docked.station_id)
+        else {
+            continue;
+        };
+
+        transform.translation =
+            station_transform.translation + station_transform.rotation * docked.offset;
+        transform.rotation = station_transform.rotation * docked.rotation;
+    }
+}
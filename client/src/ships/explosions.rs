@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+
+/// How long an explosion lights up its surroundings, in seconds.
+const EXPLOSION_DURATION: f32 = 0.6;
+
+/// Intensity of the light of an explosion as it goes off, fading out over its duration.
+const EXPLOSION_INTENSITY: f32 = 50_000_000.0;
+
+#[derive(Component)]
+struct Explosion {
+    radius: f32,
+    timer: Timer,
+}
+
+pub struct ShipExplosionsPlugin;
+
+impl Plugin for ShipExplosionsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, update_explosions);
+    }
+}
+
+/// Sets off an explosion growing to `radius` at `position`, for detonating missiles and
+/// destroyed ships alike.
+pub fn spawn_explosion(commands: &mut Commands, name: String, position: Vec3, radius: f32) {
+    commands.spawn((
+        Name::new(name),
+        Explosion {
+            radius,
+            timer: Timer::from_seconds(EXPLOSION_DURATION, TimerMode::Once),
+        },
+        PointLight {
+            color: Color::srgb(1.0, 0.6, 0.2),
+            intensity: EXPLOSION_INTENSITY,
+            range: radius * 10.0,
+            ..default()
+        },
+        Transform::from_translation(position),
+    ));
+}
+
+fn update_explosions(
+    mut commands: Commands,
+    mut explosions: Query<(Entity, &mut Explosion, &mut PointLight, &Transform)>,
+    mut gizmos: Gizmos,
+    time: Res<Time>,
+) {
+    for (entity, mut explosion, mut light, transform) in explosions.iter_mut() {
+        if explosion.timer.tick(time.delta()).finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let progress = explosion.timer.fraction();
+        light.intensity = EXPLOSION_INTENSITY * (1.0 - progress);
+        gizmos.sphere(
+            Isometry3d::from_translation(transform.translation),
+            explosion.radius * progress,
+            Color::srgba(1.0, 0.6, 0.2, 1.0 - progress),
+        );
+    }
+}
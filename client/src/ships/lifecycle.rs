@@ -4,15 +4,17 @@ use avian3d::prelude::{
 use bevy::prelude::*;
 use bevy_spacetimedb::{DeleteEvent, ReadDeleteEvent, ReadInsertEvent};
 
+use spacetimedb_sdk::Table;
+
 use crate::{
-    assets_loader::{CollisionAssets, ModelAssets},
-    bindings::{Ship as ShipTable, ShipTypeTableAccess},
+    bindings::{Ship as ShipTable, ShipTypeTableAccess, WreckTableAccess},
+    local_player::PlayerCamera,
     materials::GameMaterial,
-    ships::components::Ship,
+    ships::{ShipAssets, cargo::ship_mass, components::Ship},
     spacetimedb::SpacetimeDB,
 };
 
-use super::resources::ShipsRegistry;
+use super::{explosions::spawn_explosion, resources::ShipsRegistry};
 
 pub struct ShipsLifecyclePlugin;
 
@@ -26,8 +28,7 @@ fn spawn_ship(
     mut commands: Commands,
     mut events: ReadInsertEvent<ShipTable>,
     mut ships: ResMut<ShipsRegistry>,
-    models_assets: Res<ModelAssets>,
-    collision_assets: Res<CollisionAssets>,
+    ship_assets: Res<ShipAssets>,
     meshes: Res<Assets<Mesh>>,
     stdb: SpacetimeDB,
 ) {
@@ -37,9 +38,9 @@ fn spawn_ship(
         debug!("Spawning ship: {:?}", ship);
 
         let ship_type = stdb.db().ship_type().id().find(&ship.ship_type_id).unwrap();
-        let model = match ship.ship_type_id {
-            1 => &models_assets.ship_bomber_01,
-            _ => panic!("Unknown ship type: {}", ship.ship_type_id),
+        let Some(assets) = ship_assets.get(ship.ship_type_id) else {
+            warn!("No assets loaded for ship type: {}", ship.ship_type_id);
+            continue;
         };
 
         let ship_spot_light = SpotLight {
@@ -50,13 +51,8 @@ fn spawn_ship(
             ..Default::default()
         };
 
-        let mesh_handle = match ship.ship_type_id {
-            1 => collision_assets.ship_bomber_01.clone(),
-            _ => panic!("Unknown ship type: {}", ship.ship_type_id),
-        };
-
         let mesh = meshes
-            .get(&mesh_handle)
+            .get(&assets.collider)
             .expect("Failed to get mesh for ship collider");
         // I hate this
         let mesh = mesh
@@ -71,7 +67,7 @@ fn spawn_ship(
                     ship_type: ship.ship_type_id,
                 },
                 Visibility::Visible,
-                Mass(ship_type.mass),
+                Mass(ship_mass(&stdb, ship.id, &ship_type)),
                 LinearDamping(ship_type.linear_damping),
                 AngularDamping(ship_type.angular_damping),
                 ExternalTorque::default().with_persistence(false),
@@ -80,7 +76,7 @@ fn spawn_ship(
                 Transform::default(),
                 children![
                     (
-                        SceneRoot(model.clone()),
+                        SceneRoot(assets.model.clone()),
                         Transform::default()
                             .with_rotation(Quat::from_rotation_y(-180.0_f32.to_radians())),
                         GameMaterial::Ship,
@@ -94,16 +90,56 @@ fn spawn_ship(
     }
 }
 
+/// Despawns ships that are gone, with an explosion if they were destroyed.
+///
+/// A destroyed ship leaves its wreck in the same transaction, which is how it is told apart from
+/// ships that left the area of interest.
 fn despawn_ship(
     mut commands: Commands,
     mut events: ReadDeleteEvent<ShipTable>,
     mut ships: ResMut<ShipsRegistry>,
+    camera: Single<(Entity, Option<&ChildOf>, &GlobalTransform), With<PlayerCamera>>,
+    transforms: Query<&GlobalTransform>,
+    stdb: SpacetimeDB,
 ) {
+    let (camera_entity, camera_parent, camera_transform) = camera.into_inner();
+
     for event in events.read() {
         let ship = &event.row;
         debug!("Despawning ship: {:?}", ship);
         if let Some(ship_data) = ships.get(ship.id) {
-            commands.entity(ship_data.entity()).despawn();
+            let entity = ship_data.entity();
+
+            // The camera rides along with the ship the local player is piloting, it must not go
+            // down with it.
+            if camera_parent.is_some_and(|parent| parent.parent() == entity) {
+                commands
+                    .entity(camera_entity)
+                    .remove::<ChildOf>()
+                    .insert(camera_transform.compute_transform());
+            }
+
+            let destroyed = stdb
+                .db()
+                .wreck()
+                .iter()
+                .any(|wreck| wreck.ship_id == ship.id);
+            if destroyed && let Ok(transform) = transforms.get(entity) {
+                let radius = stdb
+                    .db()
+                    .ship_type()
+                    .id()
+                    .find(&ship.ship_type_id)
+                    .map_or(10.0, |ship_type| ship_type.radius);
+                spawn_explosion(
+                    &mut commands,
+                    format!("Ship Explosion {}", ship.id),
+                    transform.translation(),
+                    radius * 2.0,
+                );
+            }
+
+            commands.entity(entity).despawn();
             ships.remove(ship.id);
         } else {
             warn!("Ship[{}] not found for despawn", ship.id);
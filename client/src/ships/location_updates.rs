@@ -1,10 +1,18 @@
+use avian3d::prelude::LinearVelocity;
 use bevy::prelude::*;
 use bevy_spacetimedb::{InsertEvent, ReadInsertEvent, ReadUpdateEvent, UpdateEvent};
 use spacetimedb_sdk::Identity;
 
 use crate::{bindings::ShipLocation, spacetimedb::SpacetimeDB};
 
-use super::{components::ControlledShip, resources::ShipsRegistry};
+use super::{
+    components::{ControlledShip, Docked},
+    resources::ShipsRegistry,
+};
+
+/// How far from the controlled ship, in world units, a location from the server must be to be
+/// taken as the server moving it rather than an echo of its own moves.
+const TELEPORT_DISTANCE: f32 = 1000.0;
 
 #[derive(Component, Debug)]
 struct TargetShipLocation {
@@ -72,6 +80,7 @@ fn on_ship_location_updated(
     mut events: ReadUpdateEvent<ShipLocation>,
     mut commands: Commands,
     mut ships: Query<&mut TargetShipLocation>,
+    mut controlled_ship: Query<(&mut Transform, &mut LinearVelocity), With<ControlledShip>>,
     ship_registry: Res<ShipsRegistry>,
     stdb: SpacetimeDB,
 ) {
@@ -83,6 +92,15 @@ fn on_ship_location_updated(
             // before the pilot is assigned).
             let pilot_id = ship.pilot_id().unwrap_or(Identity::ZERO);
             if pilot_id == stdb.identity() {
+                // The local player's own moves come back close to where the ship already is, but
+                // a move made by the server, like an admin teleport, has to be followed.
+                let position = Vec3::new(ship_location.x, ship_location.y, ship_location.z);
+                if let Ok((mut transform, mut velocity)) = controlled_ship.get_mut(ship.entity())
+                    && transform.translation.distance(position) > TELEPORT_DISTANCE
+                {
+                    transform.translation = position;
+                    velocity.0 = Vec3::ZERO;
+                }
                 continue;
             }
 
@@ -115,7 +133,10 @@ fn on_ship_location_updated(
 }
 
 fn update_target_ship_locations(
-    mut ships: Query<(&mut Transform, &TargetShipLocation), Without<ControlledShip>>,
+    mut ships: Query<
+        (&mut Transform, &TargetShipLocation),
+        (Without<ControlledShip>, Without<Docked>),
+    >,
 ) {
     for (mut transform, target_location) in ships.iter_mut() {
         transform.translation = transform.translation.lerp(target_location.pos, 0.1);
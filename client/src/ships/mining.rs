@@ -0,0 +1,165 @@
+use avian3d::prelude::{SpatialQuery, SpatialQueryFilter};
+use bevy::prelude::*;
+use bevy_spacetimedb::ReadInsertEvent;
+use simulation::ranges::MAX_MINING_DISTANCE;
+use spacetimedb_sdk::Status;
+
+use crate::{
+    GameState,
+    bindings::{ShipMining, mine_asteroid},
+    local_player::LocalPlayerState,
+    spacetimedb::SpacetimeDB,
+    world::Asteroid,
+};
+
+use super::{components::ControlledShip, resources::ShipsRegistry};
+
+/// How often ore is extracted while the mining laser is held, in seconds. Mirrors
+/// `MINING_INTERVAL` in the server's `mining.rs`.
+const MINING_INTERVAL: f32 = 1.0;
+
+/// How long a mining beam stays visible after an extraction, in seconds.
+const MINING_BEAM_DURATION: f32 = 1.2;
+
+/// Paces the extractions requested while the mining laser is held.
+#[derive(Resource)]
+struct MiningLaser(Timer);
+
+/// A mining beam drawn between a ship and the asteroid it's mining, for any ship in interest.
+#[derive(Component)]
+struct MiningBeam {
+    ship: Entity,
+    asteroid: Entity,
+    timer: Timer,
+}
+
+pub struct ShipMiningPlugin;
+
+impl Plugin for ShipMiningPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(MiningLaser(Timer::from_seconds(
+            MINING_INTERVAL,
+            TimerMode::Repeating,
+        )))
+        .add_systems(OnEnter(GameState::InGame), register_mining_callbacks)
+        .add_systems(
+            Update,
+            (
+                fire_mining_laser.run_if(in_state(LocalPlayerState::InShip)),
+                (on_ship_mining_inserted, draw_mining_beams).chain(),
+            ),
+        );
+    }
+}
+
+fn register_mining_callbacks(stdb: SpacetimeDB) {
+    stdb.reducers().on_mine_asteroid(|ctx, asteroid_id| {
+        if let Status::Failed(err) = &ctx.event.status {
+            debug!("Failed to mine asteroid {asteroid_id}: {err}");
+        }
+    });
+}
+
+/// Mines the asteroid in front of the controlled ship while the left mouse button is held.
+fn fire_mining_laser(
+    ship: Single<(Entity, &GlobalTransform), With<ControlledShip>>,
+    asteroids: Query<&Asteroid>,
+    spatial_query: SpatialQuery,
+    input: Res<ButtonInput<MouseButton>>,
+    mut laser: ResMut<MiningLaser>,
+    time: Res<Time>,
+    stdb: SpacetimeDB,
+) {
+    if !input.pressed(MouseButton::Left) {
+        return;
+    }
+
+    // Fire as soon as the button is pressed, then once per interval.
+    if input.just_pressed(MouseButton::Left) {
+        laser.0.reset();
+    } else if !laser.0.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    let (ship_entity, transform) = ship.into_inner();
+    let Some(hit) = spatial_query.cast_ray(
+        transform.translation(),
+        transform.forward(),
+        MAX_MINING_DISTANCE,
+        true,
+        &SpatialQueryFilter::from_excluded_entities([ship_entity]),
+    ) else {
+        return;
+    };
+
+    if let Ok(asteroid) = asteroids.get(hit.entity) {
+        debug!("Mining asteroid: {}", asteroid.id);
+        stdb.reducers().mine_asteroid(asteroid.id).unwrap();
+    }
+}
+
+fn on_ship_mining_inserted(
+    mut commands: Commands,
+    mut events: ReadInsertEvent<ShipMining>,
+    mut beams: Query<&mut MiningBeam>,
+    asteroids: Query<(Entity, &Asteroid)>,
+    ships: Res<ShipsRegistry>,
+) {
+    for event in events.read() {
+        let mining = &event.row;
+
+        let Some(ship) = ships.get(mining.ship_id) else {
+            continue;
+        };
+        let Some((asteroid, _)) = asteroids
+            .iter()
+            .find(|(_, asteroid)| asteroid.id == mining.asteroid_id)
+        else {
+            continue;
+        };
+
+        let timer = Timer::from_seconds(MINING_BEAM_DURATION, TimerMode::Once);
+        if let Some(mut beam) = beams.iter_mut().find(|beam| beam.ship == ship.entity()) {
+            beam.asteroid = asteroid;
+            beam.timer = timer;
+        } else {
+            commands.spawn((
+                Name::new(format!("Mining Beam {}", mining.ship_id)),
+                MiningBeam {
+                    ship: ship.entity(),
+                    asteroid,
+                    timer,
+                },
+            ));
+        }
+    }
+}
+
+fn draw_mining_beams(
+    mut commands: Commands,
+    mut beams: Query<(Entity, &mut MiningBeam)>,
+    transforms: Query<&GlobalTransform>,
+    mut gizmos: Gizmos,
+    time: Res<Time>,
+) {
+    for (entity, mut beam) in beams.iter_mut() {
+        if beam.timer.tick(time.delta()).finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let (Ok(ship), Ok(asteroid)) = (transforms.get(beam.ship), transforms.get(beam.asteroid))
+        else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+
+        // Fade the beam out until the next extraction refreshes it.
+        let alpha = 1.0 - beam.timer.fraction();
+        gizmos.line(
+            ship.translation(),
+            asteroid.translation(),
+            Color::srgba(0.4, 0.9, 1.0, alpha),
+        );
+    }
+}
@@ -0,0 +1,270 @@
+use bevy::{platform::collections::HashMap, prelude::*};
+use bevy_spacetimedb::{ReadDeleteEvent, ReadInsertEvent, ReadUpdateEvent};
+use simulation::damage::efficiency;
+use spacetimedb_sdk::{Status, Table};
+
+use crate::{
+    GameState,
+    bindings::{
+        HardpointTableAccess, Missile, MissileTypeTableAccess, ShipStatusTableAccess,
+        WeaponTypeTableAccess, launch_missile,
+    },
+    local_player::LocalPlayerState,
+    spacetimedb::SpacetimeDB,
+};
+
+use super::{
+    components::{ControlledShip, Docked, Ship},
+    explosions::spawn_explosion,
+};
+
+/// Widest angle from the nose of the controlled ship at which a target can be locked, in
+/// degrees.
+const LOCK_CONE: f32 = 10.0;
+
+/// The entities of the missiles in interest, by missile id.
+#[derive(Resource, Default)]
+struct MissilesRegistry(HashMap<u64, Entity>);
+
+/// The ship the launchers of the controlled ship would fire at, if any.
+#[derive(Resource, Default)]
+struct MissileLock(Option<(u64, Entity)>);
+
+/// Where a missile is according to the server, extrapolated along its velocity between updates.
+#[derive(Component, Debug)]
+struct TargetMissileLocation {
+    pos: Vec3,
+    vel: Vec3,
+}
+
+pub struct ShipMissilesPlugin;
+
+impl Plugin for ShipMissilesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MissilesRegistry>()
+            .init_resource::<MissileLock>()
+            .add_systems(OnEnter(GameState::InGame), register_missiles_callbacks)
+            .add_systems(
+                Update,
+                (
+                    (update_missile_lock, launch_missiles)
+                        .chain()
+                        .run_if(in_state(LocalPlayerState::InShip)),
+                    (on_missile_inserted, on_missile_updated, on_missile_deleted).chain(),
+                ),
+            )
+            .add_systems(PostUpdate, update_target_missile_locations);
+    }
+}
+
+fn register_missiles_callbacks(stdb: SpacetimeDB) {
+    stdb.reducers()
+        .on_launch_missile(|ctx, hardpoint_id, target_ship_id| {
+            if let Status::Failed(err) = &ctx.event.status {
+                debug!(
+                    "Failed to launch missile from hardpoint {hardpoint_id} at ship {target_ship_id}: {err}"
+                );
+            }
+        });
+}
+
+/// Locks on the ship closest to the nose of the controlled ship, within reach of its launchers
+/// as far as its sensors allow.
+fn update_missile_lock(
+    ship: Single<(Entity, &Ship, &GlobalTransform), (With<ControlledShip>, Without<Docked>)>,
+    targets: Query<(Entity, &Ship, &GlobalTransform), Without<Docked>>,
+    mut lock: ResMut<MissileLock>,
+    mut gizmos: Gizmos,
+    stdb: SpacetimeDB,
+) {
+    let (ship_entity, ship, transform) = ship.into_inner();
+    let range = stdb
+        .db()
+        .hardpoint()
+        .iter()
+        .filter(|hardpoint| hardpoint.ship_type_id == ship.ship_type)
+        .filter(|hardpoint| {
+            stdb.db()
+                .missile_type()
+                .weapon_type_id()
+                .find(&hardpoint.weapon_type_id)
+                .is_some()
+        })
+        .filter_map(|hardpoint| stdb.db().weapon_type().id().find(&hardpoint.weapon_type_id))
+        .map(|weapon_type| weapon_type.range)
+        .fold(0.0, f32::max);
+    let range = range
+        * stdb
+            .db()
+            .ship_status()
+            .ship_id()
+            .find(&ship.id)
+            .map_or(1.0, |status| efficiency(status.sensors));
+
+    lock.0 = targets
+        .iter()
+        .filter(|(entity, _, _)| *entity != ship_entity)
+        .filter_map(|(entity, target, target_transform)| {
+            let to_target = target_transform.translation() - transform.translation();
+            let angle = transform.forward().angle_between(to_target);
+            (to_target.length() <= range && angle <= LOCK_CONE.to_radians())
+                .then_some((angle, target.id, entity))
+        })
+        .min_by(|(a, _, _), (b, _, _)| a.total_cmp(b))
+        .map(|(_, ship_id, entity)| (ship_id, entity));
+
+    if let Some((_, entity)) = lock.0
+        && let Ok((_, _, target_transform)) = targets.get(entity)
+    {
+        gizmos.sphere(
+            Isometry3d::from_translation(target_transform.translation()),
+            20.0,
+            Color::srgb(1.0, 0.2, 0.2),
+        );
+    }
+}
+
+/// Launches a missile at the locked target from every launcher of the controlled ship when F is
+/// pressed. Launchers still cooling down are refused by the server.
+fn launch_missiles(
+    ship: Single<&Ship, (With<ControlledShip>, Without<Docked>)>,
+    lock: Res<MissileLock>,
+    input: Res<ButtonInput<KeyCode>>,
+    stdb: SpacetimeDB,
+) {
+    if !input.just_pressed(KeyCode::KeyF) {
+        return;
+    }
+
+    let Some((target_ship_id, _)) = lock.0 else {
+        debug!("No target locked");
+        return;
+    };
+
+    for hardpoint in stdb.db().hardpoint().iter().filter(|hardpoint| {
+        hardpoint.ship_type_id == ship.ship_type
+            && stdb
+                .db()
+                .missile_type()
+                .weapon_type_id()
+                .find(&hardpoint.weapon_type_id)
+                .is_some()
+    }) {
+        debug!("Launching missile at ship: {target_ship_id}");
+        stdb.reducers()
+            .launch_missile(hardpoint.id, target_ship_id)
+            .unwrap();
+    }
+}
+
+fn on_missile_inserted(
+    mut commands: Commands,
+    mut events: ReadInsertEvent<Missile>,
+    mut missiles: ResMut<MissilesRegistry>,
+    asset_server: Res<AssetServer>,
+    stdb: SpacetimeDB,
+) {
+    for event in events.read() {
+        let missile = &event.row;
+        let Some(missile_type) = stdb
+            .db()
+            .missile_type()
+            .weapon_type_id()
+            .find(&missile.weapon_type_id)
+        else {
+            warn!("Missile type {} not found", missile.weapon_type_id);
+            continue;
+        };
+
+        debug!("Spawning missile: {:?}", missile);
+        let pos = Vec3::new(missile.x, missile.y, missile.z);
+        let vel = Vec3::new(missile.vel_x, missile.vel_y, missile.vel_z);
+        let entity = commands
+            .spawn((
+                Name::new(format!("Missile {}", missile.id)),
+                SceneRoot(asset_server.load(format!("{}#Scene0", missile_type.model))),
+                Transform::from_translation(pos).looking_to(vel, Vec3::Y),
+                TargetMissileLocation { pos, vel },
+            ))
+            .id();
+        missiles.0.insert(missile.id, entity);
+    }
+}
+
+fn on_missile_updated(
+    mut events: ReadUpdateEvent<Missile>,
+    mut targets: Query<&mut TargetMissileLocation>,
+    missiles: Res<MissilesRegistry>,
+) {
+    for event in events.read() {
+        let missile = &event.new;
+
+        let Some(mut target) = missiles
+            .0
+            .get(&missile.id)
+            .and_then(|entity| targets.get_mut(*entity).ok())
+        else {
+            continue;
+        };
+        target.pos = Vec3::new(missile.x, missile.y, missile.z);
+        target.vel = Vec3::new(missile.vel_x, missile.vel_y, missile.vel_z);
+    }
+}
+
+/// Despawns missiles that detonated, with an explosion.
+///
+/// Missiles also go when they leave the area of interest, but that's sectors away from the
+/// local player where an explosion goes unnoticed, so they're not told apart.
+fn on_missile_deleted(
+    mut commands: Commands,
+    mut events: ReadDeleteEvent<Missile>,
+    mut missiles: ResMut<MissilesRegistry>,
+    transforms: Query<&Transform>,
+    stdb: SpacetimeDB,
+) {
+    for event in events.read() {
+        let missile = &event.row;
+
+        let Some(entity) = missiles.0.remove(&missile.id) else {
+            continue;
+        };
+        let position = transforms
+            .get(entity)
+            .map(|transform| transform.translation)
+            .unwrap_or(Vec3::new(missile.x, missile.y, missile.z));
+        commands.entity(entity).despawn();
+
+        let Some(missile_type) = stdb
+            .db()
+            .missile_type()
+            .weapon_type_id()
+            .find(&missile.weapon_type_id)
+        else {
+            continue;
+        };
+        spawn_explosion(
+            &mut commands,
+            format!("Missile Explosion {}", missile.id),
+            position,
+            missile_type.blast_radius,
+        );
+    }
+}
+
+/// Moves missiles towards where the server says they are, extrapolating along their velocity
+/// between the updates.
+fn update_target_missile_locations(
+    mut missiles: Query<(&mut Transform, &mut TargetMissileLocation)>,
+    time: Res<Time>,
+) {
+    for (mut transform, mut target) in missiles.iter_mut() {
+        let vel = target.vel;
+        target.pos += vel * time.delta_secs();
+
+        transform.translation = transform.translation.lerp(target.pos, 0.3);
+        if let Some(direction) = vel.try_normalize() {
+            let rotation = Transform::IDENTITY.looking_to(direction, Vec3::Y).rotation;
+            transform.rotation = transform.rotation.slerp(rotation, 0.3);
+        }
+    }
+}
@@ -1,24 +1,55 @@
+use assets::ShipAssetsPlugin;
 use bevy::prelude::*;
+use cargo::ShipCargoPlugin;
 use controls::ShipControlsPlugin;
+use docking::ShipDockingPlugin;
+use explosions::ShipExplosionsPlugin;
 use lifecycle::ShipsLifecyclePlugin;
 use location_updates::ShipLocationUpdatesPlugin;
+use mining::ShipMiningPlugin;
+use missiles::ShipMissilesPlugin;
 use resources::ShipsRegistry;
+use respawn::ShipRespawnPlugin;
+use status::ShipStatusPlugin;
+use trading::ShipTradingPlugin;
+use weapons::ShipWeaponsPlugin;
 
+mod assets;
+mod cargo;
 mod components;
 mod controls;
+mod docking;
+mod explosions;
 mod lifecycle;
 mod location_updates;
+mod mining;
+mod missiles;
 mod resources;
+mod respawn;
+mod status;
+mod trading;
+mod weapons;
 
-pub use components::Ship;
+pub use assets::ShipAssets;
+pub use components::{ControlledShip, Ship};
 
 pub struct ShipsPlugin;
 
 impl Plugin for ShipsPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<ShipsRegistry>()
+            .add_plugins(ShipAssetsPlugin)
             .add_plugins(ShipLocationUpdatesPlugin)
             .add_plugins(ShipsLifecyclePlugin)
-            .add_plugins(ShipControlsPlugin);
+            .add_plugins(ShipCargoPlugin)
+            .add_plugins(ShipControlsPlugin)
+            .add_plugins(ShipDockingPlugin)
+            .add_plugins(ShipExplosionsPlugin)
+            .add_plugins(ShipMiningPlugin)
+            .add_plugins(ShipMissilesPlugin)
+            .add_plugins(ShipRespawnPlugin)
+            .add_plugins(ShipStatusPlugin)
+            .add_plugins(ShipTradingPlugin)
+            .add_plugins(ShipWeaponsPlugin);
     }
 }
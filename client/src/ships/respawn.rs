@@ -0,0 +1,119 @@
+use bevy::prelude::*;
+use bevy_inspector_egui::bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
+use spacetimedb_sdk::Status;
+
+use crate::{
+    GameState,
+    bindings::{
+        HomeStationTableAccess, ShipTypeTableAccess, StationTableAccess, respawn_at_home,
+        set_home_station,
+    },
+    spacetimedb::SpacetimeDB,
+};
+
+use super::components::{ControlledShip, Docked, Ship};
+
+/// Name of the ship type pilots eject in when their ship is destroyed.
+///
+/// Mirrors `ESCAPE_POD` in the server's `respawn.rs`.
+const ESCAPE_POD: &str = "Escape Pod";
+
+pub struct ShipRespawnPlugin;
+
+impl Plugin for ShipRespawnPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::InGame), register_respawn_callbacks)
+            .add_systems(
+                EguiPrimaryContextPass,
+                (show_home_station, show_escape_pod_controls),
+            );
+    }
+}
+
+fn register_respawn_callbacks(stdb: SpacetimeDB) {
+    stdb.reducers().on_set_home_station(|ctx| {
+        if let Status::Failed(err) = &ctx.event.status {
+            warn!("Failed to set home station: {err}");
+        }
+    });
+
+    stdb.reducers().on_respawn_at_home(|ctx| {
+        if let Status::Failed(err) = &ctx.event.status {
+            warn!("Failed to respawn at home station: {err}");
+        }
+    });
+}
+
+/// Lets the local player make the station the controlled ship is docked at their home station.
+fn show_home_station(
+    ship: Option<Single<&Docked, With<ControlledShip>>>,
+    mut contexts: EguiContexts,
+    stdb: SpacetimeDB,
+) -> Result {
+    let Some(docked) = ship else {
+        return Ok(());
+    };
+
+    let Some(station) = stdb.db().station().id().find(&docked.station_id) else {
+        return Ok(());
+    };
+    let home_station = stdb
+        .db()
+        .home_station()
+        .player_id()
+        .find(&stdb.identity())
+        .and_then(|home_station| stdb.db().station().id().find(&home_station.station_id));
+
+    egui::Window::new(format!("{} - Services", station.name))
+        .resizable(false)
+        .show(contexts.ctx_mut()?, |ui| {
+            match &home_station {
+                Some(home_station) => ui.label(format!("Home station: {}", home_station.name)),
+                None => ui.label("Home station: none, the nearest station is used"),
+            };
+
+            let is_home = home_station.is_some_and(|home_station| home_station.id == station.id);
+            if ui
+                .add_enabled(!is_home, egui::Button::new("Set as home station"))
+                .clicked()
+            {
+                stdb.reducers().set_home_station().unwrap();
+            }
+        });
+
+    Ok(())
+}
+
+/// Offers the local player to leave their escape pod for their home station.
+fn show_escape_pod_controls(
+    ship: Option<Single<&Ship, With<ControlledShip>>>,
+    mut contexts: EguiContexts,
+    stdb: SpacetimeDB,
+) -> Result {
+    let Some(ship) = ship else {
+        return Ok(());
+    };
+
+    let in_escape_pod = stdb
+        .db()
+        .ship_type()
+        .id()
+        .find(&ship.ship_type)
+        .is_some_and(|ship_type| ship_type.name == ESCAPE_POD);
+    if !in_escape_pod {
+        return Ok(());
+    }
+
+    egui::Window::new("Ship destroyed")
+        .resizable(false)
+        .collapsible(false)
+        .anchor(egui::Align2::CENTER_TOP, [0.0, 20.0])
+        .show(contexts.ctx_mut()?, |ui| {
+            ui.label("You ejected in an escape pod.");
+            if ui.button("Respawn at home station").clicked() {
+                stdb.reducers().respawn_at_home().unwrap();
+            }
+        });
+
+    Ok(())
+}
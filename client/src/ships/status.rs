@@ -0,0 +1,216 @@
+use bevy::prelude::*;
+use bevy_inspector_egui::bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
+use spacetimedb_sdk::{Status, Timestamp};
+
+use crate::{
+    GameState,
+    bindings::{
+        ShipFlightTableAccess, ShipStatus, ShipStatusTableAccess, ShipType, ShipTypeTableAccess,
+        player_set_flight_controls, player_set_flight_mode, refuel_ship, repair_ship,
+    },
+    spacetimedb::SpacetimeDB,
+};
+
+use super::components::{ControlledShip, Docked, Ship};
+
+/// Credits charged at stations per unit of fuel.
+///
+/// Mirrors `FUEL_PRICE` in the server's `fuel.rs`.
+const FUEL_PRICE: f32 = 0.5;
+
+pub struct ShipStatusPlugin;
+
+impl Plugin for ShipStatusPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::InGame), register_status_callbacks)
+            .add_systems(EguiPrimaryContextPass, show_ship_status);
+    }
+}
+
+fn register_status_callbacks(stdb: SpacetimeDB) {
+    stdb.reducers().on_repair_ship(|ctx| {
+        if let Status::Failed(err) = &ctx.event.status {
+            warn!("Failed to repair ship: {err}");
+        }
+    });
+
+    stdb.reducers().on_refuel_ship(|ctx| {
+        if let Status::Failed(err) = &ctx.event.status {
+            warn!("Failed to refuel ship: {err}");
+        }
+    });
+
+    stdb.reducers()
+        .on_player_set_flight_mode(|ctx, server_flown| {
+            if let Status::Failed(err) = &ctx.event.status {
+                warn!("Failed to set flight mode (server flown: {server_flown}): {err}");
+            }
+        });
+
+    stdb.reducers().on_player_set_flight_controls(|ctx, _| {
+        if let Status::Failed(err) = &ctx.event.status {
+            warn!("Failed to send flight controls: {err}");
+        }
+    });
+}
+
+/// Energy a ship has at `now`, having recharged since its status was last updated.
+pub fn current_energy(status: &ShipStatus, ship_type: &ShipType, now: Timestamp) -> f32 {
+    let elapsed = now
+        .duration_since(status.energy_updated_at)
+        .unwrap_or_default();
+    simulation::damage::current_energy(
+        status.energy,
+        elapsed.as_secs_f32(),
+        ship_type.max_energy,
+        ship_type.energy_recharge,
+    )
+}
+
+/// Shield points a ship has at `now`, having regenerated since it was last hit.
+fn current_shield(status: &ShipStatus, ship_type: &ShipType, now: Timestamp) -> f32 {
+    let since_hit = now
+        .duration_since(status.damaged_at)
+        .unwrap_or_default()
+        .as_secs_f32();
+    simulation::damage::current_shield(
+        status.shield,
+        since_hit,
+        ship_type.max_shield,
+        ship_type.shield_recharge,
+        ship_type.shield_delay,
+    )
+}
+
+/// Credits it costs to repair a ship's hull and subsystems.
+fn repair_cost(status: &ShipStatus, ship_type: &ShipType) -> u64 {
+    simulation::damage::repair_cost(
+        status.hull,
+        ship_type.max_hull,
+        [
+            status.engines,
+            status.thrusters,
+            status.weapons,
+            status.sensors,
+        ],
+    )
+}
+
+/// Credits it costs to fill a ship's tank.
+///
+/// Mirrors `fuel::refuel_cost` on the server.
+fn refuel_cost(status: &ShipStatus, ship_type: &ShipType) -> u64 {
+    let missing_fuel = (ship_type.max_fuel - status.fuel).max(0.0);
+    (missing_fuel * FUEL_PRICE).ceil() as u64
+}
+
+/// Shows the condition of the controlled ship, and lets the local player repair and refuel it
+/// while docked, and hand its flight over to the server.
+fn show_ship_status(
+    ship: Option<Single<(&Ship, Has<Docked>), With<ControlledShip>>>,
+    mut contexts: EguiContexts,
+    stdb: SpacetimeDB,
+) -> Result {
+    let Some(ship) = ship else {
+        return Ok(());
+    };
+    let (ship, docked) = ship.into_inner();
+
+    let Some(status) = stdb.db().ship_status().ship_id().find(&ship.id) else {
+        return Ok(());
+    };
+    let Some(ship_type) = stdb.db().ship_type().id().find(&ship.ship_type) else {
+        return Ok(());
+    };
+    let now = Timestamp::now();
+    let shield = current_shield(&status, &ship_type, now);
+    let energy = current_energy(&status, &ship_type, now);
+
+    egui::Window::new("Ship status")
+        .resizable(false)
+        .anchor(egui::Align2::LEFT_BOTTOM, [10.0, -10.0])
+        .show(contexts.ctx_mut()?, |ui| {
+            if ship_type.max_shield > 0.0 {
+                ui.add(
+                    egui::ProgressBar::new(shield / ship_type.max_shield)
+                        .text(format!("Shield {shield:.0} / {:.0}", ship_type.max_shield)),
+                );
+            }
+            ui.add(
+                egui::ProgressBar::new(status.hull / ship_type.max_hull).text(format!(
+                    "Hull {:.0} / {:.0}",
+                    status.hull, ship_type.max_hull
+                )),
+            );
+            if ship_type.max_energy > 0.0 {
+                ui.add(
+                    egui::ProgressBar::new(energy / ship_type.max_energy)
+                        .text(format!("Energy {energy:.0} / {:.0}", ship_type.max_energy)),
+                );
+            }
+            ui.add(
+                egui::ProgressBar::new(status.fuel / ship_type.max_fuel).text(format!(
+                    "Fuel {:.0} / {:.0}",
+                    status.fuel, ship_type.max_fuel
+                )),
+            );
+            if status.fuel <= 0.0 {
+                ui.colored_label(
+                    egui::Color32::from_rgb(255, 120, 80),
+                    "Tank empty, only the RCS thrusters respond",
+                );
+            }
+            ui.separator();
+
+            for (name, condition) in [
+                ("Engines", status.engines),
+                ("Thrusters", status.thrusters),
+                ("Weapons", status.weapons),
+                ("Sensors", status.sensors),
+            ] {
+                ui.add(
+                    egui::ProgressBar::new(condition)
+                        .text(format!("{name} {:.0}%", condition * 100.0)),
+                );
+            }
+
+            if docked {
+                ui.separator();
+                let cost = repair_cost(&status, &ship_type);
+                if ui
+                    .add_enabled(
+                        cost > 0,
+                        egui::Button::new(format!("Repair ({cost} credits)")),
+                    )
+                    .clicked()
+                {
+                    stdb.reducers().repair_ship().unwrap();
+                }
+
+                let cost = refuel_cost(&status, &ship_type);
+                if ui
+                    .add_enabled(
+                        cost > 0,
+                        egui::Button::new(format!("Refuel ({cost} credits)")),
+                    )
+                    .clicked()
+                {
+                    stdb.reducers().refuel_ship().unwrap();
+                }
+            }
+
+            ui.separator();
+            let mut server_flown = stdb.db().ship_flight().ship_id().find(&ship.id).is_some();
+            if ui
+                .checkbox(&mut server_flown, "Flown by the server")
+                .on_hover_text("Send the controls to the server, which flies the ship")
+                .changed()
+            {
+                stdb.reducers()
+                    .player_set_flight_mode(server_flown)
+                    .unwrap();
+            }
+        });
+
+    Ok(())
+}
@@ -0,0 +1,278 @@
+use std::sync::mpsc::Sender;
+
+use bevy::{platform::collections::HashMap, prelude::*};
+use bevy_inspector_egui::bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
+use bevy_spacetimedb::AddEventChannelAppExtensions;
+use spacetimedb_sdk::{Status, SubscriptionHandle as _, Table};
+
+use crate::{
+    GameState,
+    bindings::{
+        CargoTableAccess, ItemTypeTableAccess, MarketPriceHistoryTableAccess, MarketTableAccess,
+        ShipTypeTableAccess, StationTableAccess, SubscriptionHandle, WalletTableAccess, buy, sell,
+    },
+    spacetimedb::SpacetimeDB,
+};
+
+use super::{
+    cargo::cargo_volume,
+    components::{ControlledShip, Docked, Ship},
+};
+
+/// Sent when the server refuses a trade, with the reason to show in the terminal.
+#[derive(Event, Debug)]
+struct TradeFailedEvent(String);
+
+#[derive(Resource)]
+struct TradeFailedSender(Sender<TradeFailedEvent>);
+
+/// How many economy ticks of price history are charted in the terminal.
+const PRICE_CHART_TICKS: usize = 60;
+
+/// State of the trading terminal between frames.
+#[derive(Resource, Default)]
+struct TradingTerminal {
+    /// Quantity entered for each item type.
+    quantities: HashMap<u64, u32>,
+    /// Why the last trade was refused, if it was.
+    error: Option<String>,
+    /// The station whose price history is subscribed to, along with the subscription.
+    history: Option<(u64, SubscriptionHandle)>,
+}
+
+pub struct ShipTradingPlugin;
+
+impl Plugin for ShipTradingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TradingTerminal>()
+            .add_systems(OnEnter(GameState::InGame), register_trading_callbacks)
+            .add_systems(Update, (on_trade_failed, subscribe_to_price_history))
+            .add_systems(EguiPrimaryContextPass, show_trading_terminal);
+
+        let (send, recv) = std::sync::mpsc::channel();
+        app.insert_resource(TradeFailedSender(send));
+        app.add_event_channel::<TradeFailedEvent>(recv);
+    }
+}
+
+fn register_trading_callbacks(stdb: SpacetimeDB, sender: Res<TradeFailedSender>) {
+    let buy_sender = sender.0.clone();
+    stdb.reducers()
+        .on_buy(move |ctx, station_id, item_type_id, quantity| {
+            if let Status::Failed(err) = &ctx.event.status {
+                debug!("Failed to buy {quantity} of {item_type_id} at station {station_id}: {err}");
+                buy_sender.send(TradeFailedEvent(err.to_string())).unwrap();
+            }
+        });
+
+    let sell_sender = sender.0.clone();
+    stdb.reducers()
+        .on_sell(move |ctx, station_id, item_type_id, quantity| {
+            if let Status::Failed(err) = &ctx.event.status {
+                debug!(
+                    "Failed to sell {quantity} of {item_type_id} at station {station_id}: {err}"
+                );
+                sell_sender.send(TradeFailedEvent(err.to_string())).unwrap();
+            }
+        });
+}
+
+fn on_trade_failed(
+    mut events: EventReader<TradeFailedEvent>,
+    mut terminal: ResMut<TradingTerminal>,
+) {
+    for event in events.read() {
+        terminal.error = Some(event.0.clone());
+    }
+}
+
+/// Subscribes to the price history of the station the controlled ship is docked at, for as
+/// long as it stays docked.
+fn subscribe_to_price_history(
+    ship: Option<Single<&Docked, With<ControlledShip>>>,
+    mut terminal: ResMut<TradingTerminal>,
+    stdb: SpacetimeDB,
+) {
+    let station_id = ship.map(|docked| docked.station_id);
+    if terminal.history.as_ref().map(|(id, _)| *id) == station_id {
+        return;
+    }
+
+    if let Some((_, handle)) = terminal.history.take()
+        && let Err(err) = handle.unsubscribe()
+    {
+        warn!("Failed to unsubscribe from price history: {err}");
+    }
+
+    if let Some(station_id) = station_id {
+        let handle = stdb
+            .subscription_builder()
+            .on_error(move |_, err| {
+                error!("Failed to subscribe to price history of station {station_id}: {err}");
+            })
+            .subscribe([format!(
+                "SELECT market_price_history.* FROM market_price_history \
+                 JOIN market ON market_price_history.market_id = market.id \
+                 WHERE market.station_id = {station_id}"
+            )]);
+        terminal.history = Some((station_id, handle));
+    }
+}
+
+/// Shows the market of the station the controlled ship is docked at.
+fn show_trading_terminal(
+    ship: Option<Single<(&Ship, &Docked), With<ControlledShip>>>,
+    mut terminal: ResMut<TradingTerminal>,
+    mut contexts: EguiContexts,
+    stdb: SpacetimeDB,
+) -> Result {
+    let Some(ship) = ship else {
+        terminal.error = None;
+        return Ok(());
+    };
+    let (ship, docked) = ship.into_inner();
+
+    let Some(station) = stdb.db().station().id().find(&docked.station_id) else {
+        return Ok(());
+    };
+    let credits = stdb
+        .db()
+        .wallet()
+        .player_id()
+        .find(&stdb.identity())
+        .map(|wallet| wallet.credits)
+        .unwrap_or_default();
+    let capacity = stdb
+        .db()
+        .ship_type()
+        .id()
+        .find(&ship.ship_type)
+        .map(|ship_type| ship_type.cargo_capacity)
+        .unwrap_or_default();
+
+    let mut markets = stdb
+        .db()
+        .market()
+        .iter()
+        .filter(|market| market.station_id == station.id)
+        .collect::<Vec<_>>();
+    markets.sort_by_key(|market| market.item_type_id);
+
+    egui::Window::new(format!("{} - Market", station.name))
+        .resizable(false)
+        .show(contexts.ctx_mut()?, |ui| {
+            ui.label(format!("Credits: {credits}"));
+            ui.label(format!(
+                "Cargo hold: {:.1} / {:.1}",
+                cargo_volume(&stdb, ship.id),
+                capacity
+            ));
+            ui.separator();
+
+            egui::Grid::new("market")
+                .striped(true)
+                .num_columns(9)
+                .show(ui, |ui| {
+                    for header in [
+                        "Item", "Stock", "Buy", "Sell", "Trend", "In hold", "Quantity",
+                    ] {
+                        ui.strong(header);
+                    }
+                    ui.end_row();
+
+                    for market in &markets {
+                        let Some(item_type) = stdb.db().item_type().id().find(&market.item_type_id)
+                        else {
+                            continue;
+                        };
+                        let in_hold = stdb
+                            .db()
+                            .cargo()
+                            .iter()
+                            .find(|cargo| {
+                                cargo.ship_id == ship.id && cargo.item_type_id == item_type.id
+                            })
+                            .map(|cargo| cargo.quantity)
+                            .unwrap_or_default();
+
+                        ui.label(&item_type.name);
+                        ui.label(market.stock.to_string());
+                        ui.label(market.buy_price.to_string());
+                        ui.label(market.sell_price.to_string());
+                        price_chart(ui, &stdb, market.id);
+                        ui.label(in_hold.to_string());
+
+                        let quantity = terminal.quantities.entry(item_type.id).or_insert(1);
+                        ui.add(egui::DragValue::new(quantity).range(1..=u32::MAX));
+                        let quantity = *quantity;
+
+                        if ui.button("Buy").clicked() {
+                            terminal.error = None;
+                            stdb.reducers()
+                                .buy(station.id, item_type.id, quantity)
+                                .unwrap();
+                        }
+                        if ui.button("Sell").clicked() {
+                            terminal.error = None;
+                            stdb.reducers()
+                                .sell(station.id, item_type.id, quantity)
+                                .unwrap();
+                        }
+                        ui.end_row();
+                    }
+                });
+
+            if let Some(error) = &terminal.error {
+                ui.separator();
+                ui.colored_label(egui::Color32::LIGHT_RED, error);
+            }
+        });
+
+    Ok(())
+}
+
+/// Draws the recent prices of a market as a small line chart.
+fn price_chart(ui: &mut egui::Ui, stdb: &SpacetimeDB, market_id: u64) {
+    let mut history = stdb
+        .db()
+        .market_price_history()
+        .iter()
+        .filter(|entry| entry.market_id == market_id)
+        .collect::<Vec<_>>();
+    history.sort_by_key(|entry| entry.recorded_at);
+    let prices = history
+        .iter()
+        .rev()
+        .take(PRICE_CHART_TICKS)
+        .rev()
+        .map(|entry| entry.price)
+        .collect::<Vec<_>>();
+
+    let (rect, _) = ui.allocate_exact_size(egui::vec2(80.0, 16.0), egui::Sense::hover());
+    if prices.len() < 2 {
+        return;
+    }
+
+    let min = prices.iter().copied().fold(f32::INFINITY, f32::min);
+    let max = prices.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    let range = (max - min).max(f32::EPSILON);
+    let points = prices
+        .iter()
+        .enumerate()
+        .map(|(i, price)| {
+            egui::pos2(
+                rect.left() + rect.width() * i as f32 / (prices.len() - 1) as f32,
+                rect.bottom() - rect.height() * (price - min) / range,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let rising = prices[prices.len() - 1] >= prices[0];
+    let color = if rising {
+        egui::Color32::LIGHT_GREEN
+    } else {
+        egui::Color32::LIGHT_RED
+    };
+    ui.painter()
+        .add(egui::Shape::line(points, egui::Stroke::new(1.0, color)));
+}
@@ -0,0 +1,187 @@
+use bevy::{platform::collections::HashMap, prelude::*};
+use bevy_spacetimedb::ReadInsertEvent;
+use spacetimedb_sdk::{Status, Table};
+
+use crate::{
+    GameState,
+    bindings::{
+        HardpointTableAccess, MissileTypeTableAccess, WeaponFire, WeaponTypeTableAccess,
+        fire_weapon,
+    },
+    local_player::LocalPlayerState,
+    spacetimedb::SpacetimeDB,
+};
+
+use super::components::{ControlledShip, Docked, Ship};
+
+/// How long the tracer of a shot stays visible, in seconds.
+const TRACER_DURATION: f32 = 0.25;
+
+/// How long the muzzle flash of a shot lights up its surroundings, in seconds.
+const MUZZLE_FLASH_DURATION: f32 = 0.08;
+
+/// Cooldowns of the hardpoints of the controlled ship, by hardpoint id.
+#[derive(Resource, Default)]
+struct WeaponCooldowns(HashMap<u64, Timer>);
+
+/// A shot drawn from the hardpoint that fired it to where it hit or ran out of range.
+#[derive(Component)]
+struct Tracer {
+    origin: Vec3,
+    end: Vec3,
+    hit: bool,
+    timer: Timer,
+}
+
+#[derive(Component)]
+struct MuzzleFlash(Timer);
+
+pub struct ShipWeaponsPlugin;
+
+impl Plugin for ShipWeaponsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WeaponCooldowns>()
+            .add_systems(OnEnter(GameState::InGame), register_weapons_callbacks)
+            .add_systems(
+                Update,
+                (
+                    fire_weapons.run_if(in_state(LocalPlayerState::InShip)),
+                    on_weapon_fire_inserted,
+                    draw_tracers,
+                    fade_muzzle_flashes,
+                ),
+            );
+    }
+}
+
+fn register_weapons_callbacks(stdb: SpacetimeDB) {
+    stdb.reducers().on_fire_weapon(|ctx, hardpoint_id| {
+        if let Status::Failed(err) = &ctx.event.status {
+            debug!("Failed to fire hardpoint {hardpoint_id}: {err}");
+        }
+    });
+}
+
+/// Fires every weapon of the controlled ship while the right mouse button is held, each as
+/// often as its cooldown allows. Missile launchers are fired at a locked target instead, see
+/// `missiles.rs`.
+fn fire_weapons(
+    ship: Single<&Ship, (With<ControlledShip>, Without<Docked>)>,
+    input: Res<ButtonInput<MouseButton>>,
+    mut cooldowns: ResMut<WeaponCooldowns>,
+    time: Res<Time>,
+    stdb: SpacetimeDB,
+) {
+    for timer in cooldowns.0.values_mut() {
+        timer.tick(time.delta());
+    }
+
+    if !input.pressed(MouseButton::Right) {
+        return;
+    }
+
+    for hardpoint in stdb
+        .db()
+        .hardpoint()
+        .iter()
+        .filter(|hardpoint| hardpoint.ship_type_id == ship.ship_type)
+        .filter(|hardpoint| {
+            stdb.db()
+                .missile_type()
+                .weapon_type_id()
+                .find(&hardpoint.weapon_type_id)
+                .is_none()
+        })
+    {
+        let Some(weapon_type) = stdb.db().weapon_type().id().find(&hardpoint.weapon_type_id) else {
+            continue;
+        };
+
+        if cooldowns
+            .0
+            .get(&hardpoint.id)
+            .is_some_and(|timer| !timer.finished())
+        {
+            continue;
+        }
+
+        cooldowns.0.insert(
+            hardpoint.id,
+            Timer::from_seconds(weapon_type.cooldown, TimerMode::Once),
+        );
+        stdb.reducers().fire_weapon(hardpoint.id).unwrap();
+    }
+}
+
+/// Shows the shots fired by any ship in interest.
+fn on_weapon_fire_inserted(mut commands: Commands, mut events: ReadInsertEvent<WeaponFire>) {
+    for event in events.read() {
+        let fire = &event.row;
+        let origin = Vec3::new(fire.origin_x, fire.origin_y, fire.origin_z);
+        let end = Vec3::new(fire.end_x, fire.end_y, fire.end_z);
+
+        // Missile launches end where they start, the missile itself is shown instead.
+        if end != origin {
+            commands.spawn((
+                Name::new(format!("Tracer {}", fire.id)),
+                Tracer {
+                    origin,
+                    end,
+                    hit: fire.hit_ship_id.is_some(),
+                    timer: Timer::from_seconds(TRACER_DURATION, TimerMode::Once),
+                },
+            ));
+        }
+        commands.spawn((
+            Name::new(format!("Muzzle Flash {}", fire.id)),
+            MuzzleFlash(Timer::from_seconds(MUZZLE_FLASH_DURATION, TimerMode::Once)),
+            PointLight {
+                color: Color::srgb(1.0, 0.7, 0.3),
+                intensity: 2_000_000.0,
+                range: 40.0,
+                ..default()
+            },
+            Transform::from_translation(origin),
+        ));
+    }
+}
+
+fn draw_tracers(
+    mut commands: Commands,
+    mut tracers: Query<(Entity, &mut Tracer)>,
+    mut gizmos: Gizmos,
+    time: Res<Time>,
+) {
+    for (entity, mut tracer) in tracers.iter_mut() {
+        if tracer.timer.tick(time.delta()).finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let alpha = 1.0 - tracer.timer.fraction();
+        gizmos.line(
+            tracer.origin,
+            tracer.end,
+            Color::srgba(1.0, 0.5, 0.2, alpha),
+        );
+        if tracer.hit {
+            gizmos.sphere(
+                Isometry3d::from_translation(tracer.end),
+                2.0,
+                Color::srgba(1.0, 0.9, 0.5, alpha),
+            );
+        }
+    }
+}
+
+fn fade_muzzle_flashes(
+    mut commands: Commands,
+    mut flashes: Query<(Entity, &mut MuzzleFlash)>,
+    time: Res<Time>,
+) {
+    for (entity, mut flash) in flashes.iter_mut() {
+        if flash.0.tick(time.delta()).finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
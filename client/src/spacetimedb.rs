@@ -1,17 +1,25 @@
 use std::{env, sync::mpsc::Sender};
 
 use bevy::prelude::*;
+use bevy_inspector_egui::bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
 use bevy_spacetimedb::{
-    AddEventChannelAppExtensions, ReadStdbConnectedEvent, ReadStdbConnectionErrorEvent,
-    ReadStdbDisconnectedEvent, StdbConnection, StdbPlugin,
+    AddEventChannelAppExtensions, ReadInsertEvent, ReadStdbConnectedEvent,
+    ReadStdbConnectionErrorEvent, ReadStdbDisconnectedEvent, ReadUpdateEvent, StdbConnection,
+    StdbPlugin,
 };
+use spacetimedb_sdk::{DbContext, Timestamp};
 
 use crate::{
     GameState,
     bindings::{
-        AsteroidTableAccess, DbConnection, PlayerLocationTableAccess, PlayerTableAccess,
-        RemoteTables, ShipLocationTableAccess, ShipPilotTableAccess, ShipTableAccess,
-        ShipTypeTableAccess, StationTableAccess,
+        AsteroidResourceTableAccess, AsteroidTableAccess, Ban, BanTableAccess, CargoTableAccess,
+        DbConnection, DockingBayTableAccess, HardpointTableAccess, ItemTypeTableAccess,
+        MarketPriceHistoryTableAccess, MarketTableAccess, MissileTableAccess,
+        MissileTypeTableAccess, PlayerLocation, PlayerLocationTableAccess, PlayerTableAccess,
+        RemoteTables, ShipDockingTableAccess, ShipLocation, ShipLocationTableAccess,
+        ShipMiningTableAccess, ShipPilotTableAccess, ShipStatusTableAccess, ShipTableAccess,
+        ShipTypeTableAccess, StationRotationTableAccess, StationTableAccess, WalletTableAccess,
+        WeaponFireTableAccess, WeaponTypeTableAccess,
     },
 };
 
@@ -23,6 +31,43 @@ pub struct StaticDataLoadedEvent;
 #[derive(Resource)]
 pub struct StaticDataLoadedSender(Sender<StaticDataLoadedEvent>);
 
+/// How much of the gap to a lower clock offset sample is closed per sample, so the offset follows
+/// a clock that drifts back without jumping on every late update.
+const SERVER_CLOCK_DECAY: f64 = 0.01;
+
+/// The server's clock, estimated from the time rows are updated at.
+///
+/// An update reaches the client after the server wrote it, so the offset it gives is never above
+/// the real one: higher samples are taken at once, lower ones only slowly pull the offset down.
+#[derive(Resource, Debug, Default)]
+pub struct ServerClock {
+    /// Microseconds to add to the local clock to get the server's.
+    offset: Option<i64>,
+}
+
+impl ServerClock {
+    /// The current time on the server, or the local time until an update came in.
+    pub fn now(&self) -> Timestamp {
+        let local = Timestamp::now().to_micros_since_unix_epoch();
+        Timestamp::from_micros_since_unix_epoch(local + self.offset.unwrap_or_default())
+    }
+
+    fn sample(&mut self, updated_at: Timestamp) {
+        let sample =
+            updated_at.to_micros_since_unix_epoch() - Timestamp::now().to_micros_since_unix_epoch();
+        self.offset = Some(match self.offset {
+            Some(offset) if sample < offset => {
+                offset - ((offset - sample) as f64 * SERVER_CLOCK_DECAY) as i64
+            }
+            _ => sample,
+        });
+    }
+}
+
+/// Why the server stopped accepting the local player, shown until they quit.
+#[derive(Resource)]
+struct Banned(String);
+
 pub struct SpacetimeDbPlugin;
 
 impl Plugin for SpacetimeDbPlugin {
@@ -42,19 +87,45 @@ impl Plugin for SpacetimeDbPlugin {
                 .with_module_name(module_name)
                 .with_run_fn(DbConnection::run_threaded)
                 .add_table(RemoteTables::asteroid)
+                .add_table(RemoteTables::asteroid_resource)
+                .add_table(RemoteTables::ban)
+                .add_table(RemoteTables::cargo)
+                .add_table(RemoteTables::chat_message)
+                .add_table(RemoteTables::docking_bay)
+                .add_table(RemoteTables::hardpoint)
+                .add_table(RemoteTables::home_station)
+                .add_table(RemoteTables::item_type)
+                .add_table(RemoteTables::market)
+                .add_table(RemoteTables::market_price_history)
+                .add_table(RemoteTables::missile)
+                .add_table(RemoteTables::missile_type)
                 .add_table(RemoteTables::player)
                 .add_table(RemoteTables::player_location)
+                .add_table(RemoteTables::rejected_call)
                 .add_table(RemoteTables::ship)
+                .add_table(RemoteTables::ship_docking)
+                .add_table(RemoteTables::ship_flight)
                 .add_table(RemoteTables::ship_location)
+                .add_table(RemoteTables::ship_mining)
                 .add_table(RemoteTables::ship_pilot)
+                .add_table(RemoteTables::ship_status)
                 .add_table(RemoteTables::ship_type)
-                .add_table(RemoteTables::station),
+                .add_table(RemoteTables::station)
+                .add_table(RemoteTables::station_rotation)
+                .add_table(RemoteTables::wallet)
+                .add_table(RemoteTables::weapon_fire)
+                .add_table(RemoteTables::weapon_type)
+                .add_table(RemoteTables::wreck),
         )
         .add_systems(OnEnter(GameState::StaticDataLoading), load_static_data)
         .add_systems(
             PreUpdate,
             (on_connected, on_connection_error, on_disconnected).chain(),
         )
+        .init_resource::<ServerClock>()
+        .add_systems(PreUpdate, sync_server_clock)
+        .add_systems(Update, on_banned)
+        .add_systems(EguiPrimaryContextPass, show_banned)
         .add_systems(
             Update,
             (
@@ -90,6 +161,57 @@ fn on_disconnected(mut events: ReadStdbDisconnectedEvent) {
     }
 }
 
+/// Estimates the server's clock from location updates. Inserts are left out: rows sent by a new
+/// subscription can have been written long ago.
+fn sync_server_clock(
+    mut clock: ResMut<ServerClock>,
+    mut ship_locations: ReadUpdateEvent<ShipLocation>,
+    mut player_locations: ReadUpdateEvent<PlayerLocation>,
+) {
+    for event in ship_locations.read() {
+        clock.sample(event.new.updated_at);
+    }
+    for event in player_locations.read() {
+        clock.sample(event.new.updated_at);
+    }
+}
+
+/// Leaves the server when the local player gets banned or kicked: the server can't close the
+/// connection itself, it only refuses new ones, and the calls made on this one, until the ban
+/// expires.
+fn on_banned(mut commands: Commands, mut events: ReadInsertEvent<Ban>, stdb: SpacetimeDB) {
+    for event in events.read().filter(|e| e.row.player_id == stdb.identity()) {
+        warn!("Removed from the server: {}", event.row.reason);
+        if let Err(err) = stdb.conn().disconnect() {
+            error!("Failed to disconnect: {err}");
+        }
+        commands.insert_resource(Banned(event.row.reason.clone()));
+    }
+}
+
+fn show_banned(
+    banned: Option<Res<Banned>>,
+    mut contexts: EguiContexts,
+    mut exit: EventWriter<AppExit>,
+) -> Result {
+    let Some(banned) = banned else {
+        return Ok(());
+    };
+
+    egui::Window::new("Disconnected")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(contexts.ctx_mut()?, |ui| {
+            ui.label(format!("You were removed from the server: {}", banned.0));
+            if ui.button("Quit").clicked() {
+                exit.write(AppExit::Success);
+            }
+        });
+
+    Ok(())
+}
+
 fn ensure_connected(stdb: SpacetimeDB, mut next_state: ResMut<NextState<GameState>>) {
     if stdb.is_active() {
         next_state.set(GameState::StaticDataLoading);
@@ -104,7 +226,13 @@ fn load_static_data(stdb: SpacetimeDB, sender: Res<StaticDataLoadedSender>) {
             sender.send(StaticDataLoadedEvent {}).unwrap();
         })
         .on_error(|_, err| panic!("Static data loading error: {err}"))
-        .subscribe(["SELECT * FROM ship_type"]);
+        .subscribe([
+            "SELECT * FROM ship_type",
+            "SELECT * FROM item_type",
+            "SELECT * FROM weapon_type",
+            "SELECT * FROM hardpoint",
+            "SELECT * FROM missile_type",
+        ]);
 }
 
 fn on_static_data_loaded(
@@ -113,6 +241,6 @@ fn on_static_data_loaded(
 ) {
     for _ in events.read() {
         info!("Static data loaded successfully.");
-        next_state.set(GameState::InGame);
+        next_state.set(GameState::ShipAssetsLoading);
     }
 }
@@ -2,7 +2,14 @@ use avian3d::prelude::{Collider, RigidBody};
 use bevy::{platform::collections::HashMap, prelude::*};
 use bevy_spacetimedb::{ReadDeleteEvent, ReadInsertEvent};
 
-use crate::{assets_loader::ModelAssets, bindings::Asteroid, materials::GameMaterial};
+use crate::{
+    assets_loader::ModelAssets, bindings::Asteroid as AsteroidRow, materials::GameMaterial,
+};
+
+#[derive(Component, Debug, Clone)]
+pub struct Asteroid {
+    pub id: u64,
+}
 
 #[derive(Resource, Default, Debug, Clone)]
 struct AsteroidsRegistry {
@@ -34,7 +41,7 @@ impl Plugin for AsteroidsPlugin {
 }
 
 fn spawn_asteroid(
-    mut events: ReadInsertEvent<Asteroid>,
+    mut events: ReadInsertEvent<AsteroidRow>,
     mut commands: Commands,
     mut registry: ResMut<AsteroidsRegistry>,
     model_assets: Res<ModelAssets>,
@@ -58,6 +65,22 @@ fn spawn_asteroid(
             2 => model_assets.asteroid_03.clone(),
             3 => model_assets.asteroid_04.clone(),
             4 => model_assets.asteroid_05.clone(),
+            5 => model_assets.asteroid_rock_01.clone(),
+            6 => model_assets.asteroid_rock_02.clone(),
+            7 => model_assets.asteroid_rock_03.clone(),
+            8 => model_assets.asteroid_rock_04.clone(),
+            9 => model_assets.asteroid_rock_05.clone(),
+            10 => model_assets.asteroid_rock_06.clone(),
+            11 => model_assets.asteroid_rock_07.clone(),
+            12 => model_assets.asteroid_holes_01.clone(),
+            13 => model_assets.asteroid_holes_02.clone(),
+            14 => model_assets.asteroid_holes_03.clone(),
+            15 => model_assets.asteroid_holes_04.clone(),
+            16 => model_assets.asteroid_holes_05.clone(),
+            17 => model_assets.asteroid_large_01.clone(),
+            18 => model_assets.asteroid_large_02.clone(),
+            19 => model_assets.asteroid_large_holes_01.clone(),
+            20 => model_assets.asteroid_large_holes_02.clone(),
             _ => {
                 panic!("Unknown asteroid type: {}", asteroid.asteroid_type);
             }
@@ -69,12 +92,30 @@ fn spawn_asteroid(
             2 => Collider::sphere(3.8),
             3 => Collider::capsule(2.8, 4.6),
             4 => Collider::cylinder(3.55, 4.5),
+            // Bounding spheres, matching `asteroid_bounding_radius` on the server
+            5 => Collider::sphere(2.0),
+            6 => Collider::sphere(2.5),
+            7 => Collider::sphere(3.0),
+            8 => Collider::sphere(3.5),
+            9 => Collider::sphere(2.2),
+            10 => Collider::sphere(2.8),
+            11 => Collider::sphere(4.0),
+            12 => Collider::sphere(4.0),
+            13 => Collider::sphere(4.5),
+            14 => Collider::sphere(5.0),
+            15 => Collider::sphere(4.0),
+            16 => Collider::sphere(5.5),
+            17 => Collider::sphere(12.0),
+            18 => Collider::sphere(14.0),
+            19 => Collider::sphere(12.0),
+            20 => Collider::sphere(15.0),
             _ => Collider::sphere(1.0),
         };
 
         let entity = commands
             .spawn((
                 Name::new(format!("Asteroid {}", asteroid.id)),
+                Asteroid { id: asteroid.id },
                 SceneRoot(model),
                 GameMaterial::Standard,
                 RigidBody::Static,
@@ -89,7 +130,7 @@ fn spawn_asteroid(
 fn remove_asteroid(
     mut commands: Commands,
     mut registry: ResMut<AsteroidsRegistry>,
-    mut events: ReadDeleteEvent<Asteroid>,
+    mut events: ReadDeleteEvent<AsteroidRow>,
 ) {
     for event in events.read() {
         trace!("Removing asteroid: {:?}", event.row);
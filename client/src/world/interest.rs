@@ -0,0 +1,180 @@
+use bevy::prelude::*;
+use simulation::sectors::sector_of;
+use spacetimedb_sdk::SubscriptionHandle as _;
+
+use crate::{
+    GameState, bindings::SubscriptionHandle, local_player::LocalPlayer, spacetimedb::SpacetimeDB,
+};
+
+/// How many sectors around the local player's own are subscribed to, in every direction.
+const INTEREST_RADIUS: i32 = 1;
+
+/// The subscription to the rows in the sectors around the local player.
+#[derive(Resource, Default)]
+struct InterestSubscription {
+    sector: Option<IVec3>,
+    handle: Option<SubscriptionHandle>,
+}
+
+pub struct InterestPlugin;
+
+impl Plugin for InterestPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InterestSubscription>()
+            .add_systems(OnEnter(GameState::InGame), subscribe_to_local_player)
+            .add_systems(Update, update_interest.run_if(in_state(GameState::InGame)));
+    }
+}
+
+/// Subscribes to the rows about the local player that are needed wherever they are, along with
+/// the factions, which are few and shown next to player names everywhere.
+fn subscribe_to_local_player(stdb: SpacetimeDB) {
+    stdb.subscription_builder()
+        .on_applied(|_| debug!("Subscribed to local player"))
+        .on_error(|_, err| {
+            panic!("Failed to subscribe to local player: {err}");
+        })
+        .subscribe([
+            "SELECT * FROM player WHERE id = :sender",
+            "SELECT * FROM ship_pilot WHERE player_id = :sender",
+            "SELECT ship_flight.* FROM ship_flight JOIN ship_pilot ON ship_flight.ship_id = ship_pilot.ship_id WHERE ship_pilot.player_id = :sender",
+            "SELECT * FROM wallet WHERE player_id = :sender",
+            "SELECT * FROM home_station WHERE player_id = :sender",
+            "SELECT * FROM chat_message WHERE recipient_id = :sender",
+            "SELECT * FROM faction_invite WHERE player_id = :sender",
+            "SELECT * FROM ban WHERE player_id = :sender",
+            "SELECT * FROM rejected_call WHERE player_id = :sender",
+            "SELECT * FROM faction",
+            "SELECT * FROM faction_member",
+        ]);
+}
+
+/// Re-subscribes to the sectors around the local player whenever they move to another sector.
+///
+/// Rows that are no longer in any subscribed sector are removed from the client cache once the
+/// previous subscription ends, which despawns their entities through the usual delete events.
+fn update_interest(
+    mut interest: ResMut<InterestSubscription>,
+    local_player: Option<Single<&GlobalTransform, With<LocalPlayer>>>,
+    stdb: SpacetimeDB,
+) {
+    // The local player follows the camera, which is parented to the ship while piloting.
+    let Some(local_player) = local_player else {
+        return;
+    };
+
+    let sector = sector_of(local_player.translation());
+    if interest.sector == Some(sector) {
+        return;
+    }
+
+    debug!("Local player entered sector {sector}, updating interest");
+    let previous = interest.handle.take();
+    let handle = stdb
+        .subscription_builder()
+        .on_applied(move |_| {
+            // Only drop the previous sectors once the new ones are in the cache, so entities in
+            // both are not despawned and spawned again.
+            if let Some(previous) = previous
+                && let Err(err) = previous.unsubscribe()
+            {
+                warn!("Failed to unsubscribe from previous sectors: {err}");
+            }
+        })
+        .on_error(move |_, err| {
+            error!("Failed to subscribe to sector {sector}: {err}");
+        })
+        .subscribe(interest_queries(sector));
+
+    interest.sector = Some(sector);
+    interest.handle = Some(handle);
+}
+
+/// Builds the queries for every row located within `INTEREST_RADIUS` sectors of `sector`.
+fn interest_queries(sector: IVec3) -> Vec<String> {
+    let min = sector - IVec3::splat(INTEREST_RADIUS);
+    let max = sector + IVec3::splat(INTEREST_RADIUS);
+    let in_range = |table: &str| {
+        format!(
+            "{table}.sector_x >= {} AND {table}.sector_x <= {} \
+             AND {table}.sector_y >= {} AND {table}.sector_y <= {} \
+             AND {table}.sector_z >= {} AND {table}.sector_z <= {}",
+            min.x, max.x, min.y, max.y, min.z, max.z
+        )
+    };
+
+    vec![
+        format!("SELECT * FROM station WHERE {}", in_range("station")),
+        format!("SELECT * FROM asteroid WHERE {}", in_range("asteroid")),
+        format!(
+            "SELECT asteroid_resource.* FROM asteroid_resource JOIN asteroid ON asteroid_resource.asteroid_id = asteroid.id WHERE {}",
+            in_range("asteroid")
+        ),
+        format!(
+            "SELECT * FROM player_location WHERE {}",
+            in_range("player_location")
+        ),
+        format!(
+            "SELECT * FROM ship_location WHERE {}",
+            in_range("ship_location")
+        ),
+        format!(
+            "SELECT ship.* FROM ship JOIN ship_location ON ship.id = ship_location.ship_id WHERE {}",
+            in_range("ship_location")
+        ),
+        format!(
+            "SELECT ship_pilot.* FROM ship_pilot JOIN ship_location ON ship_pilot.ship_id = ship_location.ship_id WHERE {}",
+            in_range("ship_location")
+        ),
+        format!(
+            "SELECT cargo.* FROM cargo JOIN ship_location ON cargo.ship_id = ship_location.ship_id WHERE {}",
+            in_range("ship_location")
+        ),
+        format!(
+            "SELECT ship_mining.* FROM ship_mining JOIN ship_location ON ship_mining.ship_id = ship_location.ship_id WHERE {}",
+            in_range("ship_location")
+        ),
+        format!(
+            "SELECT ship_status.* FROM ship_status JOIN ship_location ON ship_status.ship_id = ship_location.ship_id WHERE {}",
+            in_range("ship_location")
+        ),
+        format!(
+            "SELECT weapon_fire.* FROM weapon_fire JOIN ship_location ON weapon_fire.ship_id = ship_location.ship_id WHERE {}",
+            in_range("ship_location")
+        ),
+        format!("SELECT * FROM missile WHERE {}", in_range("missile")),
+        format!("SELECT * FROM wreck WHERE {}", in_range("wreck")),
+        format!(
+            "SELECT station_rotation.* FROM station_rotation JOIN station ON station_rotation.station_id = station.id WHERE {}",
+            in_range("station")
+        ),
+        format!(
+            "SELECT docking_bay.* FROM docking_bay JOIN station ON docking_bay.station_id = station.id WHERE {}",
+            in_range("station")
+        ),
+        format!(
+            "SELECT market.* FROM market JOIN station ON market.station_id = station.id WHERE {}",
+            in_range("station")
+        ),
+        format!(
+            "SELECT * FROM ship_docking WHERE {}",
+            in_range("ship_docking")
+        ),
+        format!(
+            "SELECT ship.* FROM ship JOIN ship_docking ON ship.id = ship_docking.ship_id WHERE {}",
+            in_range("ship_docking")
+        ),
+        format!(
+            "SELECT ship_pilot.* FROM ship_pilot JOIN ship_docking ON ship_pilot.ship_id = ship_docking.ship_id WHERE {}",
+            in_range("ship_docking")
+        ),
+        format!(
+            "SELECT cargo.* FROM cargo JOIN ship_docking ON cargo.ship_id = ship_docking.ship_id WHERE {}",
+            in_range("ship_docking")
+        ),
+        format!(
+            "SELECT ship_status.* FROM ship_status JOIN ship_docking ON ship_status.ship_id = ship_docking.ship_id WHERE {}",
+            in_range("ship_docking")
+        ),
+    ]
+}
@@ -1,23 +1,35 @@
 use asteroids::AsteroidsPlugin;
 use avian3d::math::PI;
 use bevy::prelude::*;
+use interest::InterestPlugin;
 use stations::StationsPlugin;
+use wrecks::WrecksPlugin;
 
-use crate::{GameState, spacetimedb::SpacetimeDB};
+use crate::GameState;
 
 mod asteroids;
+mod interest;
 mod stations;
+mod wrecks;
+
+pub use asteroids::Asteroid;
+pub use stations::{Station, station_rotation};
 
 pub struct WorldPlugin;
 
 impl Plugin for WorldPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins((StationsPlugin, AsteroidsPlugin))
-            .add_systems(OnEnter(GameState::InGame), subscribe_to_world);
+        app.add_plugins((
+            StationsPlugin,
+            AsteroidsPlugin,
+            WrecksPlugin,
+            InterestPlugin,
+        ))
+        .add_systems(OnEnter(GameState::InGame), spawn_lights);
     }
 }
 
-fn subscribe_to_world(mut commands: Commands, stdb: SpacetimeDB) {
+fn spawn_lights(mut commands: Commands) {
     commands.spawn((
         DirectionalLight {
             illuminance: 500.0,
@@ -32,11 +44,4 @@ fn subscribe_to_world(mut commands: Commands, stdb: SpacetimeDB) {
         color: Color::srgb_u8(210, 220, 240),
         ..default()
     });
-
-    stdb.subscription_builder()
-        .on_applied(|_| debug!("Subscribed to world"))
-        .on_error(|_, err| {
-            panic!("Failed to subscribe to world: {err}");
-        })
-        .subscribe_to_all_tables();
 }
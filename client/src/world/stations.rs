@@ -1,19 +1,52 @@
-use std::time::{SystemTime, UNIX_EPOCH};
-
 use avian3d::prelude::{Collider, RigidBody};
 use bevy::{platform::collections::HashMap, prelude::*};
 use bevy_spacetimedb::{ReadDeleteEvent, ReadInsertEvent, ReadUpdateEvent};
+use simulation::stations::StationRotation;
 
 use crate::{
     assets_loader::{CollisionAssets, ModelAssets},
-    bindings::Station as StationRow,
+    bindings::{
+        Station as StationRow, StationRotation as StationRotationRow, StationRotationTableAccess,
+    },
     materials::GameMaterial,
+    spacetimedb::{ServerClock, SpacetimeDB},
 };
 
 #[derive(Component, Debug, Clone)]
 pub struct Station {
-    pub rotation_angle: f32,
-    pub reach_angle_at: u128,
+    pub id: u64,
+    pub rotation: StationRotation,
+}
+
+impl From<&StationRotationRow> for StationRotation {
+    fn from(rotation: &StationRotationRow) -> Self {
+        Self {
+            epoch: rotation.epoch.to_micros_since_unix_epoch(),
+            epoch_angle: rotation.epoch_angle,
+            speed: rotation.speed,
+            axis: rotation
+                .axis
+                .as_ref()
+                .map(|axis| Vec3::new(axis.x, axis.y, axis.z)),
+        }
+    }
+}
+
+/// How a station rotates. Until the server migrated it, a station from an older database turns
+/// as its row says.
+pub fn station_rotation(stdb: &SpacetimeDB, station: &StationRow) -> StationRotation {
+    stdb.db()
+        .station_rotation()
+        .station_id()
+        .find(&station.id)
+        .map(|rotation| (&rotation).into())
+        .unwrap_or_else(|| {
+            StationRotation::from_target(
+                station.target_angle,
+                station.rotation_speed,
+                station.reach_angle_at,
+            )
+        })
 }
 
 #[derive(Resource, Default, Debug, Clone)]
@@ -41,7 +74,10 @@ impl Plugin for StationsPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<StationsRegistry>()
             .add_systems(PreUpdate, spawn_stations)
-            .add_systems(Update, (update_stations, rotate_stations))
+            .add_systems(
+                Update,
+                (update_stations, update_station_rotations, rotate_stations).chain(),
+            )
             .add_systems(PostUpdate, remove_stations);
     }
 }
@@ -53,6 +89,7 @@ fn spawn_stations(
     model_assets: Res<ModelAssets>,
     collision_assets: Res<CollisionAssets>,
     meshes: Res<Assets<Mesh>>,
+    stdb: SpacetimeDB,
 ) {
     for event in events.read() {
         debug!("Spawning station: {:?}", event.row);
@@ -61,8 +98,8 @@ fn spawn_stations(
         let entity = commands
             .spawn((
                 Station {
-                    rotation_angle: station.target_angle,
-                    reach_angle_at: station.reach_angle_at,
+                    id: station.id,
+                    rotation: station_rotation(&stdb, station),
                 },
                 Name::new(format!("Station {}", station.id)),
                 SceneRoot(model_assets.ship_station_01.clone()),
@@ -110,6 +147,7 @@ fn update_stations(
     mut events: ReadUpdateEvent<StationRow>,
     mut commands: Commands,
     registry: ResMut<StationsRegistry>,
+    stdb: SpacetimeDB,
 ) {
     for event in events.read() {
         trace!("Updating station: {:?}", event.new);
@@ -117,8 +155,8 @@ fn update_stations(
 
         if let Some(entity) = registry.get(station.id) {
             commands.entity(*entity).insert(Station {
-                rotation_angle: station.target_angle,
-                reach_angle_at: station.reach_angle_at,
+                id: station.id,
+                rotation: station_rotation(&stdb, station),
             });
         } else {
             warn!(
@@ -129,6 +167,31 @@ fn update_stations(
     }
 }
 
+/// Turns stations along their rotation as it changes, or as they get one from the migration.
+fn update_station_rotations(
+    mut inserts: ReadInsertEvent<StationRotationRow>,
+    mut updates: ReadUpdateEvent<StationRotationRow>,
+    registry: Res<StationsRegistry>,
+    mut stations: Query<&mut Station>,
+) {
+    let rotations = inserts
+        .read()
+        .map(|event| &event.row)
+        .chain(updates.read().map(|event| &event.new));
+
+    for rotation in rotations {
+        trace!("Updating station rotation: {:?}", rotation);
+
+        // The station may not be in the area of interest.
+        if let Some(mut station) = registry
+            .get(rotation.station_id)
+            .and_then(|entity| stations.get_mut(*entity).ok())
+        {
+            station.rotation = rotation.into();
+        }
+    }
+}
+
 fn remove_stations(
     mut commands: Commands,
     mut registry: ResMut<StationsRegistry>,
@@ -150,22 +213,13 @@ fn remove_stations(
     }
 }
 
-fn rotate_stations(mut query: Query<(&mut Transform, &Station)>, time: Res<Time>) {
-    let now_ms = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap()
-        .as_millis();
+/// Turns the stations to the orientation the server has them at, their rotation being described
+/// once and for all by their row. The server's clock is used, as that's the one their row is on.
+fn rotate_stations(mut query: Query<(&mut Transform, &Station)>, clock: Res<ServerClock>) {
+    let now = clock.now().to_micros_since_unix_epoch();
 
     for (mut transform, station) in query.iter_mut() {
-        let current_rotation = transform.rotation;
-        let target_rotation = Quat::from_rotation_y(station.rotation_angle);
-
-        // Compute time remaining until target time is reached (in seconds)
-        let remaining =
-            ((station.reach_angle_at + 100) as i128 - now_ms as i128).max(0) as f32 / 1000.0;
-
-        let t = (time.delta_secs() / remaining).clamp(0.0, 1.0);
-        transform.rotation = current_rotation.slerp(target_rotation, t);
+        transform.rotation = station.rotation.orientation_at(now);
     }
 }
 
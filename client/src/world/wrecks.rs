@@ -0,0 +1,83 @@
+use bevy::{platform::collections::HashMap, prelude::*};
+use bevy_spacetimedb::{ReadDeleteEvent, ReadInsertEvent};
+
+use crate::{assets_loader::ModelAssets, bindings::Wreck, materials::GameMaterial};
+
+/// The entities of the debris in interest, by wreck id.
+#[derive(Resource, Default, Debug, Clone)]
+struct WrecksRegistry(HashMap<u64, Entity>);
+
+pub struct WrecksPlugin;
+
+impl Plugin for WrecksPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WrecksRegistry>()
+            .add_systems(PreUpdate, spawn_wreck)
+            .add_systems(PostUpdate, remove_wreck);
+    }
+}
+
+fn spawn_wreck(
+    mut events: ReadInsertEvent<Wreck>,
+    mut commands: Commands,
+    mut registry: ResMut<WrecksRegistry>,
+    model_assets: Res<ModelAssets>,
+) {
+    for event in events.read() {
+        trace!("Spawning wreck: {:?}", event.row);
+        let wreck = &event.row;
+
+        let transform = Transform::from_xyz(wreck.pos_x, wreck.pos_y, wreck.pos_z)
+            .with_rotation(Quat::from_xyzw(
+                wreck.rot_x,
+                wreck.rot_y,
+                wreck.rot_z,
+                wreck.rot_w,
+            ))
+            .with_scale(Vec3::splat(wreck.scale));
+
+        // Matches `Wreck::debris_type` on the server
+        let model = match wreck.debris_type {
+            0 => model_assets.debris_shell_01.clone(),
+            1 => model_assets.debris_shell_02.clone(),
+            2 => model_assets.debris_shell_03.clone(),
+            3 => model_assets.debris_shell_04.clone(),
+            4 => model_assets.debris_shell_05.clone(),
+            5 => model_assets.debris_shell_06.clone(),
+            6 => model_assets.debris_shell_07.clone(),
+            7 => model_assets.debris_shell_08.clone(),
+            8 => model_assets.debris_structure_01.clone(),
+            9 => model_assets.debris_structure_02.clone(),
+            10 => model_assets.debris_structure_03.clone(),
+            _ => {
+                warn!("Unknown debris type: {}", wreck.debris_type);
+                continue;
+            }
+        };
+
+        let entity = commands
+            .spawn((
+                Name::new(format!("Wreck {}", wreck.id)),
+                SceneRoot(model),
+                GameMaterial::Standard,
+                transform,
+            ))
+            .id();
+        registry.0.insert(wreck.id, entity);
+    }
+}
+
+fn remove_wreck(
+    mut commands: Commands,
+    mut registry: ResMut<WrecksRegistry>,
+    mut events: ReadDeleteEvent<Wreck>,
+) {
+    for event in events.read() {
+        trace!("Removing wreck: {:?}", event.row);
+        let wreck = &event.row;
+
+        if let Some(entity) = registry.0.remove(&wreck.id) {
+            commands.entity(entity).despawn();
+        }
+    }
+}
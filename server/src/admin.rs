@@ -0,0 +1,462 @@
+use std::time::Duration;
+
+use glam::{Quat, Vec3};
+use log::info;
+use spacetimedb::{reducer, Identity, ReducerContext, TimeDuration, Timestamp};
+use spacetimedsl::{dsl, Wrapper};
+
+use crate::{
+    asteroid_field::{GeneratedAsteroid, ASTEROID_TYPES},
+    asteroids::spawn_asteroid,
+    chat::announce,
+    docking::release_ship,
+    player::leave_ship,
+    rate_limit::is_rate_limited,
+    sectors::sector_of,
+    ships::spawn_ship,
+    tables::*,
+    world::spawn_station,
+};
+
+/// How long kicked players are kept from reconnecting.
+///
+/// Modules can't close client connections, so kicking a player bans them for a little while.
+/// Their client is expected to disconnect when it sees the ban, and whether it does or not, its
+/// calls to gameplay reducers are refused until the ban expires, see `ensure_not_banned`.
+const KICK_DURATION: Duration = Duration::from_secs(5 * 60);
+
+/// Whether a ban or mute expiring at `expires_at`, if ever, still applies at `now`.
+pub fn in_effect(expires_at: Option<Timestamp>, now: Timestamp) -> bool {
+    expires_at.is_none_or(|expires_at| expires_at > now)
+}
+
+/// Refuses the calls of a player under a ban to gameplay reducers.
+///
+/// Bans are enforced when connecting, but the session a player had open when they got banned
+/// stays open: modules can't close connections, and clients are only asked to leave.
+pub fn ensure_not_banned(ctx: &ReducerContext) -> Result<(), String> {
+    if let Ok(ban) = dsl(ctx).get_ban_by_player_id(&PlayerId::new(ctx.sender)) {
+        if in_effect(*ban.get_expires_at(), ctx.timestamp) {
+            return Err(format!("Banned: {}", ban.get_reason()));
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks the caller is an admin, and records the action they're taking in the audit log.
+///
+/// The record is rolled back along with everything else if the action fails, so the log only
+/// holds the actions that were carried out.
+fn authorize(ctx: &ReducerContext, action: &str, details: String) -> Result<(), String> {
+    let dsl = dsl(ctx);
+
+    if dsl.get_admin_by_id(&AdminId::new(ctx.sender)).is_err() {
+        return Err("Only admins can do this".into());
+    }
+
+    info!("Admin {} called {action}: {details}", ctx.sender);
+    dsl.create_admin_audit(ctx.sender, action, &details, ctx.timestamp)?;
+
+    Ok(())
+}
+
+fn expiry(ctx: &ReducerContext, duration: Option<Duration>) -> Option<Timestamp> {
+    duration.map(|duration| ctx.timestamp + TimeDuration::from(duration))
+}
+
+fn finite_position(x: f32, y: f32, z: f32) -> Result<Vec3, String> {
+    let position = Vec3::new(x, y, z);
+    if !position.is_finite() {
+        return Err("Position contains non-finite values".into());
+    }
+
+    Ok(position)
+}
+
+#[reducer]
+pub fn admin_grant(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    authorize(ctx, "admin_grant", identity.to_string())?;
+    let dsl = dsl(ctx);
+
+    if dsl.get_admin_by_id(&AdminId::new(identity)).is_ok() {
+        return Ok(());
+    }
+    dsl.create_admin(identity, ctx.sender, ctx.timestamp)?;
+
+    Ok(())
+}
+
+#[reducer]
+pub fn admin_revoke(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    authorize(ctx, "admin_revoke", identity.to_string())?;
+    let dsl = dsl(ctx);
+
+    let admin_id = AdminId::new(identity);
+    if dsl.get_admin_by_id(&admin_id).is_err() {
+        return Err("Not an admin".into());
+    }
+    if dsl.get_all_admins().count() == 1 {
+        return Err("Cannot revoke the last admin".into());
+    }
+    dsl.delete_admin_by_id(&admin_id)?;
+
+    Ok(())
+}
+
+/// Moves a player, along with the ship they're piloting if they are, freeing it from its
+/// docking bay if it's docked. Offline players are moved to where they'll resume from.
+#[reducer]
+pub fn admin_teleport_player(
+    ctx: &ReducerContext,
+    player_id: Identity,
+    x: f32,
+    y: f32,
+    z: f32,
+) -> Result<(), String> {
+    authorize(
+        ctx,
+        "admin_teleport_player",
+        format!("{player_id} to ({x}, {y}, {z})"),
+    )?;
+    let dsl = dsl(ctx);
+
+    let position = finite_position(x, y, z)?;
+    let [sector_x, sector_y, sector_z] = sector_of(position.x, position.y, position.z);
+    let player_id = PlayerId::new(player_id);
+
+    if let Ok(mut location) = dsl.get_player_location_by_player_id(&player_id) {
+        location.set_x(position.x);
+        location.set_y(position.y);
+        location.set_z(position.z);
+        location.set_sector_x(sector_x);
+        location.set_sector_y(sector_y);
+        location.set_sector_z(sector_z);
+        location.set_updated_at(ctx.timestamp);
+        dsl.update_player_location_by_player_id(location)?;
+    } else if let Ok(ship_pilot) = dsl.get_ship_pilot_by_player_id(&player_id) {
+        let ship_id = ship_pilot.get_ship_id();
+        if dsl.get_ship_docking_by_ship_id(&ship_id).is_ok() {
+            release_ship(ctx, &ship_id)?;
+        }
+
+        let mut location = dsl.get_ship_location_by_ship_id(&ship_id)?;
+        location.set_x(position.x);
+        location.set_y(position.y);
+        location.set_z(position.z);
+        location.set_sector_x(sector_x);
+        location.set_sector_y(sector_y);
+        location.set_sector_z(sector_z);
+        location.set_updated_at(ctx.timestamp);
+        dsl.update_ship_location_by_ship_id(location)?;
+    } else {
+        let mut player = dsl.get_player_by_id(&player_id)?;
+        player.set_x(position.x);
+        player.set_y(position.y);
+        player.set_z(position.z);
+        dsl.update_player_by_id(player)?;
+    }
+
+    Ok(())
+}
+
+#[reducer]
+pub fn admin_spawn_asteroid(
+    ctx: &ReducerContext,
+    x: f32,
+    y: f32,
+    z: f32,
+    asteroid_type: u8,
+    scale: f32,
+) -> Result<(), String> {
+    authorize(
+        ctx,
+        "admin_spawn_asteroid",
+        format!("type {asteroid_type} at scale {scale} at ({x}, {y}, {z})"),
+    )?;
+
+    if asteroid_type >= ASTEROID_TYPES {
+        return Err(format!(
+            "Asteroid types go from 0 to {}",
+            ASTEROID_TYPES - 1
+        ));
+    }
+    if !(scale.is_finite() && scale > 0.0) {
+        return Err("Scale must be positive".into());
+    }
+
+    spawn_asteroid(
+        ctx,
+        &GeneratedAsteroid {
+            position: finite_position(x, y, z)?,
+            rotation: Quat::IDENTITY,
+            asteroid_type,
+            scale,
+        },
+    )
+}
+
+#[reducer]
+pub fn admin_delete_asteroid(ctx: &ReducerContext, asteroid_id: u64) -> Result<(), String> {
+    authorize(ctx, "admin_delete_asteroid", asteroid_id.to_string())?;
+
+    dsl(ctx).delete_asteroid_by_id(AsteroidId::new(asteroid_id))?;
+
+    Ok(())
+}
+
+#[reducer]
+pub fn admin_spawn_station(
+    ctx: &ReducerContext,
+    name: String,
+    x: f32,
+    y: f32,
+    z: f32,
+) -> Result<(), String> {
+    authorize(
+        ctx,
+        "admin_spawn_station",
+        format!("{name} at ({x}, {y}, {z})"),
+    )?;
+
+    spawn_station(ctx, &name, finite_position(x, y, z)?)?;
+
+    Ok(())
+}
+
+/// Deletes a station, the ships docked at it being released into free flight first.
+#[reducer]
+pub fn admin_delete_station(ctx: &ReducerContext, station_id: u64) -> Result<(), String> {
+    authorize(ctx, "admin_delete_station", station_id.to_string())?;
+    let dsl = dsl(ctx);
+
+    let station_id = StationId::new(station_id);
+    let docked = dsl
+        .get_ship_dockings_by_station_id(&station_id)
+        .map(|docking| docking.get_ship_id())
+        .collect::<Vec<_>>();
+    for ship_id in docked {
+        release_ship(ctx, &ship_id)?;
+    }
+    dsl.delete_station_by_id(&station_id)?;
+
+    Ok(())
+}
+
+/// Changes how fast a station turns, in radians per second. It carries on from the angle it's at,
+/// its rotation being rebased on the time of the change.
+#[reducer]
+pub fn admin_set_station_rotation_speed(
+    ctx: &ReducerContext,
+    station_id: u64,
+    speed: f32,
+) -> Result<(), String> {
+    authorize(
+        ctx,
+        "admin_set_station_rotation_speed",
+        format!("{station_id} to {speed} rad/s"),
+    )?;
+    let dsl = dsl(ctx);
+
+    if !speed.is_finite() {
+        return Err("Rotation speed must be finite".into());
+    }
+
+    let mut rotation = dsl.get_station_rotation_by_station_id(&StationId::new(station_id))?;
+    let rebased = simulation::stations::StationRotation::from(&rotation)
+        .rebased(ctx.timestamp.to_micros_since_unix_epoch());
+    rotation.set_epoch(ctx.timestamp);
+    rotation.set_epoch_angle(rebased.epoch_angle);
+    rotation.set_speed(speed);
+    dsl.update_station_rotation_by_station_id(rotation)?;
+
+    Ok(())
+}
+
+#[reducer]
+pub fn admin_spawn_ship(
+    ctx: &ReducerContext,
+    ship_type_id: u64,
+    owner_id: Identity,
+    x: f32,
+    y: f32,
+    z: f32,
+) -> Result<(), String> {
+    authorize(
+        ctx,
+        "admin_spawn_ship",
+        format!("type {ship_type_id} for {owner_id} at ({x}, {y}, {z})"),
+    )?;
+    let dsl = dsl(ctx);
+
+    let ship_type = dsl.get_ship_type_by_id(ShipTypeId::new(ship_type_id))?;
+    spawn_ship(
+        ctx,
+        &ship_type,
+        &PlayerId::new(owner_id),
+        finite_position(x, y, z)?,
+        Quat::IDENTITY,
+    )?;
+
+    Ok(())
+}
+
+/// Deletes a ship, its pilot being put on foot next to it first.
+#[reducer]
+pub fn admin_delete_ship(ctx: &ReducerContext, ship_id: u64) -> Result<(), String> {
+    authorize(ctx, "admin_delete_ship", ship_id.to_string())?;
+    let dsl = dsl(ctx);
+
+    let ship_id = ShipId::new(ship_id);
+    if let Ok(ship_pilot) = dsl.get_ship_pilot_by_ship_id(&ship_id) {
+        leave_ship(ctx, &ship_pilot.get_player_id())?;
+    }
+    dsl.delete_ship_by_id(&ship_id)?;
+
+    Ok(())
+}
+
+/// Kicks a player out of the game by banning them for `KICK_DURATION`.
+#[reducer]
+pub fn admin_kick(ctx: &ReducerContext, player_id: Identity, reason: String) -> Result<(), String> {
+    authorize(ctx, "admin_kick", format!("{player_id}: {reason}"))?;
+    let dsl = dsl(ctx);
+
+    let player_id = PlayerId::new(player_id);
+    if dsl.get_ban_by_player_id(&player_id).is_ok() {
+        return Err("Player is already banned".into());
+    }
+    dsl.create_ban(
+        &player_id,
+        &reason,
+        ctx.sender,
+        ctx.timestamp,
+        expiry(ctx, Some(KICK_DURATION)),
+    )?;
+
+    Ok(())
+}
+
+/// Bans a player for `duration_secs`, or forever, replacing any ban they were under.
+#[reducer]
+pub fn admin_ban(
+    ctx: &ReducerContext,
+    player_id: Identity,
+    reason: String,
+    duration_secs: Option<u64>,
+) -> Result<(), String> {
+    authorize(
+        ctx,
+        "admin_ban",
+        format!("{player_id} for {duration_secs:?} seconds: {reason}"),
+    )?;
+    let dsl = dsl(ctx);
+
+    let player_id = PlayerId::new(player_id);
+    if dsl.get_ban_by_player_id(&player_id).is_ok() {
+        dsl.delete_ban_by_player_id(&player_id)?;
+    }
+    dsl.create_ban(
+        &player_id,
+        &reason,
+        ctx.sender,
+        ctx.timestamp,
+        expiry(ctx, duration_secs.map(Duration::from_secs)),
+    )?;
+
+    Ok(())
+}
+
+#[reducer]
+pub fn admin_unban(ctx: &ReducerContext, player_id: Identity) -> Result<(), String> {
+    authorize(ctx, "admin_unban", player_id.to_string())?;
+
+    dsl(ctx).delete_ban_by_player_id(&PlayerId::new(player_id))?;
+
+    Ok(())
+}
+
+/// Keeps a player from chatting for `duration_secs`, or forever, replacing any mute they were
+/// under.
+#[reducer]
+pub fn admin_mute(
+    ctx: &ReducerContext,
+    player_id: Identity,
+    duration_secs: Option<u64>,
+) -> Result<(), String> {
+    authorize(
+        ctx,
+        "admin_mute",
+        format!("{player_id} for {duration_secs:?} seconds"),
+    )?;
+    let dsl = dsl(ctx);
+
+    let player_id = dsl.get_player_by_id(&PlayerId::new(player_id))?.get_id();
+    if dsl.get_mute_by_player_id(&player_id).is_ok() {
+        dsl.delete_mute_by_player_id(&player_id)?;
+    }
+    dsl.create_mute(
+        &player_id,
+        ctx.sender,
+        ctx.timestamp,
+        expiry(ctx, duration_secs.map(Duration::from_secs)),
+    )?;
+
+    Ok(())
+}
+
+#[reducer]
+pub fn admin_unmute(ctx: &ReducerContext, player_id: Identity) -> Result<(), String> {
+    authorize(ctx, "admin_unmute", player_id.to_string())?;
+
+    dsl(ctx).delete_mute_by_player_id(&PlayerId::new(player_id))?;
+
+    Ok(())
+}
+
+/// Sends an announcement to every player online.
+#[reducer]
+pub fn admin_broadcast(ctx: &ReducerContext, text: String) -> Result<(), String> {
+    authorize(ctx, "admin_broadcast", text.clone())?;
+
+    announce(ctx, &text)
+}
+
+/// Changes how often players may call a rate-limited reducer, see `rate_limit.rs`.
+#[reducer]
+pub fn admin_set_rate_limit(
+    ctx: &ReducerContext,
+    reducer: String,
+    capacity: f32,
+    refill_rate: f32,
+) -> Result<(), String> {
+    authorize(
+        ctx,
+        "admin_set_rate_limit",
+        format!("{reducer} to {capacity} calls, refilled at {refill_rate} per second"),
+    )?;
+    let dsl = dsl(ctx);
+
+    if !is_rate_limited(&reducer) {
+        return Err(format!("{reducer} is not rate limited"));
+    }
+    if !(capacity.is_finite() && capacity >= 1.0) {
+        return Err("Capacity must be at least 1".into());
+    }
+    if !(refill_rate.is_finite() && refill_rate > 0.0) {
+        return Err("Refill rate must be positive".into());
+    }
+
+    match dsl.get_rate_limit_by_reducer(&reducer) {
+        Ok(mut rate_limit) => {
+            rate_limit.set_capacity(capacity);
+            rate_limit.set_refill_rate(refill_rate);
+            dsl.update_rate_limit_by_id(rate_limit)?;
+        }
+        Err(_) => {
+            dsl.create_rate_limit(&reducer, capacity, refill_rate)?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,423 @@
+use std::f32::consts::TAU;
+
+use glam::{Quat, Vec3};
+
+use crate::egress::{asteroid_bounding_radius, Obstacle};
+
+/// Number of asteroid models, see `asteroid_bounding_radius` for the list.
+pub const ASTEROID_TYPES: u8 = 21;
+
+/// The first of the large asteroid models, which are all at the end of the list.
+const LARGE_ASTEROID_TYPES_START: u8 = 17;
+
+/// Gap, in world units, kept between the bounding spheres of two asteroids.
+const MIN_SPACING: f32 = 10.0;
+
+/// How many candidates are drawn per requested asteroid before a field gives up.
+const MAX_ATTEMPTS_PER_ASTEROID: usize = 20;
+
+/// Frequency of the density noise, in cycles per world unit.
+const NOISE_FREQUENCY: f32 = 1.0 / 400.0;
+
+/// The shape asteroids of a field are scattered in.
+#[derive(Debug, Clone, Copy)]
+pub enum FieldShape {
+    /// A flat ring around `center`, in the XZ plane.
+    Belt {
+        radius: f32,
+        width: f32,
+        thickness: f32,
+    },
+    /// A dense ball of asteroids, thinning out towards its edge.
+    Cluster { radius: f32 },
+    /// Asteroids spread evenly through a sphere.
+    Sparse { radius: f32 },
+}
+
+/// Which asteroid models a field is made of.
+#[derive(Debug, Clone, Copy)]
+pub enum FieldComposition {
+    /// Small and medium asteroids only.
+    Rocks,
+    /// Any asteroid, including the large ones.
+    Mixed,
+}
+
+/// A region of space to fill with asteroids.
+#[derive(Debug, Clone, Copy)]
+pub struct AsteroidField {
+    pub center: Vec3,
+    pub shape: FieldShape,
+    pub composition: FieldComposition,
+    /// How many asteroids to place. Fewer may be placed if the field is too crowded.
+    pub count: usize,
+    pub min_scale: f32,
+    pub max_scale: f32,
+}
+
+/// An asteroid produced by `generate_asteroids`, ready to be inserted in the `asteroid` table.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeneratedAsteroid {
+    pub position: Vec3,
+    pub rotation: Quat,
+    pub asteroid_type: u8,
+    pub scale: f32,
+}
+
+/// Fills the given fields with asteroids.
+///
+/// The same seed and fields always produce the same asteroids. Asteroids are kept out of the
+/// `keep_out` spheres, and never placed closer than `MIN_SPACING` to each other, even across
+/// fields.
+pub fn generate_asteroids(
+    seed: u64,
+    fields: &[AsteroidField],
+    keep_out: &[Obstacle],
+) -> Vec<GeneratedAsteroid> {
+    let mut rng = SplitMix64::new(seed);
+    let mut asteroids: Vec<GeneratedAsteroid> = Vec::new();
+    let mut placed: Vec<Obstacle> = Vec::new();
+
+    for field in fields {
+        let mut remaining = field.count;
+        for _ in 0..field.count * MAX_ATTEMPTS_PER_ASTEROID {
+            if remaining == 0 {
+                break;
+            }
+
+            if let Some((asteroid, bounds)) = try_place(&mut rng, seed, field, keep_out, &placed) {
+                asteroids.push(asteroid);
+                placed.push(bounds);
+                remaining -= 1;
+            }
+        }
+    }
+
+    asteroids
+}
+
+/// Places a single asteroid in one of the fields, picked in proportion to their asteroid count,
+/// such as to replace a depleted one.
+///
+/// `field_seed` is the seed the fields were generated with, so the new asteroid follows the same
+/// density, while `seed` decides where it goes. `asteroids` are the bounds of the asteroids
+/// already in the world. Returns `None` if no free spot was found.
+pub fn generate_asteroid(
+    field_seed: u64,
+    seed: u64,
+    fields: &[AsteroidField],
+    keep_out: &[Obstacle],
+    asteroids: &[Obstacle],
+) -> Option<GeneratedAsteroid> {
+    let mut rng = SplitMix64::new(seed);
+    let total = fields.iter().map(|field| field.count as u64).sum::<u64>();
+    if total == 0 {
+        return None;
+    }
+
+    let mut pick = rng.next_u64() % total;
+    let field = fields.iter().find(|field| {
+        if pick < field.count as u64 {
+            return true;
+        }
+        pick -= field.count as u64;
+        false
+    })?;
+
+    (0..MAX_ATTEMPTS_PER_ASTEROID)
+        .find_map(|_| try_place(&mut rng, field_seed, field, keep_out, asteroids))
+        .map(|(asteroid, _)| asteroid)
+}
+
+/// Draws one candidate asteroid in the field, returning it along with its bounds if it landed
+/// somewhere dense enough and clear of every obstacle.
+fn try_place(
+    rng: &mut SplitMix64,
+    field_seed: u64,
+    field: &AsteroidField,
+    keep_out: &[Obstacle],
+    placed: &[Obstacle],
+) -> Option<(GeneratedAsteroid, Obstacle)> {
+    let (position, falloff) = sample_position(rng, field);
+    let density = falloff * fractal_noise(field_seed, position * NOISE_FREQUENCY);
+    if rng.next_f32() >= density {
+        return None;
+    }
+
+    let asteroid_type = pick_asteroid_type(rng, field.composition);
+    let scale = rng.range(field.min_scale, field.max_scale);
+    let radius = asteroid_bounding_radius(asteroid_type) * scale;
+
+    let blocked = keep_out
+        .iter()
+        .any(|zone| position.distance(zone.center) < zone.radius + radius)
+        || placed
+            .iter()
+            .any(|other| position.distance(other.center) < other.radius + radius + MIN_SPACING);
+    if blocked {
+        return None;
+    }
+
+    let asteroid = GeneratedAsteroid {
+        position,
+        rotation: random_rotation(rng),
+        asteroid_type,
+        scale,
+    };
+    let bounds = Obstacle {
+        center: position,
+        radius,
+    };
+    Some((asteroid, bounds))
+}
+
+/// Draws a point in the field, along with how dense the field is at that point, from 0 to 1.
+fn sample_position(rng: &mut SplitMix64, field: &AsteroidField) -> (Vec3, f32) {
+    match field.shape {
+        FieldShape::Belt {
+            radius,
+            width,
+            thickness,
+        } => {
+            let angle = rng.range(0.0, TAU);
+            let radial = rng.range(-0.5, 0.5);
+            let vertical = rng.range(-0.5, 0.5);
+            let distance = radius + radial * width;
+            let offset = Vec3::new(
+                distance * angle.cos(),
+                vertical * thickness,
+                distance * angle.sin(),
+            );
+
+            // Densest along the middle of the belt.
+            let falloff = 1.0 - (radial * 2.0).powi(2).max((vertical * 2.0).powi(2));
+            (field.center + offset, falloff)
+        }
+        FieldShape::Cluster { radius } => {
+            let offset = random_in_unit_sphere(rng);
+            let falloff = 1.0 - offset.length();
+            (field.center + offset * radius, falloff)
+        }
+        FieldShape::Sparse { radius } => {
+            let offset = random_in_unit_sphere(rng);
+            (field.center + offset * radius, 0.5)
+        }
+    }
+}
+
+/// Picks an asteroid model, large ones being rarer than the rest.
+fn pick_asteroid_type(rng: &mut SplitMix64, composition: FieldComposition) -> u8 {
+    let large = match composition {
+        FieldComposition::Rocks => false,
+        FieldComposition::Mixed => rng.next_f32() < 0.1,
+    };
+
+    if large {
+        let count = ASTEROID_TYPES - LARGE_ASTEROID_TYPES_START;
+        LARGE_ASTEROID_TYPES_START + (rng.next_u64() % count as u64) as u8
+    } else {
+        (rng.next_u64() % LARGE_ASTEROID_TYPES_START as u64) as u8
+    }
+}
+
+/// A rotation drawn uniformly from all possible orientations.
+fn random_rotation(rng: &mut SplitMix64) -> Quat {
+    // Shoemake's method for uniform random unit quaternions.
+    let u1 = rng.next_f32();
+    let u2 = rng.range(0.0, TAU);
+    let u3 = rng.range(0.0, TAU);
+    let a = (1.0 - u1).sqrt();
+    let b = u1.sqrt();
+    Quat::from_xyzw(a * u2.sin(), a * u2.cos(), b * u3.sin(), b * u3.cos()).normalize()
+}
+
+fn random_in_unit_sphere(rng: &mut SplitMix64) -> Vec3 {
+    loop {
+        let point = Vec3::new(
+            rng.range(-1.0, 1.0),
+            rng.range(-1.0, 1.0),
+            rng.range(-1.0, 1.0),
+        );
+        if point.length_squared() <= 1.0 {
+            return point;
+        }
+    }
+}
+
+/// Two octaves of value noise, from 0 to 1.
+fn fractal_noise(seed: u64, point: Vec3) -> f32 {
+    (value_noise(seed, point) * 2.0 + value_noise(seed ^ 0x5bd1_e995, point * 2.0)) / 3.0
+}
+
+/// Smoothly interpolated random values on an integer lattice, from 0 to 1.
+fn value_noise(seed: u64, point: Vec3) -> f32 {
+    let cell = point.floor();
+    let t = point - cell;
+    let t = t * t * (Vec3::splat(3.0) - 2.0 * t);
+    let (x, y, z) = (cell.x as i64, cell.y as i64, cell.z as i64);
+
+    let corner = |dx: i64, dy: i64, dz: i64| lattice_value(seed, x + dx, y + dy, z + dz);
+    let lerp = |a: f32, b: f32, t: f32| a + (b - a) * t;
+
+    let x00 = lerp(corner(0, 0, 0), corner(1, 0, 0), t.x);
+    let x10 = lerp(corner(0, 1, 0), corner(1, 1, 0), t.x);
+    let x01 = lerp(corner(0, 0, 1), corner(1, 0, 1), t.x);
+    let x11 = lerp(corner(0, 1, 1), corner(1, 1, 1), t.x);
+    lerp(lerp(x00, x10, t.y), lerp(x01, x11, t.y), t.z)
+}
+
+fn lattice_value(seed: u64, x: i64, y: i64, z: i64) -> f32 {
+    let hash = SplitMix64::new(
+        seed ^ (x as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15)
+            ^ (y as u64).wrapping_mul(0xc2b2_ae3d_27d4_eb4f)
+            ^ (z as u64).wrapping_mul(0x1656_67b1_9e37_79f9),
+    )
+    .next_u64();
+    (hash >> 40) as f32 / (1u64 << 24) as f32
+}
+
+/// A small, stable PRNG, so a seed produces the same field regardless of dependency versions.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A float in `[0, 1)`.
+    fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    fn range(&mut self, min: f32, max: f32) -> f32 {
+        min + (max - min) * self.next_f32()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields() -> [AsteroidField; 3] {
+        [
+            AsteroidField {
+                center: Vec3::ZERO,
+                shape: FieldShape::Belt {
+                    radius: 2000.0,
+                    width: 600.0,
+                    thickness: 200.0,
+                },
+                composition: FieldComposition::Mixed,
+                count: 150,
+                min_scale: 1.0,
+                max_scale: 3.0,
+            },
+            AsteroidField {
+                center: Vec3::new(-1500.0, 0.0, 500.0),
+                shape: FieldShape::Cluster { radius: 500.0 },
+                composition: FieldComposition::Rocks,
+                count: 60,
+                min_scale: 1.0,
+                max_scale: 2.0,
+            },
+            AsteroidField {
+                center: Vec3::new(1500.0, 300.0, -500.0),
+                shape: FieldShape::Sparse { radius: 800.0 },
+                composition: FieldComposition::Mixed,
+                count: 40,
+                min_scale: 1.0,
+                max_scale: 4.0,
+            },
+        ]
+    }
+
+    fn bounds(asteroid: &GeneratedAsteroid) -> Obstacle {
+        Obstacle {
+            center: asteroid.position,
+            radius: asteroid_bounding_radius(asteroid.asteroid_type) * asteroid.scale,
+        }
+    }
+
+    #[test]
+    fn same_seed_generates_the_same_asteroids() {
+        let fields = fields();
+        let asteroids = generate_asteroids(42, &fields, &[]);
+        assert!(!asteroids.is_empty());
+        assert_eq!(asteroids, generate_asteroids(42, &fields, &[]));
+        assert_ne!(asteroids, generate_asteroids(43, &fields, &[]));
+    }
+
+    #[test]
+    fn asteroids_stay_out_of_keep_out_zones() {
+        let keep_out = [
+            Obstacle {
+                center: Vec3::new(2000.0, 0.0, 0.0),
+                radius: 400.0,
+            },
+            Obstacle {
+                center: Vec3::new(-1500.0, 0.0, 500.0),
+                radius: 200.0,
+            },
+        ];
+
+        for seed in 0..10 {
+            let asteroids = generate_asteroids(seed, &fields(), &keep_out);
+            assert!(!asteroids.is_empty());
+            for asteroid in &asteroids {
+                let asteroid = bounds(asteroid);
+                for zone in &keep_out {
+                    assert!(asteroid.center.distance(zone.center) >= zone.radius + asteroid.radius);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn asteroids_keep_their_spacing() {
+        let asteroids = generate_asteroids(7, &fields(), &[]);
+        for (i, a) in asteroids.iter().enumerate() {
+            for b in &asteroids[i + 1..] {
+                let (a, b) = (bounds(a), bounds(b));
+                assert!(a.center.distance(b.center) >= a.radius + b.radius + MIN_SPACING);
+            }
+        }
+    }
+
+    #[test]
+    fn replacement_asteroids_avoid_existing_ones() {
+        let fields = fields();
+        let keep_out = [Obstacle {
+            center: Vec3::new(2000.0, 0.0, 0.0),
+            radius: 400.0,
+        }];
+        let existing = generate_asteroids(42, &fields, &keep_out)
+            .iter()
+            .map(bounds)
+            .collect::<Vec<_>>();
+
+        for seed in 0..20 {
+            let Some(asteroid) = generate_asteroid(42, seed, &fields, &keep_out, &existing) else {
+                continue;
+            };
+            let asteroid = bounds(&asteroid);
+            assert!(
+                asteroid.center.distance(keep_out[0].center)
+                    >= keep_out[0].radius + asteroid.radius
+            );
+            for other in &existing {
+                assert!(
+                    asteroid.center.distance(other.center)
+                        >= asteroid.radius + other.radius + MIN_SPACING
+                );
+            }
+        }
+    }
+}
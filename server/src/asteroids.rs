@@ -0,0 +1,205 @@
+use std::time::Duration;
+
+use glam::{Quat, Vec3};
+use log::warn;
+use simulation::ranges::STATION_BOUNDING_RADIUS;
+use spacetimedb::{
+    rand::{seq::SliceRandom, Rng},
+    reducer, table, ReducerContext, ScheduleAt, Table, TimeDuration,
+};
+use spacetimedsl::dsl;
+
+use crate::{
+    asteroid_field::{
+        generate_asteroid, generate_asteroids, AsteroidField, FieldComposition, FieldShape,
+        GeneratedAsteroid,
+    },
+    egress::{asteroid_bounding_radius, Obstacle},
+    sectors::sector_of,
+    tables::*,
+};
+
+/// Seed of the asteroid fields generated when the world is created.
+const ASTEROID_FIELD_SEED: u64 = 0x6e6f_7661_0009;
+
+/// Distance, in world units, kept clear of asteroids around the bounds of each station.
+const STATION_KEEP_OUT_MARGIN: f32 = 250.0;
+
+/// Units of ore an asteroid holds per world unit of its bounding radius.
+const ORE_PER_RADIUS: f32 = 10.0;
+
+/// The most ores a single asteroid is made of.
+const MAX_ASTEROID_RESOURCES: usize = 3;
+
+/// How long it takes for a depleted asteroid to be replaced by a new one somewhere else.
+const ASTEROID_RESPAWN_DELAY: Duration = Duration::from_secs(5 * 60);
+
+#[table(name = asteroid_respawn, scheduled(world_respawn_asteroid))]
+pub struct AsteroidRespawn {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+}
+
+/// Fills the world with the asteroid fields it's created with.
+pub fn spawn_asteroid_fields(ctx: &ReducerContext) -> Result<(), String> {
+    let asteroids = generate_asteroids(
+        ASTEROID_FIELD_SEED,
+        &asteroid_fields(),
+        &station_keep_out(ctx),
+    );
+    for asteroid in asteroids {
+        spawn_asteroid(ctx, &asteroid)?;
+    }
+
+    Ok(())
+}
+
+/// Schedules a new asteroid to replace one that was just depleted.
+pub fn schedule_asteroid_respawn(ctx: &ReducerContext) {
+    ctx.db.asteroid_respawn().insert(AsteroidRespawn {
+        scheduled_id: 0,
+        scheduled_at: (ctx.timestamp + TimeDuration::from(ASTEROID_RESPAWN_DELAY)).into(),
+    });
+}
+
+#[reducer]
+pub fn world_respawn_asteroid(
+    ctx: &ReducerContext,
+    _respawn: AsteroidRespawn,
+) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Asteroids can only be respawned by the server".to_string());
+    }
+
+    let asteroids = dsl(ctx)
+        .get_all_asteroids()
+        .map(|asteroid| asteroid_obstacle(&asteroid))
+        .collect::<Vec<_>>();
+
+    let seed = ctx.rng().gen::<u64>();
+    let Some(asteroid) = generate_asteroid(
+        ASTEROID_FIELD_SEED,
+        seed,
+        &asteroid_fields(),
+        &station_keep_out(ctx),
+        &asteroids,
+    ) else {
+        warn!("No room to respawn an asteroid, trying again later");
+        schedule_asteroid_respawn(ctx);
+        return Ok(());
+    };
+
+    spawn_asteroid(ctx, &asteroid)
+}
+
+/// The bounding sphere of an asteroid.
+pub fn asteroid_obstacle(asteroid: &Asteroid) -> Obstacle {
+    Obstacle {
+        center: Vec3::new(
+            *asteroid.get_pos_x(),
+            *asteroid.get_pos_y(),
+            *asteroid.get_pos_z(),
+        ),
+        radius: asteroid_bounding_radius(*asteroid.get_asteroid_type()) * asteroid.get_scale(),
+    }
+}
+
+/// Inserts a generated asteroid, made of a random mix of ores.
+pub fn spawn_asteroid(ctx: &ReducerContext, asteroid: &GeneratedAsteroid) -> Result<(), String> {
+    let dsl = dsl(ctx);
+
+    let position = asteroid.position;
+    let rotation: Quat = asteroid.rotation;
+    let radius = asteroid_bounding_radius(asteroid.asteroid_type) * asteroid.scale;
+    let [sector_x, sector_y, sector_z] = sector_of(position.x, position.y, position.z);
+
+    let row = dsl.create_asteroid(
+        position.x,
+        position.y,
+        position.z,
+        rotation.x,
+        rotation.y,
+        rotation.z,
+        rotation.w,
+        asteroid.asteroid_type,
+        asteroid.scale,
+        (radius * ORE_PER_RADIUS).ceil() as u32,
+        sector_x,
+        sector_y,
+        sector_z,
+    )?;
+
+    let ores = dsl
+        .get_all_item_types()
+        .filter(|item_type| *item_type.get_ore())
+        .collect::<Vec<_>>();
+    let count = ctx
+        .rng()
+        .gen_range(1..=MAX_ASTEROID_RESOURCES.min(ores.len()));
+    let resources = ores
+        .choose_multiple(&mut ctx.rng(), count)
+        .map(|ore| (ore.get_id(), ctx.rng().gen_range(0.1..1.0)))
+        .collect::<Vec<_>>();
+    let total = resources.iter().map(|(_, weight)| weight).sum::<f32>();
+    for (item_type_id, weight) in resources {
+        dsl.create_asteroid_resource(row.get_id(), item_type_id, weight / total)?;
+    }
+
+    Ok(())
+}
+
+/// Spheres around the stations that asteroids must not be placed in.
+fn station_keep_out(ctx: &ReducerContext) -> Vec<Obstacle> {
+    dsl(ctx)
+        .get_all_stations()
+        .map(|station| Obstacle {
+            center: Vec3::new(*station.get_x(), *station.get_y(), *station.get_z()),
+            radius: STATION_BOUNDING_RADIUS + STATION_KEEP_OUT_MARGIN,
+        })
+        .collect()
+}
+
+/// The asteroid fields the world is created with.
+fn asteroid_fields() -> [AsteroidField; 4] {
+    [
+        // The belt around Station Alpha
+        AsteroidField {
+            center: Vec3::ZERO,
+            shape: FieldShape::Belt {
+                radius: 1500.0,
+                width: 500.0,
+                thickness: 150.0,
+            },
+            composition: FieldComposition::Rocks,
+            count: 250,
+            min_scale: 6.0,
+            max_scale: 14.0,
+        },
+        AsteroidField {
+            center: Vec3::new(3500.0, 200.0, -2500.0),
+            shape: FieldShape::Cluster { radius: 700.0 },
+            composition: FieldComposition::Mixed,
+            count: 80,
+            min_scale: 6.0,
+            max_scale: 16.0,
+        },
+        AsteroidField {
+            center: Vec3::new(-4000.0, -300.0, 3000.0),
+            shape: FieldShape::Cluster { radius: 500.0 },
+            composition: FieldComposition::Mixed,
+            count: 50,
+            min_scale: 6.0,
+            max_scale: 16.0,
+        },
+        AsteroidField {
+            center: Vec3::ZERO,
+            shape: FieldShape::Sparse { radius: 6000.0 },
+            composition: FieldComposition::Rocks,
+            count: 200,
+            min_scale: 4.0,
+            max_scale: 10.0,
+        },
+    ]
+}
@@ -0,0 +1,199 @@
+use glam::Vec3;
+use simulation::ranges::{check_range, MAX_TRANSFER_DISTANCE};
+use spacetimedb::{reducer, ReducerContext};
+use spacetimedsl::{dsl, Wrapper};
+
+use crate::{admin::ensure_not_banned, tables::*};
+
+/// Total volume of the items in a ship's cargo hold.
+pub fn cargo_volume(ctx: &ReducerContext, ship_id: &ShipId) -> Result<f32, String> {
+    let dsl = dsl(ctx);
+
+    let mut volume = 0.0;
+    for cargo in dsl.get_cargos_by_ship_id(ship_id) {
+        let item_type = dsl.get_item_type_by_id(cargo.get_item_type_id())?;
+        volume += *item_type.get_volume() * *cargo.get_quantity() as f32;
+    }
+
+    Ok(volume)
+}
+
+/// Mass of a ship including everything in its cargo hold.
+pub fn ship_mass(
+    ctx: &ReducerContext,
+    ship_id: &ShipId,
+    ship_type: &ShipType,
+) -> Result<f32, String> {
+    let dsl = dsl(ctx);
+
+    let mut mass = *ship_type.get_mass();
+    for cargo in dsl.get_cargos_by_ship_id(ship_id) {
+        let item_type = dsl.get_item_type_by_id(cargo.get_item_type_id())?;
+        mass += *item_type.get_mass() * *cargo.get_quantity() as f32;
+    }
+
+    Ok(mass)
+}
+
+/// How many units of an item still fit in a ship's cargo hold.
+pub fn cargo_space_for(
+    ctx: &ReducerContext,
+    ship_id: &ShipId,
+    item_type_id: &ItemTypeId,
+) -> Result<u32, String> {
+    let dsl = dsl(ctx);
+
+    let ship = dsl.get_ship_by_id(ship_id)?;
+    let ship_type = dsl.get_ship_type_by_id(ship.get_ship_type_id())?;
+    let item_type = dsl.get_item_type_by_id(item_type_id)?;
+
+    let free = (*ship_type.get_cargo_capacity() - cargo_volume(ctx, ship_id)?).max(0.0);
+    if *item_type.get_volume() <= 0.0 {
+        return Ok(u32::MAX);
+    }
+
+    // Rounded with some slack so that filling a hold to the brim isn't refused by float error.
+    Ok((free / *item_type.get_volume() + 1e-4).floor() as u32)
+}
+
+/// Adds items to a ship's cargo hold, stacking them with any items of the same type already in it.
+///
+/// Fails without adding anything if the items don't all fit.
+pub fn add_cargo(
+    ctx: &ReducerContext,
+    ship_id: &ShipId,
+    item_type_id: &ItemTypeId,
+    quantity: u32,
+) -> Result<(), String> {
+    let dsl = dsl(ctx);
+
+    if quantity > cargo_space_for(ctx, ship_id, item_type_id)? {
+        return Err("Not enough room in the cargo hold".into());
+    }
+
+    let stack = dsl
+        .get_cargos_by_ship_id(ship_id)
+        .find(|cargo| cargo.get_item_type_id() == *item_type_id);
+    if let Some(mut cargo) = stack {
+        cargo.set_quantity(cargo.get_quantity() + quantity);
+        dsl.update_cargo_by_id(cargo)?;
+    } else {
+        dsl.create_cargo(ship_id, item_type_id, quantity)?;
+    }
+
+    Ok(())
+}
+
+/// Removes items from a ship's cargo hold, deleting the stack once it's empty.
+///
+/// Fails without removing anything if the ship doesn't carry that many.
+pub fn remove_cargo(
+    ctx: &ReducerContext,
+    ship_id: &ShipId,
+    item_type_id: &ItemTypeId,
+    quantity: u32,
+) -> Result<(), String> {
+    let dsl = dsl(ctx);
+
+    let Some(mut cargo) = dsl
+        .get_cargos_by_ship_id(ship_id)
+        .find(|cargo| cargo.get_item_type_id() == *item_type_id)
+    else {
+        return Err("The cargo hold has none of this item".into());
+    };
+
+    let available = *cargo.get_quantity();
+    if quantity > available {
+        return Err(format!("The cargo hold only has {available} of this item"));
+    }
+
+    if quantity == available {
+        dsl.delete_cargo_by_id(cargo.get_id())?;
+    } else {
+        cargo.set_quantity(available - quantity);
+        dsl.update_cargo_by_id(cargo)?;
+    }
+
+    Ok(())
+}
+
+/// Dumps items out of the cargo hold of the ship the player is piloting. They are lost.
+#[reducer]
+pub fn jettison_cargo(
+    ctx: &ReducerContext,
+    item_type_id: u64,
+    quantity: u32,
+) -> Result<(), String> {
+    ensure_not_banned(ctx)?;
+    let dsl = dsl(ctx);
+
+    let Ok(ship_pilot) = dsl.get_ship_pilot_by_player_id(&PlayerId::new(ctx.sender)) else {
+        return Err("Player is not piloting a ship".into());
+    };
+
+    if quantity == 0 {
+        return Err("Nothing to jettison".into());
+    }
+
+    remove_cargo(
+        ctx,
+        &ship_pilot.get_ship_id(),
+        &ItemTypeId::new(item_type_id),
+        quantity,
+    )
+}
+
+/// Moves items from the cargo hold of the ship the player is piloting into another ship nearby.
+///
+/// The target ship doesn't need to belong to the player, so this can be used to hand cargo over
+/// to someone else.
+#[reducer]
+pub fn transfer_cargo(
+    ctx: &ReducerContext,
+    target_ship_id: u64,
+    item_type_id: u64,
+    quantity: u32,
+) -> Result<(), String> {
+    ensure_not_banned(ctx)?;
+    let dsl = dsl(ctx);
+
+    let Ok(ship_pilot) = dsl.get_ship_pilot_by_player_id(&PlayerId::new(ctx.sender)) else {
+        return Err("Player is not piloting a ship".into());
+    };
+    let ship_id = ship_pilot.get_ship_id();
+    let target_ship_id = ShipId::new(target_ship_id);
+    let item_type_id = ItemTypeId::new(item_type_id);
+
+    if quantity == 0 {
+        return Err("Nothing to transfer".into());
+    }
+    if target_ship_id == ship_id {
+        return Err("Cannot transfer cargo to the same ship".into());
+    }
+
+    let ship_location = dsl.get_ship_location_by_ship_id(&ship_id)?;
+    let Ok(target_location) = dsl.get_ship_location_by_ship_id(&target_ship_id) else {
+        return Err("Target ship not found".into());
+    };
+
+    if let Err(distance) = check_range(
+        Vec3::new(
+            *ship_location.get_x(),
+            *ship_location.get_y(),
+            *ship_location.get_z(),
+        ),
+        Vec3::new(
+            *target_location.get_x(),
+            *target_location.get_y(),
+            *target_location.get_z(),
+        ),
+        MAX_TRANSFER_DISTANCE,
+    ) {
+        return Err(format!(
+            "Target ship is too far away ({distance:.0}m, max {MAX_TRANSFER_DISTANCE:.0}m)"
+        ));
+    }
+
+    remove_cargo(ctx, &ship_id, &item_type_id, quantity)?;
+    add_cargo(ctx, &target_ship_id, &item_type_id, quantity)
+}
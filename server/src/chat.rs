@@ -0,0 +1,205 @@
+use std::time::Duration;
+
+use glam::Vec3;
+use simulation::ranges::check_range;
+use spacetimedb::{reducer, table, Identity, ReducerContext, ScheduleAt, TimeDuration};
+use spacetimedsl::{dsl, Wrapper};
+
+use crate::{
+    admin::{ensure_not_banned, in_effect},
+    docking::docked_ship_transform,
+    tables::*,
+};
+
+/// How far from the sender, in world units, local messages are heard.
+pub const LOCAL_CHAT_RADIUS: f32 = 1000.0;
+
+/// Longest message players can send, in characters.
+const MAX_MESSAGE_LENGTH: usize = 256;
+
+/// Most messages a player can send within `MESSAGE_RATE_WINDOW`.
+const MAX_MESSAGES_PER_WINDOW: usize = 5;
+const MESSAGE_RATE_WINDOW: Duration = Duration::from_secs(10);
+
+/// How often messages that have been around for too long are cleared.
+pub const CHAT_CLEANUP_INTERVAL: Duration = Duration::from_secs(60);
+
+/// How long messages are kept, for players to scroll back through.
+const CHAT_HISTORY: Duration = Duration::from_secs(30 * 60);
+
+#[table(name = chat_cleanup, scheduled(world_clear_chat))]
+pub struct ChatCleanup {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+}
+
+impl ChatCleanup {
+    pub fn new(scheduled_at: ScheduleAt) -> Self {
+        Self {
+            scheduled_id: 0,
+            scheduled_at,
+        }
+    }
+}
+
+/// Where a player is in the world: in the ship they're piloting, at the station it's docked at,
+/// or on foot.
+fn player_position(ctx: &ReducerContext, player_id: &PlayerId) -> Option<Vec3> {
+    let dsl = dsl(ctx);
+
+    if let Ok(ship_pilot) = dsl.get_ship_pilot_by_player_id(player_id) {
+        let ship_id = ship_pilot.get_ship_id();
+        if let Ok(location) = dsl.get_ship_location_by_ship_id(&ship_id) {
+            return Some(Vec3::new(
+                *location.get_x(),
+                *location.get_y(),
+                *location.get_z(),
+            ));
+        }
+        return docked_ship_transform(ctx, &ship_id).map(|(position, _)| position);
+    }
+
+    dsl.get_player_location_by_player_id(player_id)
+        .ok()
+        .map(|location| Vec3::new(*location.get_x(), *location.get_y(), *location.get_z()))
+}
+
+/// Trims a message, which must not be empty nor too long.
+fn validate_text(text: &str) -> Result<String, String> {
+    let text = text.trim().to_string();
+    if text.is_empty() {
+        return Err("Message is empty".into());
+    }
+    if text.chars().count() > MAX_MESSAGE_LENGTH {
+        return Err(format!(
+            "Message is too long (max {MAX_MESSAGE_LENGTH} characters)"
+        ));
+    }
+
+    Ok(text)
+}
+
+/// Delivers an announcement from the calling admin to every player online.
+pub fn announce(ctx: &ReducerContext, text: &str) -> Result<(), String> {
+    let dsl = dsl(ctx);
+
+    let text = validate_text(text)?;
+    for player in dsl.get_all_players().filter(|player| *player.get_online()) {
+        dsl.create_chat_message(
+            player.get_id(),
+            ctx.sender,
+            ChatChannel::Announcement,
+            None,
+            &text,
+            ctx.timestamp,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Sends a chat message on a channel. Private messages need a `recipient_id`.
+#[reducer]
+pub fn send_message(
+    ctx: &ReducerContext,
+    channel: ChatChannel,
+    recipient_id: Option<Identity>,
+    text: String,
+) -> Result<(), String> {
+    ensure_not_banned(ctx)?;
+    let dsl = dsl(ctx);
+
+    let sender_id = PlayerId::new(ctx.sender);
+    let text = validate_text(&text)?;
+    if dsl
+        .get_mute_by_player_id(&sender_id)
+        .is_ok_and(|mute| in_effect(*mute.get_expires_at(), ctx.timestamp))
+    {
+        return Err("You are muted".into());
+    }
+
+    // Senders always get a copy of their own messages, which tells how many they sent lately.
+    let window_start = ctx.timestamp - TimeDuration::from(MESSAGE_RATE_WINDOW);
+    let recent = dsl
+        .get_chat_messages_by_recipient_id(&sender_id)
+        .filter(|message| *message.get_sender_id() == ctx.sender)
+        .filter(|message| *message.get_sent_at() > window_start)
+        .count();
+    if recent >= MAX_MESSAGES_PER_WINDOW {
+        return Err("You are sending messages too fast".into());
+    }
+
+    let recipients = match channel {
+        ChatChannel::Global => dsl
+            .get_all_players()
+            .filter(|player| *player.get_online() || player.get_id() == sender_id)
+            .map(|player| player.get_id())
+            .collect::<Vec<_>>(),
+        ChatChannel::Local => {
+            let Some(origin) = player_position(ctx, &sender_id) else {
+                return Err("Player is nowhere to be heard".into());
+            };
+            dsl.get_all_players()
+                .filter(|player| *player.get_online())
+                .map(|player| player.get_id())
+                .filter(|player_id| {
+                    player_position(ctx, player_id).is_some_and(|position| {
+                        check_range(position, origin, LOCAL_CHAT_RADIUS).is_ok()
+                    })
+                })
+                .collect()
+        }
+        ChatChannel::Announcement => {
+            return Err("Only admins can make announcements".into());
+        }
+        ChatChannel::Private => {
+            let Some(recipient_id) = recipient_id else {
+                return Err("Private messages need a recipient".into());
+            };
+            if recipient_id == ctx.sender {
+                return Err("Cannot send a private message to yourself".into());
+            }
+            let Ok(recipient) = dsl.get_player_by_id(&PlayerId::new(recipient_id)) else {
+                return Err("Recipient not found".into());
+            };
+            vec![recipient.get_id(), sender_id]
+        }
+    };
+
+    let private_to = match channel {
+        ChatChannel::Private => recipient_id,
+        _ => None,
+    };
+    for recipient in recipients {
+        dsl.create_chat_message(
+            &recipient,
+            ctx.sender,
+            channel,
+            private_to,
+            &text,
+            ctx.timestamp,
+        )?;
+    }
+
+    Ok(())
+}
+
+#[reducer]
+pub fn world_clear_chat(ctx: &ReducerContext, _cleanup: ChatCleanup) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Chat history can only be cleared by the server".to_string());
+    }
+
+    let dsl = dsl(ctx);
+
+    let cutoff = ctx.timestamp - TimeDuration::from(CHAT_HISTORY);
+    for message in dsl.get_all_chat_messages() {
+        if *message.get_sent_at() < cutoff {
+            dsl.delete_chat_message_by_id(message.get_id())?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,136 @@
+use simulation::damage::{absorb_damage, subsystem_wear};
+use spacetimedb::{rand::Rng, reducer, ReducerContext, Timestamp};
+use spacetimedsl::{dsl, Wrapper};
+
+use crate::{admin::ensure_not_banned, destruction::destroy_ship, tables::*};
+
+/// Shield points a ship has at `now`, having regenerated since it was last hit.
+pub fn current_shield(status: &ShipStatus, ship_type: &ShipType, now: Timestamp) -> f32 {
+    let since_hit = now
+        .duration_since(*status.get_damaged_at())
+        .unwrap_or_default()
+        .as_secs_f32();
+    simulation::damage::current_shield(
+        *status.get_shield(),
+        since_hit,
+        *ship_type.get_max_shield(),
+        *ship_type.get_shield_recharge(),
+        *ship_type.get_shield_delay(),
+    )
+}
+
+/// Fraction of the damage of the given type the armor of ships of this type stops.
+pub fn armor_resistance(ship_type: &ShipType, damage_type: DamageType) -> f32 {
+    match damage_type {
+        DamageType::Energy => *ship_type.get_armor_energy(),
+        DamageType::Kinetic => *ship_type.get_armor_kinetic(),
+        DamageType::Explosive => *ship_type.get_armor_explosive(),
+    }
+}
+
+/// Damages a ship, through its shield and armor, destroying it when its hull is gone.
+///
+/// Whatever damage reaches the hull also wears down one of the ship's subsystems.
+pub fn damage_ship(
+    ctx: &ReducerContext,
+    ship_id: &ShipId,
+    damage: f32,
+    damage_type: DamageType,
+) -> Result<(), String> {
+    let dsl = dsl(ctx);
+
+    let ship = dsl.get_ship_by_id(ship_id)?;
+    let ship_type = dsl.get_ship_type_by_id(ship.get_ship_type_id())?;
+    let mut status = dsl.get_ship_status_by_ship_id(ship_id)?;
+
+    let shield = current_shield(&status, &ship_type, ctx.timestamp);
+    let (shield, hull_damage) =
+        absorb_damage(shield, damage, armor_resistance(&ship_type, damage_type));
+    let hull = (status.get_hull() - hull_damage).max(0.0);
+    status.set_shield(shield);
+    status.set_damaged_at(ctx.timestamp);
+    status.set_hull(hull);
+
+    if hull_damage > 0.0 {
+        let wear = subsystem_wear(hull_damage, *ship_type.get_max_hull());
+        match ctx.rng().gen_range(0..4) {
+            0 => {
+                status.set_engines((status.get_engines() - wear).max(0.0));
+            }
+            1 => {
+                status.set_thrusters((status.get_thrusters() - wear).max(0.0));
+            }
+            2 => {
+                status.set_weapons((status.get_weapons() - wear).max(0.0));
+            }
+            _ => {
+                status.set_sensors((status.get_sensors() - wear).max(0.0));
+            }
+        }
+    }
+    dsl.update_ship_status_by_ship_id(status)?;
+
+    if hull <= 0.0 {
+        destroy_ship(ctx, ship_id)?;
+    }
+
+    Ok(())
+}
+
+/// Credits it costs to repair a ship's hull and subsystems.
+pub fn repair_cost(status: &ShipStatus, ship_type: &ShipType) -> u64 {
+    simulation::damage::repair_cost(
+        *status.get_hull(),
+        *ship_type.get_max_hull(),
+        [
+            *status.get_engines(),
+            *status.get_thrusters(),
+            *status.get_weapons(),
+            *status.get_sensors(),
+        ],
+    )
+}
+
+/// Repairs the ship the player is piloting at the station it's docked at, restoring its hull,
+/// subsystems and shield for credits.
+#[reducer]
+pub fn repair_ship(ctx: &ReducerContext) -> Result<(), String> {
+    ensure_not_banned(ctx)?;
+    let dsl = dsl(ctx);
+
+    let player_id = PlayerId::new(ctx.sender);
+    let Ok(ship_pilot) = dsl.get_ship_pilot_by_player_id(&player_id) else {
+        return Err("Player is not piloting a ship".into());
+    };
+    let ship_id = ship_pilot.get_ship_id();
+    if dsl.get_ship_docking_by_ship_id(&ship_id).is_err() {
+        return Err("Ship must be docked at a station to be repaired".into());
+    }
+
+    let ship = dsl.get_ship_by_id(&ship_id)?;
+    let ship_type = dsl.get_ship_type_by_id(ship.get_ship_type_id())?;
+    let mut status = dsl.get_ship_status_by_ship_id(&ship_id)?;
+    let mut wallet = dsl.get_wallet_by_player_id(&player_id)?;
+
+    let cost = repair_cost(&status, &ship_type);
+    let credits = *wallet.get_credits();
+    if cost > credits {
+        return Err(format!(
+            "Not enough credits ({cost} needed, {credits} available)"
+        ));
+    }
+
+    wallet.set_credits(credits - cost);
+    dsl.update_wallet_by_player_id(wallet)?;
+
+    status.set_hull(*ship_type.get_max_hull());
+    status.set_shield(*ship_type.get_max_shield());
+    status.set_damaged_at(ctx.timestamp);
+    status.set_engines(1.0);
+    status.set_thrusters(1.0);
+    status.set_weapons(1.0);
+    status.set_sensors(1.0);
+    dsl.update_ship_status_by_ship_id(status)?;
+
+    Ok(())
+}
@@ -0,0 +1,141 @@
+use std::{f32::consts::TAU, time::Duration};
+
+use glam::{EulerRot, Quat, Vec3};
+use spacetimedb::{rand::Rng, reducer, table, ReducerContext, ScheduleAt, TimeDuration};
+use spacetimedsl::{dsl, Wrapper};
+
+use crate::{respawn::eject_pilot, sectors::sector_of, tables::*};
+
+/// How often wrecks that have been around for too long are cleared.
+pub const WRECK_CLEANUP_INTERVAL: Duration = Duration::from_secs(60);
+
+/// How long the debris of a destroyed ship stays in the world.
+const WRECK_LIFETIME: Duration = Duration::from_secs(10 * 60);
+
+/// Number of debris models, see `Wreck::debris_type`.
+const DEBRIS_TYPES: u8 = 11;
+
+/// Bounds of the number of pieces of debris a ship leaves, bigger ships leaving more.
+const MIN_DEBRIS: usize = 3;
+const MAX_DEBRIS: usize = 16;
+
+/// Pieces of debris per world unit of the ship's radius.
+const DEBRIS_PER_RADIUS: f32 = 0.25;
+
+#[table(name = wreck_cleanup, scheduled(world_clear_wrecks))]
+pub struct WreckCleanup {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+}
+
+impl WreckCleanup {
+    pub fn new(scheduled_at: ScheduleAt) -> Self {
+        Self {
+            scheduled_id: 0,
+            scheduled_at,
+        }
+    }
+}
+
+/// Destroys a ship in flight, leaving a wreck where it was.
+///
+/// Everything aboard goes down with the ship, its cargo included. Its pilot, if it had one,
+/// ejects in an escape pod.
+pub fn destroy_ship(ctx: &ReducerContext, ship_id: &ShipId) -> Result<(), String> {
+    let dsl = dsl(ctx);
+
+    let ship = dsl.get_ship_by_id(ship_id)?;
+    let ship_type = dsl.get_ship_type_by_id(ship.get_ship_type_id())?;
+    let ship_location = dsl.get_ship_location_by_ship_id(ship_id)?;
+    let position = Vec3::new(
+        *ship_location.get_x(),
+        *ship_location.get_y(),
+        *ship_location.get_z(),
+    );
+    let rotation = Quat::from_xyzw(
+        *ship_location.get_rot_x(),
+        *ship_location.get_rot_y(),
+        *ship_location.get_rot_z(),
+        *ship_location.get_rot_w(),
+    )
+    .normalize();
+    let pilot_id = dsl
+        .get_ship_pilot_by_ship_id(ship_id)
+        .ok()
+        .map(|ship_pilot| ship_pilot.get_player_id());
+
+    spawn_wreck(ctx, ship_id, position, *ship_type.get_radius())?;
+    dsl.delete_ship_by_id(ship_id)?;
+
+    if let Some(pilot_id) = pilot_id {
+        eject_pilot(ctx, &pilot_id, &ship_type, position, rotation)?;
+    }
+
+    Ok(())
+}
+
+/// Scatters debris around where a ship was destroyed.
+fn spawn_wreck(
+    ctx: &ReducerContext,
+    ship_id: &ShipId,
+    center: Vec3,
+    radius: f32,
+) -> Result<(), String> {
+    let dsl = dsl(ctx);
+
+    let pieces = ((radius * DEBRIS_PER_RADIUS) as usize).clamp(MIN_DEBRIS, MAX_DEBRIS);
+    for _ in 0..pieces {
+        let position = center
+            + Vec3::new(
+                ctx.rng().gen_range(-1.0..1.0),
+                ctx.rng().gen_range(-1.0..1.0),
+                ctx.rng().gen_range(-1.0..1.0),
+            ) * radius;
+        let rotation = Quat::from_euler(
+            EulerRot::XYZ,
+            ctx.rng().gen_range(0.0..TAU),
+            ctx.rng().gen_range(0.0..TAU),
+            ctx.rng().gen_range(0.0..TAU),
+        );
+        let scale = (radius / 8.0).max(0.5) * ctx.rng().gen_range(0.6..1.2);
+
+        let [sector_x, sector_y, sector_z] = sector_of(position.x, position.y, position.z);
+        dsl.create_wreck(
+            ship_id.value(),
+            position.x,
+            position.y,
+            position.z,
+            rotation.x,
+            rotation.y,
+            rotation.z,
+            rotation.w,
+            ctx.rng().gen_range(0..DEBRIS_TYPES),
+            scale,
+            sector_x,
+            sector_y,
+            sector_z,
+        )?;
+    }
+
+    Ok(())
+}
+
+#[reducer]
+pub fn world_clear_wrecks(ctx: &ReducerContext, _cleanup: WreckCleanup) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Wrecks can only be cleared by the server".to_string());
+    }
+
+    let dsl = dsl(ctx);
+
+    let cutoff = ctx.timestamp - TimeDuration::from(WRECK_LIFETIME);
+    for wreck in dsl.get_all_wrecks() {
+        if *wreck.get_created_at() < cutoff {
+            dsl.delete_wreck_by_id(wreck.get_id())?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,190 @@
+use std::f32::consts::{PI, TAU};
+
+use glam::{Quat, Vec3};
+use simulation::ranges::{check_range, MAX_DOCKING_DISTANCE, STATION_BOUNDING_RADIUS};
+use spacetimedb::{reducer, ReducerContext};
+use spacetimedsl::{dsl, Wrapper};
+
+use crate::{
+    admin::ensure_not_banned, flight::stop_ship_flight, sectors::sector_of, tables::*,
+    world::station_orientation,
+};
+
+/// Number of docking bays around each station.
+const STATION_DOCKING_BAYS: usize = 6;
+
+/// Distance of the docking bays from the station's axis, just clear of its hull.
+const DOCKING_BAY_RADIUS: f32 = STATION_BOUNDING_RADIUS + 30.0;
+
+/// Creates the docking bays of a new station, evenly spread around its axis with docked ships
+/// facing away from it, so they can fly straight out when undocking.
+pub fn create_docking_bays(ctx: &ReducerContext, station_id: &StationId) -> Result<(), String> {
+    let dsl = dsl(ctx);
+
+    for i in 0..STATION_DOCKING_BAYS {
+        let angle = TAU * i as f32 / STATION_DOCKING_BAYS as f32;
+        dsl.create_docking_bay(
+            station_id,
+            DOCKING_BAY_RADIUS * angle.sin(),
+            0.0,
+            DOCKING_BAY_RADIUS * angle.cos(),
+            angle + PI,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Where a docking bay is in the world, and how ships docked in it are oriented, with the
+/// station oriented by `station_rotation`.
+pub fn docking_bay_transform(
+    station: &Station,
+    bay: &DockingBay,
+    station_rotation: Quat,
+) -> (Vec3, Quat) {
+    let position = Vec3::new(*station.get_x(), *station.get_y(), *station.get_z())
+        + station_rotation
+            * Vec3::new(
+                *bay.get_offset_x(),
+                *bay.get_offset_y(),
+                *bay.get_offset_z(),
+            );
+    let rotation = station_rotation * Quat::from_rotation_y(*bay.get_heading());
+
+    (position, rotation)
+}
+
+/// Where a docked ship currently is in the world, or `None` if the ship isn't docked.
+pub fn docked_ship_transform(ctx: &ReducerContext, ship_id: &ShipId) -> Option<(Vec3, Quat)> {
+    let dsl = dsl(ctx);
+
+    let docking = dsl.get_ship_docking_by_ship_id(ship_id).ok()?;
+    let bay = dsl
+        .get_docking_bay_by_id(docking.get_docking_bay_id())
+        .ok()?;
+    let station = dsl.get_station_by_id(docking.get_station_id()).ok()?;
+
+    Some(docking_bay_transform(
+        &station,
+        &bay,
+        station_orientation(ctx, &station, ctx.timestamp),
+    ))
+}
+
+/// Docks the ship the player is piloting at the nearest free bay of a station.
+///
+/// The ship leaves free flight: its location is removed and clients attach it to the bay.
+#[reducer]
+pub fn request_docking(ctx: &ReducerContext, station_id: u64) -> Result<(), String> {
+    ensure_not_banned(ctx)?;
+    let dsl = dsl(ctx);
+
+    let Ok(ship_pilot) = dsl.get_ship_pilot_by_player_id(&PlayerId::new(ctx.sender)) else {
+        return Err("Player is not piloting a ship".into());
+    };
+    let ship_id = ship_pilot.get_ship_id();
+
+    if dsl.get_ship_docking_by_ship_id(&ship_id).is_ok() {
+        return Err("Ship is already docked".into());
+    }
+
+    let Ok(station) = dsl.get_station_by_id(StationId::new(station_id)) else {
+        return Err("Station not found".into());
+    };
+
+    let ship_location = dsl.get_ship_location_by_ship_id(&ship_id)?;
+    let ship_position = Vec3::new(
+        *ship_location.get_x(),
+        *ship_location.get_y(),
+        *ship_location.get_z(),
+    );
+    if let Err(distance) = check_range(
+        ship_position,
+        Vec3::new(*station.get_x(), *station.get_y(), *station.get_z()),
+        MAX_DOCKING_DISTANCE,
+    ) {
+        return Err(format!(
+            "Station is too far away to dock ({distance:.0}m, max {MAX_DOCKING_DISTANCE:.0}m)"
+        ));
+    }
+
+    let orientation = station_orientation(ctx, &station, ctx.timestamp);
+    let Some(bay) = dsl
+        .get_docking_bays_by_station_id(station.get_id())
+        .filter(|bay| {
+            dsl.get_ship_docking_by_docking_bay_id(bay.get_id())
+                .is_err()
+        })
+        .min_by(|a, b| {
+            let distance_a = docking_bay_transform(&station, a, orientation)
+                .0
+                .distance(ship_position);
+            let distance_b = docking_bay_transform(&station, b, orientation)
+                .0
+                .distance(ship_position);
+            distance_a.total_cmp(&distance_b)
+        })
+    else {
+        return Err("No docking bay available".into());
+    };
+
+    dsl.create_ship_docking(
+        &ship_id,
+        bay.get_id(),
+        station.get_id(),
+        *station.get_sector_x(),
+        *station.get_sector_y(),
+        *station.get_sector_z(),
+        ctx.timestamp,
+    )?;
+    dsl.delete_ship_location_by_ship_id(&ship_id)?;
+
+    Ok(())
+}
+
+/// Releases the ship the player is piloting from its docking bay, back into free flight from
+/// the bay's current position.
+///
+/// The server doesn't track velocities: clients give undocking ships the station's tangential
+/// velocity at the bay.
+#[reducer]
+pub fn undock(ctx: &ReducerContext) -> Result<(), String> {
+    ensure_not_banned(ctx)?;
+    let dsl = dsl(ctx);
+
+    let Ok(ship_pilot) = dsl.get_ship_pilot_by_player_id(&PlayerId::new(ctx.sender)) else {
+        return Err("Player is not piloting a ship".into());
+    };
+
+    release_ship(ctx, &ship_pilot.get_ship_id())
+}
+
+/// Takes a ship out of its docking bay, leaving it at rest in free flight where the bay currently
+/// is.
+pub fn release_ship(ctx: &ReducerContext, ship_id: &ShipId) -> Result<(), String> {
+    let dsl = dsl(ctx);
+
+    let Some((position, rotation)) = docked_ship_transform(ctx, ship_id) else {
+        return Err("Ship is not docked".into());
+    };
+
+    dsl.delete_ship_docking_by_ship_id(ship_id)?;
+    let [sector_x, sector_y, sector_z] = sector_of(position.x, position.y, position.z);
+    dsl.create_ship_location(
+        ship_id,
+        position.x,
+        position.y,
+        position.z,
+        rotation.x,
+        rotation.y,
+        rotation.z,
+        rotation.w,
+        sector_x,
+        sector_y,
+        sector_z,
+        ctx.timestamp,
+    )?;
+    stop_ship_flight(ctx, ship_id)?;
+
+    Ok(())
+}
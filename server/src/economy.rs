@@ -0,0 +1,251 @@
+use std::time::Duration;
+
+use spacetimedb::{reducer, table, ReducerContext, ScheduleAt, TimeDuration};
+use spacetimedsl::dsl;
+
+use crate::{market::market_prices, tables::*};
+
+/// How often station markets produce, consume and reprice their commodities.
+pub const MARKET_UPDATE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// How long the state of markets is kept in `market_price_history`.
+const PRICE_HISTORY_RETENTION: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Most units of a commodity a station stores, production stops once it's reached.
+const MAX_STOCK: u32 = 10_000;
+
+/// How strongly prices react to the ratio between demand and stock.
+const PRICE_ELASTICITY: f32 = 0.5;
+
+/// Bounds of the price relative to the item's base price, however scarce or plentiful it gets.
+const MIN_PRICE_FACTOR: f32 = 0.25;
+const MAX_PRICE_FACTOR: f32 = 4.0;
+
+/// Fraction of the gap to its equilibrium the price closes on each tick, so prices drift
+/// rather than jump when a player dumps cargo on a market.
+const PRICE_DRIFT: f32 = 0.1;
+
+#[table(name = market_update, scheduled(world_update_markets))]
+pub struct MarketUpdate {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+}
+
+impl MarketUpdate {
+    pub fn new(scheduled_at: ScheduleAt) -> Self {
+        Self {
+            scheduled_id: 0,
+            scheduled_at,
+        }
+    }
+}
+
+/// The part of a market that changes from tick to tick.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MarketState {
+    pub stock: u32,
+    pub demand: u32,
+    pub price: f32,
+}
+
+/// The part of a market that drives its simulation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MarketParameters {
+    pub base_price: u64,
+    pub production: u32,
+    pub consumption: u32,
+}
+
+/// Simulates one economy tick of a market.
+///
+/// The station produces and consumes the commodity, then its price drifts towards the
+/// equilibrium set by the ratio between demand and the remaining stock.
+pub fn step_market(state: MarketState, parameters: MarketParameters) -> MarketState {
+    // Players can sell past the storage limit, production just stops until stock goes down.
+    let produced = if state.stock < MAX_STOCK {
+        (state.stock + parameters.production).min(MAX_STOCK)
+    } else {
+        state.stock
+    };
+    let stock = produced.saturating_sub(parameters.consumption);
+
+    let base_price = parameters.base_price as f32;
+    let scarcity = state.demand.max(1) as f32 / stock.max(1) as f32;
+    let equilibrium = base_price
+        * scarcity
+            .powf(PRICE_ELASTICITY)
+            .clamp(MIN_PRICE_FACTOR, MAX_PRICE_FACTOR);
+    let price = state.price + (equilibrium - state.price) * PRICE_DRIFT;
+
+    MarketState {
+        stock,
+        demand: state.demand,
+        price,
+    }
+}
+
+#[reducer]
+pub fn world_update_markets(ctx: &ReducerContext, _update: MarketUpdate) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Markets can only be updated by the server".to_string());
+    }
+
+    let dsl = dsl(ctx);
+
+    let retention_cutoff = ctx.timestamp - TimeDuration::from(PRICE_HISTORY_RETENTION);
+
+    for mut market in dsl.get_all_markets() {
+        let market_id = market.get_id();
+        let item_type = dsl.get_item_type_by_id(market.get_item_type_id())?;
+
+        let state = step_market(
+            MarketState {
+                stock: *market.get_stock(),
+                demand: *market.get_demand(),
+                price: *market.get_price(),
+            },
+            MarketParameters {
+                base_price: *item_type.get_base_price(),
+                production: *market.get_production(),
+                consumption: *market.get_consumption(),
+            },
+        );
+        let (buy_price, sell_price) = market_prices(state.price);
+
+        market.set_stock(state.stock);
+        market.set_demand(state.demand);
+        market.set_price(state.price);
+        market.set_buy_price(buy_price);
+        market.set_sell_price(sell_price);
+        dsl.update_market_by_id(market)?;
+
+        dsl.create_market_price_history(
+            &market_id,
+            ctx.timestamp,
+            state.price,
+            state.stock,
+            state.demand,
+        )?;
+
+        for entry in dsl.get_market_price_histories_by_market_id(&market_id) {
+            if *entry.get_recorded_at() < retention_cutoff {
+                dsl.delete_market_price_history_by_id(entry.get_id())?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARAMETERS: MarketParameters = MarketParameters {
+        base_price: 100,
+        production: 0,
+        consumption: 0,
+    };
+
+    fn state(stock: u32, demand: u32) -> MarketState {
+        MarketState {
+            stock,
+            demand,
+            price: 100.0,
+        }
+    }
+
+    #[test]
+    fn price_rises_when_demand_outgrows_stock() {
+        let next = step_market(state(100, 400), PARAMETERS);
+        assert!(next.price > 100.0);
+        assert_eq!(
+            next,
+            MarketState {
+                price: next.price,
+                ..state(100, 400)
+            }
+        );
+    }
+
+    #[test]
+    fn price_falls_when_stock_outgrows_demand() {
+        assert!(step_market(state(400, 100), PARAMETERS).price < 100.0);
+    }
+
+    #[test]
+    fn price_holds_when_stock_meets_demand() {
+        assert_eq!(step_market(state(250, 250), PARAMETERS).price, 100.0);
+    }
+
+    #[test]
+    fn price_drifts_towards_its_equilibrium() {
+        let mut market = state(100, 400);
+        let mut previous = market.price;
+        for _ in 0..200 {
+            market = step_market(market, PARAMETERS);
+            assert!(market.price >= previous);
+            previous = market.price;
+        }
+        // Demand is four times the stock, so the price settles at twice the base price.
+        assert!((market.price - 200.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn price_stays_within_its_bounds() {
+        let mut scarce = state(0, 1_000_000);
+        let mut plentiful = state(MAX_STOCK, 0);
+        for _ in 0..500 {
+            scarce = step_market(scarce, PARAMETERS);
+            plentiful = step_market(plentiful, PARAMETERS);
+        }
+        assert!(scarce.price <= 100.0 * MAX_PRICE_FACTOR);
+        assert!((scarce.price - 100.0 * MAX_PRICE_FACTOR).abs() < 0.01);
+        assert!(plentiful.price >= 100.0 * MIN_PRICE_FACTOR);
+        assert!((plentiful.price - 100.0 * MIN_PRICE_FACTOR).abs() < 0.01);
+    }
+
+    #[test]
+    fn production_stops_at_max_stock() {
+        let parameters = MarketParameters {
+            production: 50,
+            ..PARAMETERS
+        };
+        assert_eq!(step_market(state(100, 100), parameters).stock, 150);
+        assert_eq!(
+            step_market(state(MAX_STOCK - 10, 100), parameters).stock,
+            MAX_STOCK
+        );
+        // Stock sold past the limit is kept, just not added to.
+        assert_eq!(
+            step_market(state(MAX_STOCK + 10, 100), parameters).stock,
+            MAX_STOCK + 10
+        );
+    }
+
+    #[test]
+    fn consumption_never_takes_stock_below_zero() {
+        let parameters = MarketParameters {
+            consumption: 50,
+            ..PARAMETERS
+        };
+        assert_eq!(step_market(state(100, 100), parameters).stock, 50);
+        assert_eq!(step_market(state(20, 100), parameters).stock, 0);
+    }
+
+    #[test]
+    fn production_happens_before_consumption() {
+        let parameters = MarketParameters {
+            production: 30,
+            consumption: 50,
+            ..PARAMETERS
+        };
+        assert_eq!(step_market(state(20, 100), parameters).stock, 0);
+        assert_eq!(
+            step_market(state(MAX_STOCK, 100), parameters).stock,
+            MAX_STOCK - 50
+        );
+    }
+}
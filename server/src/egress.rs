@@ -0,0 +1,117 @@
+use std::f32::consts::{FRAC_PI_2, PI};
+
+use glam::{Mat3, Quat, Vec3};
+
+/// Clearance, in world units, kept between an egress point and any obstacle.
+const OBSTACLE_CLEARANCE: f32 = 5.0;
+
+/// Multipliers applied to the ship's exit offset when the previous distance was obstructed.
+const EXIT_DISTANCE_MULTIPLIERS: [f32; 3] = [1.0, 2.0, 4.0];
+
+/// A bounding sphere the player must not be placed in when leaving a ship.
+#[derive(Debug, Clone, Copy)]
+pub struct Obstacle {
+    pub center: Vec3,
+    pub radius: f32,
+}
+
+/// Returns the radius of the bounding sphere of an asteroid of the given type at scale 1.
+///
+/// Types 0 to 4 are the `astroid_*` models, 5 to 11 `asteroid_*`, 12 to 16 `astroid_holes_*`,
+/// 17 and 18 `astroid_large_*` and 19 and 20 `astroid_large_holes_*`. Mirrors the colliders used
+/// by the client in `world/asteroids.rs`.
+pub fn asteroid_bounding_radius(asteroid_type: u8) -> f32 {
+    match asteroid_type {
+        0 => 1.3,
+        1 => 3.4,
+        2 => 3.8,
+        3 => 5.1,
+        4 => 4.2,
+        5 => 2.0,
+        6 => 2.5,
+        7 => 3.0,
+        8 => 3.5,
+        9 => 2.2,
+        10 => 2.8,
+        11 => 4.0,
+        12 => 4.0,
+        13 => 4.5,
+        14 => 5.0,
+        15 => 4.0,
+        16 => 5.5,
+        17 => 12.0,
+        18 => 14.0,
+        19 => 12.0,
+        20 => 15.0,
+        _ => 1.0,
+    }
+}
+
+/// Finds where a player leaving a ship should be placed, and which way they should face.
+///
+/// The ship's exit offset is tried first, then the same offset on the other sides of the hull
+/// and above and below it, each at increasing distances. The first candidate clear of every
+/// obstacle is used; if they are all obstructed, the one furthest from any obstacle is. The
+/// returned rotation looks away from the ship.
+pub fn find_egress(
+    ship_position: Vec3,
+    ship_rotation: Quat,
+    exit_offset: Vec3,
+    obstacles: &[Obstacle],
+) -> (Vec3, Quat) {
+    let distance = exit_offset.length();
+    let side_offsets = [
+        exit_offset,
+        Quat::from_rotation_y(PI) * exit_offset,
+        Quat::from_rotation_y(FRAC_PI_2) * exit_offset,
+        Quat::from_rotation_y(-FRAC_PI_2) * exit_offset,
+        Vec3::Y * distance,
+        Vec3::NEG_Y * distance,
+    ];
+
+    let mut best = (
+        f32::NEG_INFINITY,
+        ship_position + ship_rotation * exit_offset,
+    );
+    for multiplier in EXIT_DISTANCE_MULTIPLIERS {
+        for offset in side_offsets {
+            let candidate = ship_position + ship_rotation * (offset * multiplier);
+            let clearance = clearance(candidate, obstacles);
+            if clearance >= OBSTACLE_CLEARANCE {
+                return (
+                    candidate,
+                    look_away(ship_position, ship_rotation, candidate),
+                );
+            }
+
+            if clearance > best.0 {
+                best = (clearance, candidate);
+            }
+        }
+    }
+
+    (best.1, look_away(ship_position, ship_rotation, best.1))
+}
+
+/// Distance between a point and the surface of the closest obstacle, negative when inside one.
+fn clearance(point: Vec3, obstacles: &[Obstacle]) -> f32 {
+    obstacles
+        .iter()
+        .map(|obstacle| point.distance(obstacle.center) - obstacle.radius)
+        .fold(f32::INFINITY, f32::min)
+}
+
+/// Builds a rotation looking from the ship towards `point`, keeping the world up axis up.
+fn look_away(ship_position: Vec3, ship_rotation: Quat, point: Vec3) -> Quat {
+    let forward = (point - ship_position).normalize_or(ship_rotation * Vec3::NEG_Z);
+    let up = if forward.abs_diff_eq(Vec3::Y, 1e-3) || forward.abs_diff_eq(Vec3::NEG_Y, 1e-3) {
+        ship_rotation * Vec3::NEG_Z
+    } else {
+        Vec3::Y
+    };
+
+    let back = -forward;
+    let right = up.cross(back).normalize();
+    let up = back.cross(right);
+    Quat::from_mat3(&Mat3::from_cols(right, up, back))
+}
@@ -0,0 +1,261 @@
+use spacetimedb::{rand::Rng, reducer, Identity, ReducerContext};
+use spacetimedsl::{dsl, Wrapper};
+
+use crate::{admin::ensure_not_banned, player::owns_ship, tables::*};
+
+/// Lowest rank allowed to invite players into the faction, and to kick members of a lower rank.
+const MANAGE_MEMBERS_RANK: FactionRank = FactionRank::Officer;
+
+/// Lowest rank allowed to pilot the ships handed over to the faction.
+const PILOT_SHIPS_RANK: FactionRank = FactionRank::Pilot;
+
+/// Length limits of faction names and tags, in characters.
+const NAME_LENGTH: (usize, usize) = (3, 32);
+const TAG_LENGTH: (usize, usize) = (2, 5);
+
+/// Whether the player is a member of the faction the ship was handed over to, with a rank allowed
+/// to pilot it.
+pub fn can_pilot_faction_ship(ctx: &ReducerContext, ship: &Ship, player_id: &PlayerId) -> bool {
+    dsl(ctx)
+        .get_faction_member_by_player_id(player_id)
+        .is_ok_and(|member| {
+            Some(member.get_faction_id().value()) == *ship.get_faction_id()
+                && *member.get_rank() >= PILOT_SHIPS_RANK
+        })
+}
+
+/// The membership of the player calling a reducer, who must be in a faction.
+fn sender_membership(ctx: &ReducerContext) -> Result<FactionMember, String> {
+    dsl(ctx)
+        .get_faction_member_by_player_id(&PlayerId::new(ctx.sender))
+        .map_err(|_| "You are not in a faction".to_string())
+}
+
+/// The membership of another player of the sender's faction.
+fn fellow_membership(
+    ctx: &ReducerContext,
+    sender: &FactionMember,
+    player_id: Identity,
+) -> Result<FactionMember, String> {
+    if player_id == ctx.sender {
+        return Err("You cannot target yourself".into());
+    }
+
+    match dsl(ctx).get_faction_member_by_player_id(&PlayerId::new(player_id)) {
+        Ok(member) if member.get_faction_id() == sender.get_faction_id() => Ok(member),
+        _ => Err("Player is not a member of your faction".into()),
+    }
+}
+
+#[reducer]
+pub fn faction_create(ctx: &ReducerContext, name: String, tag: String) -> Result<(), String> {
+    ensure_not_banned(ctx)?;
+    let dsl = dsl(ctx);
+
+    let player_id = PlayerId::new(ctx.sender);
+    if dsl.get_faction_member_by_player_id(&player_id).is_ok() {
+        return Err("Leave your faction before creating another one".into());
+    }
+
+    let name = name.trim().to_string();
+    let (min, max) = NAME_LENGTH;
+    if !(min..=max).contains(&name.chars().count()) {
+        return Err(format!("Faction names are {min} to {max} characters long"));
+    }
+    let tag = tag.trim().to_uppercase();
+    let (min, max) = TAG_LENGTH;
+    if !(min..=max).contains(&tag.chars().count())
+        || !tag.chars().all(|c| c.is_ascii_alphanumeric())
+    {
+        return Err(format!("Faction tags are {min} to {max} letters or digits"));
+    }
+    if dsl.get_faction_by_name(&name).is_ok() {
+        return Err(format!("There already is a faction named {name}"));
+    }
+    if dsl.get_faction_by_tag(&tag).is_ok() {
+        return Err(format!("There already is a faction tagged [{tag}]"));
+    }
+
+    // Faction ids share the space of player identities, and are random like them.
+    let faction = dsl.create_faction(Identity::from_byte_array(ctx.rng().gen()), &name, &tag)?;
+    dsl.create_faction_member(
+        &player_id,
+        faction.get_id(),
+        FactionRank::Leader,
+        ctx.timestamp,
+    )?;
+
+    Ok(())
+}
+
+#[reducer]
+pub fn faction_invite_player(ctx: &ReducerContext, player_id: Identity) -> Result<(), String> {
+    ensure_not_banned(ctx)?;
+    let dsl = dsl(ctx);
+
+    let sender = sender_membership(ctx)?;
+    if *sender.get_rank() < MANAGE_MEMBERS_RANK {
+        return Err(format!(
+            "Only members ranked {MANAGE_MEMBERS_RANK:?} or above can invite players"
+        ));
+    }
+
+    let Ok(player) = dsl.get_player_by_id(&PlayerId::new(player_id)) else {
+        return Err("Player not found".into());
+    };
+    if dsl
+        .get_faction_member_by_player_id(&player.get_id())
+        .is_ok()
+    {
+        return Err("Player is already in a faction".into());
+    }
+    if dsl
+        .get_faction_invites_by_player_id(&player.get_id())
+        .any(|invite| invite.get_faction_id() == sender.get_faction_id())
+    {
+        return Ok(());
+    }
+
+    dsl.create_faction_invite(sender.get_faction_id(), player.get_id(), ctx.timestamp)?;
+
+    Ok(())
+}
+
+/// Joins a faction the player has been invited to, dropping their other invitations.
+#[reducer]
+pub fn faction_join(ctx: &ReducerContext, faction_id: Identity) -> Result<(), String> {
+    ensure_not_banned(ctx)?;
+    let dsl = dsl(ctx);
+
+    let player_id = PlayerId::new(ctx.sender);
+    if dsl.get_faction_member_by_player_id(&player_id).is_ok() {
+        return Err("Leave your faction before joining another one".into());
+    }
+
+    let faction_id = FactionId::new(faction_id);
+    if !dsl
+        .get_faction_invites_by_player_id(&player_id)
+        .any(|invite| invite.get_faction_id() == faction_id)
+    {
+        return Err("You have not been invited to this faction".into());
+    }
+
+    dsl.create_faction_member(&player_id, &faction_id, FactionRank::Recruit, ctx.timestamp)?;
+    dsl.delete_faction_invites_by_player_id(&player_id)?;
+
+    Ok(())
+}
+
+#[reducer]
+pub fn faction_decline_invite(ctx: &ReducerContext, faction_id: Identity) -> Result<(), String> {
+    ensure_not_banned(ctx)?;
+    let dsl = dsl(ctx);
+
+    for invite in dsl.get_faction_invites_by_player_id(&PlayerId::new(ctx.sender)) {
+        if invite.get_faction_id().value() == faction_id {
+            dsl.delete_faction_invite_by_id(invite.get_id())?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Leaves the player's faction.
+///
+/// Leaders must hand their rank over before leaving, unless they are the last member, in which
+/// case the faction is disbanded and its ships go back to them.
+#[reducer]
+pub fn faction_leave(ctx: &ReducerContext) -> Result<(), String> {
+    ensure_not_banned(ctx)?;
+    let dsl = dsl(ctx);
+
+    let sender = sender_membership(ctx)?;
+    let faction_id = sender.get_faction_id();
+    let last_member = dsl.get_faction_members_by_faction_id(&faction_id).count() == 1;
+
+    if !last_member {
+        if *sender.get_rank() == FactionRank::Leader {
+            return Err("Make another member leader before leaving".into());
+        }
+        dsl.delete_faction_member_by_player_id(sender.get_player_id())?;
+        return Ok(());
+    }
+
+    for mut ship in dsl.get_all_ships() {
+        if *ship.get_faction_id() == Some(faction_id.value()) {
+            ship.set_owner_id(sender.get_player_id());
+            ship.set_faction_id(None);
+            dsl.update_ship_by_id(ship)?;
+        }
+    }
+    dsl.delete_faction_by_id(&faction_id)?;
+
+    Ok(())
+}
+
+/// Removes a member of a lower rank from the faction.
+#[reducer]
+pub fn faction_kick(ctx: &ReducerContext, player_id: Identity) -> Result<(), String> {
+    ensure_not_banned(ctx)?;
+    let dsl = dsl(ctx);
+
+    let sender = sender_membership(ctx)?;
+    let member = fellow_membership(ctx, &sender, player_id)?;
+    if *sender.get_rank() < MANAGE_MEMBERS_RANK || sender.get_rank() <= member.get_rank() {
+        return Err(format!(
+            "Only members ranked {MANAGE_MEMBERS_RANK:?} or above can kick, and only lower ranks"
+        ));
+    }
+
+    dsl.delete_faction_member_by_player_id(member.get_player_id())?;
+
+    Ok(())
+}
+
+/// Promotes or demotes a member. Only the leader can, and making someone else leader hands the
+/// rank over, the former leader becoming an officer.
+#[reducer]
+pub fn faction_set_rank(
+    ctx: &ReducerContext,
+    player_id: Identity,
+    rank: FactionRank,
+) -> Result<(), String> {
+    ensure_not_banned(ctx)?;
+    let dsl = dsl(ctx);
+
+    let mut sender = sender_membership(ctx)?;
+    if *sender.get_rank() != FactionRank::Leader {
+        return Err("Only the leader can change ranks".into());
+    }
+    let mut member = fellow_membership(ctx, &sender, player_id)?;
+
+    member.set_rank(rank);
+    dsl.update_faction_member_by_player_id(member)?;
+
+    if rank == FactionRank::Leader {
+        sender.set_rank(FactionRank::Officer);
+        dsl.update_faction_member_by_player_id(sender)?;
+    }
+
+    Ok(())
+}
+
+/// Hands a ship the player owns over to their faction, for its members to share.
+#[reducer]
+pub fn faction_transfer_ship(ctx: &ReducerContext, ship_id: u64) -> Result<(), String> {
+    ensure_not_banned(ctx)?;
+    let dsl = dsl(ctx);
+
+    let sender = sender_membership(ctx)?;
+    let mut ship = dsl.get_ship_by_id(ShipId::new(ship_id))?;
+    if !owns_ship(&ship, &PlayerId::new(ctx.sender)) {
+        return Err("Only the owner of a ship can hand it over".into());
+    }
+
+    ship.set_faction_id(Some(sender.get_faction_id().value()));
+    dsl.update_ship_by_id(ship)?;
+    // Access granted by the former owner doesn't carry over, the faction ranks decide now.
+    dsl.delete_ship_accesses_by_ship_id(ShipId::new(ship_id))?;
+
+    Ok(())
+}
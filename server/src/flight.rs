@@ -0,0 +1,256 @@
+use glam::{Quat, Vec3};
+use log::warn;
+use simulation::flight::{step, FlightState, Power, ShipDynamics, FLIGHT_STEP};
+use spacetimedb::{reducer, table, ReducerContext, ScheduleAt};
+use spacetimedsl::{dsl, Wrapper};
+
+use crate::{
+    admin::ensure_not_banned, cargo::ship_mass, fuel::burn_fuel, rate_limit::rate_limited,
+    tables::*, weapons::current_energy,
+};
+
+/// How often ships flown by the server are advanced, by a single step of the flight simulation
+/// each time.
+pub const FLIGHT_UPDATE_INTERVAL: std::time::Duration = FLIGHT_STEP;
+
+#[table(name = ship_flight_update, scheduled(world_update_ship_flights))]
+pub struct ShipFlightUpdate {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+}
+
+impl ShipFlightUpdate {
+    pub fn new(scheduled_at: ScheduleAt) -> Self {
+        Self {
+            scheduled_id: 0,
+            scheduled_at,
+        }
+    }
+}
+
+/// How a ship of the given type and mass responds to its controls.
+fn ship_dynamics(ship_type: &ShipType, mass: f32) -> ShipDynamics {
+    ShipDynamics {
+        mass,
+        radius: *ship_type.get_radius(),
+        linear_damping: *ship_type.get_linear_damping(),
+        angular_damping: *ship_type.get_angular_damping(),
+        thrust: *ship_type.get_thrust(),
+        vertical_thrust: *ship_type.get_vertical_thrust(),
+        lateral_thrust: *ship_type.get_lateral_thrust(),
+        pitch_torque: *ship_type.get_pitch_torque(),
+        yaw_torque: *ship_type.get_yaw_torque(),
+        roll_torque: *ship_type.get_roll_torque(),
+    }
+}
+
+/// The power a ship can put behind its controls, and whether it's boosting. Boosting lasts as
+/// long as the tank and the capacitor.
+fn flight_power(
+    ctx: &ReducerContext,
+    status: &ShipStatus,
+    ship_type: &ShipType,
+    controls: &simulation::flight::FlightControls,
+) -> (Power, bool) {
+    let fueled = *status.get_fuel() > 0.0;
+    let boosting = fueled
+        && controls.boost
+        && controls.thrust > 0.0
+        && current_energy(status, ship_type, ctx.timestamp) > 0.0;
+    let boost = if boosting {
+        *ship_type.get_boost_factor()
+    } else {
+        1.0
+    };
+    let power = Power::new(
+        *status.get_engines(),
+        *status.get_thrusters(),
+        fueled,
+        boost,
+    );
+    (power, boosting)
+}
+
+/// Brings a ship flown by the server to a stop, if it is one.
+pub fn stop_ship_flight(ctx: &ReducerContext, ship_id: &ShipId) -> Result<(), String> {
+    let dsl = dsl(ctx);
+
+    if let Ok(mut flight) = dsl.get_ship_flight_by_ship_id(ship_id) {
+        flight.set_vel_x(0.0);
+        flight.set_vel_y(0.0);
+        flight.set_vel_z(0.0);
+        flight.set_ang_vel_x(0.0);
+        flight.set_ang_vel_y(0.0);
+        flight.set_ang_vel_z(0.0);
+        dsl.update_ship_flight_by_ship_id(flight)?;
+    }
+
+    Ok(())
+}
+
+/// Switches the ship the player is piloting between being moved by their client and being flown
+/// by the server from their controls. A ship handed to the server starts from rest.
+#[reducer]
+pub fn player_set_flight_mode(ctx: &ReducerContext, server_flown: bool) -> Result<(), String> {
+    ensure_not_banned(ctx)?;
+    let dsl = dsl(ctx);
+
+    let Ok(ship_pilot) = dsl.get_ship_pilot_by_player_id(&PlayerId::new(ctx.sender)) else {
+        return Err("Player is not piloting a ship".into());
+    };
+    let ship_id = ship_pilot.get_ship_id();
+
+    match (
+        server_flown,
+        dsl.get_ship_flight_by_ship_id(&ship_id).is_ok(),
+    ) {
+        (true, false) => {
+            dsl.create_ship_flight(
+                &ship_id,
+                FlightControls::default(),
+                0.0,
+                0.0,
+                0.0,
+                0.0,
+                0.0,
+                0.0,
+            )?;
+        }
+        (false, true) => {
+            dsl.delete_ship_flight_by_ship_id(&ship_id)?;
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Sets the controls of the ship flown by the server the player is piloting. They hold until
+/// the next call, every axis being clamped between -1 and 1 as they're applied.
+#[reducer]
+pub fn player_set_flight_controls(
+    ctx: &ReducerContext,
+    controls: FlightControls,
+) -> Result<(), String> {
+    rate_limited(ctx, "player_set_flight_controls", || {
+        let dsl = dsl(ctx);
+
+        let Ok(ship_pilot) = dsl.get_ship_pilot_by_player_id(&PlayerId::new(ctx.sender)) else {
+            return Err("Player is not piloting a ship".into());
+        };
+        let Ok(mut flight) = dsl.get_ship_flight_by_ship_id(&ship_pilot.get_ship_id()) else {
+            return Err("Ship is not flown by the server".into());
+        };
+
+        flight.set_controls(controls);
+        dsl.update_ship_flight_by_ship_id(flight)?;
+
+        Ok(())
+    })
+}
+
+/// Advances every ship flown by the server by a step of the flight simulation, burning fuel and
+/// capacitor energy like the moves of ships flown by their clients.
+#[reducer]
+pub fn world_update_ship_flights(
+    ctx: &ReducerContext,
+    _update: ShipFlightUpdate,
+) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Ship flights can only be updated by the server".to_string());
+    }
+
+    for flight in dsl(ctx).get_all_ship_flights() {
+        let ship_id = flight.get_ship_id();
+        // A ship that can't be advanced is left where it is, without holding up the others.
+        if let Err(err) = step_ship_flight(ctx, flight) {
+            warn!(
+                "Failed to advance the flight of ship {}: {err}",
+                ship_id.value()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Advances a ship flown by the server by a step of the flight simulation.
+fn step_ship_flight(ctx: &ReducerContext, mut flight: ShipFlight) -> Result<(), String> {
+    let dsl = dsl(ctx);
+
+    let ship_id = flight.get_ship_id();
+    // Docked ships are carried by their station.
+    let Ok(location) = dsl.get_ship_location_by_ship_id(&ship_id) else {
+        return Ok(());
+    };
+
+    let controls = simulation::flight::FlightControls::from(*flight.get_controls()).clamped();
+    let state = FlightState {
+        position: Vec3::new(*location.get_x(), *location.get_y(), *location.get_z()),
+        rotation: Quat::from_xyzw(
+            *location.get_rot_x(),
+            *location.get_rot_y(),
+            *location.get_rot_z(),
+            *location.get_rot_w(),
+        )
+        .normalize(),
+        linear_velocity: Vec3::new(
+            *flight.get_vel_x(),
+            *flight.get_vel_y(),
+            *flight.get_vel_z(),
+        ),
+        angular_velocity: Vec3::new(
+            *flight.get_ang_vel_x(),
+            *flight.get_ang_vel_y(),
+            *flight.get_ang_vel_z(),
+        ),
+    };
+    // A ship at rest stays put until its pilot touches the controls.
+    if controls.is_idle()
+        && state.linear_velocity == Vec3::ZERO
+        && state.angular_velocity == Vec3::ZERO
+    {
+        return Ok(());
+    }
+
+    let ship = dsl.get_ship_by_id(&ship_id)?;
+    let ship_type = dsl.get_ship_type_by_id(ship.get_ship_type_id())?;
+    let status = dsl.get_ship_status_by_ship_id(&ship_id)?;
+    let (power, boosting) = flight_power(ctx, &status, &ship_type, &controls);
+    let dt = FLIGHT_STEP.as_secs_f32();
+    let dynamics = ship_dynamics(&ship_type, ship_mass(ctx, &ship_id, &ship_type)?);
+
+    let next = step(&dynamics, &state, &controls, power, dt);
+    burn_fuel(
+        ctx,
+        &ship_type,
+        status,
+        state.position.distance(next.position),
+        FLIGHT_STEP,
+        boosting,
+    )?;
+
+    dsl.update_ship_location_by_ship_id(ShipLocation::new(
+        ship_id,
+        next.position.x,
+        next.position.y,
+        next.position.z,
+        next.rotation.x,
+        next.rotation.y,
+        next.rotation.z,
+        next.rotation.w,
+        ctx.timestamp,
+    ))?;
+
+    flight.set_vel_x(next.linear_velocity.x);
+    flight.set_vel_y(next.linear_velocity.y);
+    flight.set_vel_z(next.linear_velocity.z);
+    flight.set_ang_vel_x(next.angular_velocity.x);
+    flight.set_ang_vel_y(next.angular_velocity.y);
+    flight.set_ang_vel_z(next.angular_velocity.z);
+    dsl.update_ship_flight_by_ship_id(flight)?;
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use std::time::Duration;
+
+use spacetimedb::{reducer, ReducerContext};
+use spacetimedsl::{dsl, Wrapper};
+
+use crate::{admin::ensure_not_banned, tables::*, weapons::current_energy};
+
+/// Credits charged at stations per unit of fuel.
+const FUEL_PRICE: f32 = 0.5;
+
+/// Charges a ship for a move it made: fuel for the distance it flew, and capacitor energy for the
+/// time it spent boosting.
+///
+/// The move must have been validated already. A ship is trusted to have boosted for as long as
+/// its capacitor allowed, so boosting is only refused once the capacitor is dry.
+pub fn burn_fuel(
+    ctx: &ReducerContext,
+    ship_type: &ShipType,
+    mut status: ShipStatus,
+    distance: f32,
+    elapsed: Duration,
+    boosting: bool,
+) -> Result<(), String> {
+    let dsl = dsl(ctx);
+
+    if boosting {
+        let energy = current_energy(&status, ship_type, ctx.timestamp);
+        if energy <= 0.0 {
+            return Err("Not enough energy to boost".into());
+        }
+
+        let drain = ship_type.get_boost_energy_drain() * elapsed.as_secs_f32();
+        status.set_energy((energy - drain).max(0.0));
+        status.set_energy_updated_at(ctx.timestamp);
+    }
+
+    status.set_fuel((status.get_fuel() - distance * ship_type.get_fuel_consumption()).max(0.0));
+    dsl.update_ship_status_by_ship_id(status)?;
+
+    Ok(())
+}
+
+/// Credits it costs to fill a ship's tank.
+pub fn refuel_cost(status: &ShipStatus, ship_type: &ShipType) -> u64 {
+    let missing_fuel = (ship_type.get_max_fuel() - status.get_fuel()).max(0.0);
+    (missing_fuel * FUEL_PRICE).ceil() as u64
+}
+
+/// Fills the tank of the ship the player is piloting at the station it's docked at, for credits.
+#[reducer]
+pub fn refuel_ship(ctx: &ReducerContext) -> Result<(), String> {
+    ensure_not_banned(ctx)?;
+    let dsl = dsl(ctx);
+
+    let player_id = PlayerId::new(ctx.sender);
+    let Ok(ship_pilot) = dsl.get_ship_pilot_by_player_id(&player_id) else {
+        return Err("Player is not piloting a ship".into());
+    };
+    let ship_id = ship_pilot.get_ship_id();
+    if dsl.get_ship_docking_by_ship_id(&ship_id).is_err() {
+        return Err("Ship must be docked at a station to be refuelled".into());
+    }
+
+    let ship = dsl.get_ship_by_id(&ship_id)?;
+    let ship_type = dsl.get_ship_type_by_id(ship.get_ship_type_id())?;
+    let mut status = dsl.get_ship_status_by_ship_id(&ship_id)?;
+    let mut wallet = dsl.get_wallet_by_player_id(&player_id)?;
+
+    let cost = refuel_cost(&status, &ship_type);
+    let credits = *wallet.get_credits();
+    if cost > credits {
+        return Err(format!(
+            "Not enough credits ({cost} needed, {credits} available)"
+        ));
+    }
+
+    wallet.set_credits(credits - cost);
+    dsl.update_wallet_by_player_id(wallet)?;
+
+    status.set_fuel(*ship_type.get_max_fuel());
+    dsl.update_ship_status_by_ship_id(status)?;
+
+    Ok(())
+}
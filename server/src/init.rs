@@ -1,67 +1,143 @@
-use std::{f32::consts::TAU, time::Duration};
-
+use glam::Vec3;
 use spacetimedb::{reducer, ReducerContext, Table};
 use spacetimedsl::dsl;
 
 use crate::{
-    tables::{CreateAsteroidRow, CreateShipTypeRow, CreateStationRow},
-    world::{station_rotation_update, StationRotationUpdate},
+    asteroids::spawn_asteroid_fields,
+    chat::{chat_cleanup, ChatCleanup, CHAT_CLEANUP_INTERVAL},
+    destruction::{wreck_cleanup, WreckCleanup, WRECK_CLEANUP_INTERVAL},
+    economy::{market_update, MarketUpdate, MARKET_UPDATE_INTERVAL},
+    flight::{ship_flight_update, ShipFlightUpdate, FLIGHT_UPDATE_INTERVAL},
+    item_catalog::item_catalog,
+    missiles::{missile_update, MissileUpdate, MISSILE_UPDATE_INTERVAL},
+    ship_catalog::ship_catalog,
+    tables::*,
+    weapon_catalog::weapon_catalog,
+    world::spawn_station,
 };
 
-/// The speed at which stations rotate in the world, in radians per second.
-const STATIONS_ROTATION_SPEED: f32 = 0.01;
-
 #[reducer(init)]
 pub fn init(ctx: &ReducerContext) {
     let dsl = dsl(ctx);
 
-    dsl.create_ship_type(
-        "Fighter", // Name
-        0.0, 10.0, 40.0, // Camera offsets
-        1.0,  // Mass
-        1.0, 1.0, // Damping values
-        10000.0, 1000.0, 1000.0, // Thrust values
-        1500.0, 1500.0, 2000.0, // Torque values
-    )
-    .unwrap();
+    // Whoever published the module runs it, and can make others admins from there.
+    dsl.create_admin(ctx.sender, ctx.sender, ctx.timestamp)
+        .unwrap();
 
-    dsl.create_station(
-        "Station Alpha",
-        0.0,
-        0.0,
-        0.0,
-        STATIONS_ROTATION_SPEED,
-        0.0,
-        ctx.timestamp
-            .to_duration_since_unix_epoch()
-            .unwrap()
-            .as_millis(),
-    )
-    .unwrap();
+    for weapon in weapon_catalog() {
+        let weapon_type = dsl
+            .create_weapon_type(
+                &weapon.name,
+                weapon.damage_type,
+                weapon.damage,
+                weapon.range,
+                weapon.cooldown,
+                weapon.energy_cost,
+            )
+            .unwrap();
+
+        if let Some(missile) = &weapon.missile {
+            dsl.create_missile_type(
+                weapon_type.get_id(),
+                &missile.model,
+                missile.speed,
+                missile.acceleration,
+                missile.fuel,
+                missile.navigation_gain,
+                missile.proximity_radius,
+                missile.blast_radius,
+            )
+            .unwrap();
+        }
+    }
 
-    let center_x = 0.0;
-    let center_y = 0.0;
-    let center_z = 0.0;
-    let number_of_asteroids = 100;
-    let radius = 1500.0;
-    for i in 0..number_of_asteroids {
-        let angle = i as f32 / number_of_asteroids as f32 * TAU;
+    for ship in ship_catalog() {
+        let ship_type = dsl
+            .create_ship_type(
+                &ship.name,
+                &ship.model,
+                &ship.collider,
+                ship.camera_offset.0,
+                ship.camera_offset.1,
+                ship.camera_offset.2,
+                ship.exit_offset.0,
+                ship.exit_offset.1,
+                ship.exit_offset.2,
+                ship.mass,
+                ship.linear_damping,
+                ship.angular_damping,
+                ship.thrust,
+                ship.vertical_thrust,
+                ship.lateral_thrust,
+                ship.pitch_torque,
+                ship.yaw_torque,
+                ship.roll_torque,
+                ship.cargo_capacity,
+                ship.radius,
+                ship.hull,
+                ship.energy,
+                ship.energy_recharge,
+                ship.shield,
+                ship.shield_recharge,
+                ship.shield_delay,
+                ship.armor.energy,
+                ship.armor.kinetic,
+                ship.armor.explosive,
+                ship.fuel,
+                ship.fuel_consumption,
+                ship.boost,
+                ship.boost_energy,
+                ship.retired,
+            )
+            .unwrap();
 
-        dsl.create_asteroid(
-            center_x + radius * angle.cos(),
-            center_y,
-            center_z + radius * angle.sin(),
-            0.0,           // rot_x
-            0.0,           // rot_y
-            0.0,           // rot_z
-            1.0,           // rot_w
-            (i % 5) as u8, // asteroid_type
-            10.0,          // scale
+        for hardpoint in &ship.hardpoints {
+            let weapon_type = dsl
+                .get_weapon_type_by_name(&hardpoint.weapon)
+                .unwrap_or_else(|_| panic!("Unknown weapon: {}", hardpoint.weapon));
+            dsl.create_hardpoint(
+                ship_type.get_id(),
+                weapon_type.get_id(),
+                hardpoint.offset.0,
+                hardpoint.offset.1,
+                hardpoint.offset.2,
+            )
+            .unwrap();
+        }
+    }
+
+    for item in item_catalog() {
+        dsl.create_item_type(
+            &item.name,
+            item.volume,
+            item.mass,
+            item.base_price,
+            item.ore,
         )
         .unwrap();
     }
 
+    spawn_station(ctx, "Station Alpha", Vec3::ZERO).unwrap();
+
+    spawn_asteroid_fields(ctx).unwrap();
+
+    ctx.db
+        .market_update()
+        .insert(MarketUpdate::new(MARKET_UPDATE_INTERVAL.into()));
+
+    ctx.db
+        .missile_update()
+        .insert(MissileUpdate::new(MISSILE_UPDATE_INTERVAL.into()));
+
+    ctx.db
+        .ship_flight_update()
+        .insert(ShipFlightUpdate::new(FLIGHT_UPDATE_INTERVAL.into()));
+
+    ctx.db
+        .wreck_cleanup()
+        .insert(WreckCleanup::new(WRECK_CLEANUP_INTERVAL.into()));
+
     ctx.db
-        .station_rotation_update()
-        .insert(StationRotationUpdate::new(Duration::from_secs(5).into()));
+        .chat_cleanup()
+        .insert(ChatCleanup::new(CHAT_CLEANUP_INTERVAL.into()));
 }
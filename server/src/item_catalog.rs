@@ -0,0 +1,25 @@
+use serde::Deserialize;
+
+/// The item catalog, embedded in the module so it's seeded along with the rest of the world.
+const ITEM_CATALOG: &str = include_str!("../data/items.ron");
+
+/// An item that can be carried in a cargo hold, as described in `data/items.ron`.
+#[derive(Debug, Deserialize)]
+pub struct ItemDefinition {
+    pub name: String,
+    /// Volume taken by one unit of the item in a cargo hold.
+    pub volume: f32,
+    /// Mass of one unit of the item, in the same unit as ship masses.
+    pub mass: f32,
+    /// Reference price of one unit of the item, in credits.
+    pub base_price: u64,
+    /// Whether the item can be mined out of asteroids.
+    pub ore: bool,
+}
+
+/// Parses the embedded item catalog.
+///
+/// Item types are created in catalog order, so the n-th entry gets the item type id n.
+pub fn item_catalog() -> Vec<ItemDefinition> {
+    ron::from_str(ITEM_CATALOG).expect("Invalid item catalog")
+}
@@ -1,21 +1,74 @@
+use admin::in_effect;
 use log::debug;
+use market::ensure_wallet;
+use player::ship_egress;
+use ships::{cancel_parked_ships_despawn, schedule_parked_ships_despawn};
 use spacetimedb::{reducer, ReducerContext};
 use spacetimedsl::{dsl, Wrapper};
 use tables::*;
 
+mod admin;
+mod asteroid_field;
+mod asteroids;
+mod cargo;
+mod chat;
+mod damage;
+mod destruction;
+mod docking;
+mod economy;
+mod egress;
+mod factions;
+mod flight;
+mod fuel;
 mod init;
+mod item_catalog;
+mod market;
+mod mining;
+mod missiles;
+mod movement;
 mod player;
+mod rate_limit;
+mod respawn;
+mod sectors;
+mod ship_catalog;
+mod ships;
 mod tables;
+mod weapon_catalog;
+mod weapons;
 mod world;
 
 #[reducer(client_connected)]
 fn on_connected(ctx: &ReducerContext) -> Result<(), String> {
     debug!("Player connected: {}", ctx.sender);
+    let dsl = dsl(ctx);
+
+    let now = ctx.timestamp;
+    let player_id = PlayerId::new(ctx.sender);
+
+    // Refusing the connection is the only way to keep banned players out.
+    if let Ok(ban) = dsl.get_ban_by_player_id(&player_id) {
+        if in_effect(*ban.get_expires_at(), now) {
+            return Err(format!("Banned: {}", ban.get_reason()));
+        }
+        dsl.delete_ban_by_player_id(&player_id)?;
+    }
 
-    dsl(ctx).create_player(
+    if let Ok(mut player) = dsl.get_player_by_id(&player_id) {
+        player.set_online(true);
+        player.set_last_seen(now);
+        dsl.update_player_by_id(player)?;
+        cancel_parked_ships_despawn(ctx, &player_id);
+        // Players from before wallets existed get theirs on their next connection.
+        ensure_wallet(ctx, &player_id)?;
+        return Ok(());
+    }
+
+    dsl.create_player(
         ctx.sender, 0.0, 0.0, 0.0, // Initial position
         0.0, 0.0, 0.0, 1.0, // Initial rotation (identity quaternion)
+        true, now, // Online since now
     )?;
+    ensure_wallet(ctx, &player_id)?;
     Ok(())
 }
 
@@ -25,8 +78,52 @@ fn on_disconnected(ctx: &ReducerContext) -> Result<(), String> {
     let dsl = dsl(ctx);
 
     let player_id = PlayerId::new(ctx.sender);
-    dsl.delete_player_by_id(&player_id)?;
-    dsl.delete_ships_by_owner_id(&player_id)?;
+    let mut player = dsl.get_player_by_id(&player_id)?;
+
+    // Remember where the player was so they can resume from there, parking their ship in place
+    // if they were flying one.
+    let location = if let Ok(location) = dsl.get_player_location_by_player_id(&player_id) {
+        dsl.delete_player_location_by_player_id(&player_id)?;
+        Some((
+            [*location.get_x(), *location.get_y(), *location.get_z()],
+            [
+                *location.get_rot_x(),
+                *location.get_rot_y(),
+                *location.get_rot_z(),
+                *location.get_rot_w(),
+            ],
+        ))
+    } else if let Ok(ship_pilot) = dsl.get_ship_pilot_by_player_id(&player_id) {
+        let (position, rotation) = ship_egress(ctx, ship_pilot.get_ship_id())?;
+        dsl.delete_ship_pilot_by_player_id(&player_id)?;
+        // The next pilot picks how the ship is flown, like when leaving it, see `leave_ship`.
+        if dsl
+            .get_ship_flight_by_ship_id(&ship_pilot.get_ship_id())
+            .is_ok()
+        {
+            dsl.delete_ship_flight_by_ship_id(&ship_pilot.get_ship_id())?;
+        }
+        Some((position.to_array(), rotation.to_array()))
+    } else {
+        None
+    };
+
+    if let Some(([x, y, z], [rot_x, rot_y, rot_z, rot_w])) = location {
+        player.set_x(x);
+        player.set_y(y);
+        player.set_z(z);
+        player.set_rot_x(rot_x);
+        player.set_rot_y(rot_y);
+        player.set_rot_z(rot_z);
+        player.set_rot_w(rot_w);
+    }
+
+    player.set_online(false);
+    player.set_last_seen(ctx.timestamp);
+    dsl.update_player_by_id(player)?;
+    schedule_parked_ships_despawn(ctx, &player_id);
+    // Refusals are only news to the session that made the calls.
+    dsl.delete_rejected_calls_by_player_id(&player_id)?;
 
     Ok(())
 }
@@ -0,0 +1,218 @@
+use glam::Vec3;
+use simulation::ranges::{check_range, MAX_DOCKING_DISTANCE};
+use spacetimedb::{reducer, ReducerContext};
+use spacetimedsl::{dsl, Wrapper};
+
+use crate::{
+    admin::ensure_not_banned,
+    cargo::{add_cargo, remove_cargo},
+    tables::*,
+};
+
+/// Credits every new player starts with.
+const STARTING_CREDITS: u64 = 1_000;
+
+/// Units of each commodity a new station has in stock. Stations start without ore, they buy it
+/// from miners.
+const STARTING_STOCK: u32 = 500;
+
+/// The station's margin on either side of a market's price, as a fraction of it.
+const MARKET_SPREAD: f32 = 0.1;
+
+/// Stock stations want to keep of every commodity.
+const STARTING_DEMAND: u32 = 500;
+
+/// Units of each commodity other than ore a station produces per economy tick.
+const COMMODITY_PRODUCTION: u32 = 10;
+
+/// Units of each commodity a station consumes per economy tick. Stations consume ore faster, as
+/// it's only ever brought in by miners.
+const COMMODITY_CONSUMPTION: u32 = 8;
+const ORE_CONSUMPTION: u32 = 5;
+
+/// Opens a market for every item type at a new station.
+pub fn create_station_markets(ctx: &ReducerContext, station_id: &StationId) -> Result<(), String> {
+    let dsl = dsl(ctx);
+
+    for item_type in dsl.get_all_item_types() {
+        let price = *item_type.get_base_price() as f32;
+        let (buy_price, sell_price) = market_prices(price);
+        let (stock, production, consumption) = if *item_type.get_ore() {
+            (0, 0, ORE_CONSUMPTION)
+        } else {
+            (STARTING_STOCK, COMMODITY_PRODUCTION, COMMODITY_CONSUMPTION)
+        };
+
+        dsl.create_market(
+            station_id,
+            item_type.get_id(),
+            buy_price,
+            sell_price,
+            stock,
+            STARTING_DEMAND,
+            price,
+            production,
+            consumption,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// The prices players buy and sell an item at, around the market's price.
+pub fn market_prices(price: f32) -> (u64, u64) {
+    let buy_price = (price * (1.0 + MARKET_SPREAD)).ceil() as u64;
+    let sell_price = (price * (1.0 - MARKET_SPREAD)).floor() as u64;
+
+    (buy_price.max(1), sell_price)
+}
+
+/// Gives a player their starting credits, unless they already have a wallet.
+pub fn ensure_wallet(ctx: &ReducerContext, player_id: &PlayerId) -> Result<(), String> {
+    let dsl = dsl(ctx);
+
+    if dsl.get_wallet_by_player_id(player_id).is_err() {
+        dsl.create_wallet(player_id, STARTING_CREDITS)?;
+    }
+
+    Ok(())
+}
+
+/// Buys items from a station's market into the cargo hold of the ship the player is piloting.
+#[reducer]
+pub fn buy(
+    ctx: &ReducerContext,
+    station_id: u64,
+    item_type_id: u64,
+    quantity: u32,
+) -> Result<(), String> {
+    ensure_not_banned(ctx)?;
+    let dsl = dsl(ctx);
+
+    let player_id = PlayerId::new(ctx.sender);
+    let ship_id = trading_ship(ctx, &player_id, &StationId::new(station_id))?;
+    let item_type_id = ItemTypeId::new(item_type_id);
+    let mut market = find_market(ctx, &StationId::new(station_id), &item_type_id)?;
+    let mut wallet = dsl.get_wallet_by_player_id(&player_id)?;
+
+    if quantity == 0 {
+        return Err("Nothing to buy".into());
+    }
+
+    let stock = *market.get_stock();
+    if quantity > stock {
+        return Err(format!("The station only has {stock} in stock"));
+    }
+
+    let cost = market
+        .get_buy_price()
+        .checked_mul(quantity as u64)
+        .ok_or("Order is too large")?;
+    let credits = *wallet.get_credits();
+    if cost > credits {
+        return Err(format!(
+            "Not enough credits ({cost} needed, {credits} available)"
+        ));
+    }
+
+    add_cargo(ctx, &ship_id, &item_type_id, quantity)?;
+
+    wallet.set_credits(credits - cost);
+    dsl.update_wallet_by_player_id(wallet)?;
+
+    market.set_stock(stock - quantity);
+    dsl.update_market_by_id(market)?;
+
+    Ok(())
+}
+
+/// Sells items from the cargo hold of the ship the player is piloting to a station's market.
+#[reducer]
+pub fn sell(
+    ctx: &ReducerContext,
+    station_id: u64,
+    item_type_id: u64,
+    quantity: u32,
+) -> Result<(), String> {
+    ensure_not_banned(ctx)?;
+    let dsl = dsl(ctx);
+
+    let player_id = PlayerId::new(ctx.sender);
+    let ship_id = trading_ship(ctx, &player_id, &StationId::new(station_id))?;
+    let item_type_id = ItemTypeId::new(item_type_id);
+    let mut market = find_market(ctx, &StationId::new(station_id), &item_type_id)?;
+    let mut wallet = dsl.get_wallet_by_player_id(&player_id)?;
+
+    if quantity == 0 {
+        return Err("Nothing to sell".into());
+    }
+
+    let revenue = market
+        .get_sell_price()
+        .checked_mul(quantity as u64)
+        .ok_or("Order is too large")?;
+
+    remove_cargo(ctx, &ship_id, &item_type_id, quantity)?;
+
+    wallet.set_credits(wallet.get_credits().saturating_add(revenue));
+    dsl.update_wallet_by_player_id(wallet)?;
+
+    market.set_stock(market.get_stock().saturating_add(quantity));
+    dsl.update_market_by_id(market)?;
+
+    Ok(())
+}
+
+/// The ship the player is piloting, as long as it's docked at the station or close enough to
+/// trade with it.
+fn trading_ship(
+    ctx: &ReducerContext,
+    player_id: &PlayerId,
+    station_id: &StationId,
+) -> Result<ShipId, String> {
+    let dsl = dsl(ctx);
+
+    let Ok(ship_pilot) = dsl.get_ship_pilot_by_player_id(player_id) else {
+        return Err("Player is not piloting a ship".into());
+    };
+    let ship_id = ship_pilot.get_ship_id();
+
+    let Ok(station) = dsl.get_station_by_id(station_id) else {
+        return Err("Station not found".into());
+    };
+
+    if let Ok(docking) = dsl.get_ship_docking_by_ship_id(&ship_id) {
+        if docking.get_station_id() == *station_id {
+            return Ok(ship_id);
+        }
+        return Err("Ship is docked at another station".into());
+    }
+
+    let ship_location = dsl.get_ship_location_by_ship_id(&ship_id)?;
+    if let Err(distance) = check_range(
+        Vec3::new(
+            *ship_location.get_x(),
+            *ship_location.get_y(),
+            *ship_location.get_z(),
+        ),
+        Vec3::new(*station.get_x(), *station.get_y(), *station.get_z()),
+        MAX_DOCKING_DISTANCE,
+    ) {
+        return Err(format!(
+            "Station is too far away to trade ({distance:.0}m, max {MAX_DOCKING_DISTANCE:.0}m)"
+        ));
+    }
+
+    Ok(ship_id)
+}
+
+fn find_market(
+    ctx: &ReducerContext,
+    station_id: &StationId,
+    item_type_id: &ItemTypeId,
+) -> Result<Market, String> {
+    dsl(ctx)
+        .get_markets_by_station_id(station_id)
+        .find(|market| market.get_item_type_id() == *item_type_id)
+        .ok_or_else(|| "This station doesn't trade this item".into())
+}
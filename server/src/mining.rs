@@ -0,0 +1,106 @@
+use std::time::Duration;
+
+use glam::Vec3;
+use simulation::ranges::{distance_to_surface, MAX_MINING_DISTANCE};
+use spacetimedb::{rand::Rng, reducer, ReducerContext};
+use spacetimedsl::{dsl, Wrapper};
+
+use crate::{
+    admin::ensure_not_banned,
+    asteroids::{asteroid_obstacle, schedule_asteroid_respawn},
+    cargo::{add_cargo, cargo_space_for},
+    tables::*,
+};
+
+/// How often a ship can extract ore. Clients mine at this rate, the server accepts extractions
+/// slightly early to absorb network jitter.
+const MINING_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Units of ore extracted at once.
+const MINING_YIELD: u32 = 10;
+
+/// Extracts ore from an asteroid into the cargo hold of the ship the player is piloting.
+///
+/// Each extraction yields one of the asteroid's ores, picked according to its share of the
+/// asteroid. Depleted asteroids are removed and replaced later on.
+#[reducer]
+pub fn mine_asteroid(ctx: &ReducerContext, asteroid_id: u64) -> Result<(), String> {
+    ensure_not_banned(ctx)?;
+    let dsl = dsl(ctx);
+
+    let Ok(ship_pilot) = dsl.get_ship_pilot_by_player_id(&PlayerId::new(ctx.sender)) else {
+        return Err("Player is not piloting a ship".into());
+    };
+    let ship_id = ship_pilot.get_ship_id();
+
+    let Ok(mut asteroid) = dsl.get_asteroid_by_id(AsteroidId::new(asteroid_id)) else {
+        return Err("Asteroid not found".into());
+    };
+
+    let ship_location = dsl.get_ship_location_by_ship_id(&ship_id)?;
+    let bounds = asteroid_obstacle(&asteroid);
+    let distance = distance_to_surface(
+        Vec3::new(
+            *ship_location.get_x(),
+            *ship_location.get_y(),
+            *ship_location.get_z(),
+        ),
+        bounds.center,
+        bounds.radius,
+    );
+    if distance > MAX_MINING_DISTANCE {
+        return Err(format!(
+            "Asteroid is too far away to mine ({distance:.0} units)"
+        ));
+    }
+
+    if let Ok(mining) = dsl.get_ship_mining_by_ship_id(&ship_id) {
+        let elapsed = ctx
+            .timestamp
+            .duration_since(*mining.get_last_mined_at())
+            .unwrap_or_default();
+        if elapsed < MINING_INTERVAL.mul_f32(0.9) {
+            return Err("Mining laser is still cycling".into());
+        }
+
+        dsl.delete_ship_mining_by_ship_id(&ship_id)?;
+    }
+
+    let resources = dsl
+        .get_asteroid_resources_by_asteroid_id(asteroid.get_id())
+        .collect::<Vec<_>>();
+    let mut roll = ctx.rng().gen::<f32>();
+    let Some(resource) = resources
+        .iter()
+        .find(|resource| {
+            roll -= resource.get_share();
+            roll < 0.0
+        })
+        .or(resources.last())
+    else {
+        return Err("Asteroid has nothing left to mine".into());
+    };
+
+    let item_type_id = resource.get_item_type_id();
+    let quantity = MINING_YIELD
+        .min(*asteroid.get_remaining())
+        .min(cargo_space_for(ctx, &ship_id, &item_type_id)?);
+    if quantity == 0 {
+        return Err("Cargo hold is full".into());
+    }
+    add_cargo(ctx, &ship_id, &item_type_id, quantity)?;
+
+    // Recorded before the asteroid is updated, as it goes away with a depleted asteroid.
+    dsl.create_ship_mining(&ship_id, asteroid.get_id(), ctx.timestamp)?;
+
+    let remaining = asteroid.get_remaining() - quantity;
+    if remaining == 0 {
+        dsl.delete_asteroid_by_id(asteroid.get_id())?;
+        schedule_asteroid_respawn(ctx);
+    } else {
+        asteroid.set_remaining(remaining);
+        dsl.update_asteroid_by_id(asteroid)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,390 @@
+use std::time::Duration;
+
+use glam::Vec3;
+use simulation::ranges::closest_point_on_segment;
+use spacetimedb::{reducer, table, ReducerContext, ScheduleAt};
+use spacetimedsl::{dsl, Wrapper};
+
+use crate::{
+    admin::ensure_not_banned,
+    damage::damage_ship,
+    sectors::sector_of,
+    tables::*,
+    weapons::{ready_weapon, ReadyWeapon},
+};
+
+/// How often missiles in flight are advanced. Missiles are fast, so this is much shorter than
+/// the other world updates.
+pub const MISSILE_UPDATE_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Fraction of its top speed a missile leaves the launcher at, its motor does the rest.
+const LAUNCH_SPEED_FACTOR: f32 = 0.5;
+
+#[table(name = missile_update, scheduled(world_update_missiles))]
+pub struct MissileUpdate {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+}
+
+impl MissileUpdate {
+    pub fn new(scheduled_at: ScheduleAt) -> Self {
+        Self {
+            scheduled_id: 0,
+            scheduled_at,
+        }
+    }
+}
+
+/// The part of a missile type that drives its flight.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MissileFlight {
+    pub speed: f32,
+    pub acceleration: f32,
+    pub navigation_gain: f32,
+}
+
+/// Advances a missile by `dt` seconds, steering it towards its target with proportional
+/// navigation.
+///
+/// The missile turns proportionally to how fast its line of sight to the target rotates, which
+/// leads a moving target rather than chasing it. Whatever acceleration the motor has left after
+/// turning goes into reaching the missile's top speed. Returns the new position and velocity.
+pub fn steer_missile(
+    position: Vec3,
+    velocity: Vec3,
+    target_position: Vec3,
+    target_velocity: Vec3,
+    flight: MissileFlight,
+    dt: f32,
+) -> (Vec3, Vec3) {
+    let line_of_sight = target_position - position;
+    let relative_velocity = target_velocity - velocity;
+    let line_of_sight_rate =
+        line_of_sight.cross(relative_velocity) / line_of_sight.length_squared().max(f32::EPSILON);
+
+    let turn = (flight.navigation_gain * line_of_sight_rate.cross(velocity))
+        .clamp_length_max(flight.acceleration);
+    let remaining = (flight.acceleration.powi(2) - turn.length_squared())
+        .max(0.0)
+        .sqrt();
+    let heading = velocity
+        .try_normalize()
+        .or_else(|| line_of_sight.try_normalize())
+        .unwrap_or(Vec3::NEG_Z);
+    let boost = ((flight.speed - velocity.length()) / dt).clamp(-remaining, remaining);
+
+    let velocity = (velocity + (turn + heading * boost) * dt).clamp_length_max(flight.speed);
+    (position + velocity * dt, velocity)
+}
+
+/// Launches a missile from one of the launchers of the ship the player is piloting, homing on
+/// another ship in range.
+#[reducer]
+pub fn launch_missile(
+    ctx: &ReducerContext,
+    hardpoint_id: u64,
+    target_ship_id: u64,
+) -> Result<(), String> {
+    ensure_not_banned(ctx)?;
+    let dsl = dsl(ctx);
+
+    let ReadyWeapon {
+        ship_id,
+        hardpoint,
+        weapon_type,
+        damage,
+        range,
+        origin,
+        direction,
+    } = ready_weapon(ctx, hardpoint_id)?;
+    let Ok(missile_type) = dsl.get_missile_type_by_weapon_type_id(weapon_type.get_id()) else {
+        return Err("This weapon does not launch missiles".into());
+    };
+
+    if target_ship_id == ship_id.value() {
+        return Err("Cannot lock on the ship itself".into());
+    }
+    let Ok(target_location) = dsl.get_ship_location_by_ship_id(&ShipId::new(target_ship_id)) else {
+        return Err("Target not found".into());
+    };
+    let target_position = Vec3::new(
+        *target_location.get_x(),
+        *target_location.get_y(),
+        *target_location.get_z(),
+    );
+    let distance = origin.distance(target_position);
+    if distance > range {
+        return Err(format!(
+            "Target is out of range ({distance:.0}m, max {range:.0}m)"
+        ));
+    }
+
+    // The launch is recorded like any other shot, for the cooldown and the muzzle flash.
+    dsl.create_weapon_fire(
+        &ship_id,
+        hardpoint.get_id(),
+        origin.x,
+        origin.y,
+        origin.z,
+        origin.x,
+        origin.y,
+        origin.z,
+        None,
+        ctx.timestamp,
+    )?;
+
+    let velocity = direction * *missile_type.get_speed() * LAUNCH_SPEED_FACTOR;
+    let [sector_x, sector_y, sector_z] = sector_of(origin.x, origin.y, origin.z);
+    dsl.create_missile(
+        weapon_type.get_id(),
+        ship_id.value(),
+        target_ship_id,
+        damage,
+        origin.x,
+        origin.y,
+        origin.z,
+        velocity.x,
+        velocity.y,
+        velocity.z,
+        target_position.x,
+        target_position.y,
+        target_position.z,
+        *missile_type.get_fuel(),
+        sector_x,
+        sector_y,
+        sector_z,
+        ctx.timestamp,
+    )?;
+
+    Ok(())
+}
+
+#[reducer]
+pub fn world_update_missiles(ctx: &ReducerContext, _update: MissileUpdate) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Missiles can only be updated by the server".to_string());
+    }
+
+    let dsl = dsl(ctx);
+
+    for mut missile in dsl.get_all_missiles() {
+        let missile_type = dsl.get_missile_type_by_weapon_type_id(missile.get_weapon_type_id())?;
+        let dt = ctx
+            .timestamp
+            .duration_since(*missile.get_updated_at())
+            .unwrap_or_default()
+            .as_secs_f32();
+        if dt <= 0.0 {
+            continue;
+        }
+
+        let position = Vec3::new(*missile.get_x(), *missile.get_y(), *missile.get_z());
+        let velocity = Vec3::new(
+            *missile.get_vel_x(),
+            *missile.get_vel_y(),
+            *missile.get_vel_z(),
+        );
+        let last_target_position = Vec3::new(
+            *missile.get_target_x(),
+            *missile.get_target_y(),
+            *missile.get_target_z(),
+        );
+
+        // A missile whose target is gone, destroyed or docked, keeps flying straight ahead.
+        let (target_position, target_velocity) =
+            match dsl.get_ship_location_by_ship_id(&ShipId::new(*missile.get_target_ship_id())) {
+                Ok(target_location) => {
+                    let target_position = Vec3::new(
+                        *target_location.get_x(),
+                        *target_location.get_y(),
+                        *target_location.get_z(),
+                    );
+                    (
+                        target_position,
+                        (target_position - last_target_position) / dt,
+                    )
+                }
+                Err(_) => (position + velocity, velocity),
+            };
+
+        let (next_position, next_velocity) = steer_missile(
+            position,
+            velocity,
+            target_position,
+            target_velocity,
+            MissileFlight {
+                speed: *missile_type.get_speed(),
+                acceleration: *missile_type.get_acceleration(),
+                navigation_gain: *missile_type.get_navigation_gain(),
+            },
+            dt,
+        );
+
+        // Test the whole path flown since the last update, a fast missile could otherwise pass
+        // through a small ship between two updates.
+        let mut detonation = None;
+        for ship_location in dsl.get_all_ship_locations() {
+            if ship_location.get_ship_id().value() == *missile.get_launcher_ship_id() {
+                continue;
+            }
+
+            let ship = dsl.get_ship_by_id(&ship_location.get_ship_id())?;
+            let ship_type = dsl.get_ship_type_by_id(ship.get_ship_type_id())?;
+            let center = Vec3::new(
+                *ship_location.get_x(),
+                *ship_location.get_y(),
+                *ship_location.get_z(),
+            );
+            let closest = closest_point_on_segment(position, next_position, center);
+            if closest.distance(center)
+                <= ship_type.get_radius() + missile_type.get_proximity_radius()
+            {
+                detonation = Some(closest);
+                break;
+            }
+        }
+
+        let fuel = missile.get_fuel() - dt;
+        if detonation.is_none() && fuel <= 0.0 {
+            detonation = Some(next_position);
+        }
+
+        if let Some(point) = detonation {
+            detonate(ctx, &missile, &missile_type, point)?;
+            continue;
+        }
+
+        let [sector_x, sector_y, sector_z] =
+            sector_of(next_position.x, next_position.y, next_position.z);
+        missile.set_x(next_position.x);
+        missile.set_y(next_position.y);
+        missile.set_z(next_position.z);
+        missile.set_vel_x(next_velocity.x);
+        missile.set_vel_y(next_velocity.y);
+        missile.set_vel_z(next_velocity.z);
+        missile.set_target_x(target_position.x);
+        missile.set_target_y(target_position.y);
+        missile.set_target_z(target_position.z);
+        missile.set_fuel(fuel);
+        missile.set_sector_x(sector_x);
+        missile.set_sector_y(sector_y);
+        missile.set_sector_z(sector_z);
+        missile.set_updated_at(ctx.timestamp);
+        dsl.update_missile_by_id(missile)?;
+    }
+
+    Ok(())
+}
+
+/// Blows a missile up at `point`, damaging every ship caught in the blast.
+///
+/// Ships take the full damage of the missile when it goes off against their hull, and less the
+/// further their hull is from the blast.
+fn detonate(
+    ctx: &ReducerContext,
+    missile: &Missile,
+    missile_type: &MissileType,
+    point: Vec3,
+) -> Result<(), String> {
+    let dsl = dsl(ctx);
+
+    let weapon_type = dsl.get_weapon_type_by_id(missile.get_weapon_type_id())?;
+    let blast_radius = *missile_type.get_blast_radius();
+
+    // Ships destroyed by the blast are removed as it goes, so take them all in first.
+    let ship_locations = dsl.get_all_ship_locations().collect::<Vec<_>>();
+    for ship_location in ship_locations {
+        let ship_id = ship_location.get_ship_id();
+        let ship = dsl.get_ship_by_id(&ship_id)?;
+        let ship_type = dsl.get_ship_type_by_id(ship.get_ship_type_id())?;
+        let center = Vec3::new(
+            *ship_location.get_x(),
+            *ship_location.get_y(),
+            *ship_location.get_z(),
+        );
+
+        let hull_distance = (point.distance(center) - ship_type.get_radius()).max(0.0);
+        if hull_distance > blast_radius {
+            continue;
+        }
+
+        let falloff = 1.0 - hull_distance / blast_radius;
+        damage_ship(
+            ctx,
+            &ship_id,
+            missile.get_damage() * falloff,
+            *weapon_type.get_damage_type(),
+        )?;
+    }
+
+    dsl.delete_missile_by_id(missile.get_id())?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FLIGHT: MissileFlight = MissileFlight {
+        speed: 100.0,
+        acceleration: 50.0,
+        navigation_gain: 3.0,
+    };
+
+    const DT: f32 = 0.05;
+
+    /// A missile at top speed, heading down -Z from the origin.
+    const VELOCITY: Vec3 = Vec3::new(0.0, 0.0, -100.0);
+
+    #[test]
+    fn head_on_target_is_flown_straight_at() {
+        let target = Vec3::new(0.0, 0.0, -1000.0);
+        let (position, velocity) =
+            steer_missile(Vec3::ZERO, VELOCITY, target, Vec3::Z * 50.0, FLIGHT, DT);
+
+        assert!(velocity.abs_diff_eq(VELOCITY, 1e-4));
+        assert!(position.abs_diff_eq(VELOCITY * DT, 1e-4));
+    }
+
+    #[test]
+    fn crossing_target_is_led() {
+        let target = Vec3::new(0.0, 0.0, -1000.0);
+        let (_, velocity) = steer_missile(Vec3::ZERO, VELOCITY, target, Vec3::X * 50.0, FLIGHT, DT);
+
+        // The line of sight turns at 0.05 rad/s, so the missile turns towards +X at
+        // 3 * 0.05 * 100 = 15 units/s², ahead of the target rather than at it.
+        assert!((velocity.x - 15.0 * DT).abs() < 1e-3);
+        assert!(velocity.y.abs() < 1e-6);
+        assert!(velocity.length() <= FLIGHT.speed + 1e-3);
+    }
+
+    #[test]
+    fn turn_is_limited_by_acceleration() {
+        let flight = MissileFlight {
+            navigation_gain: 1000.0,
+            ..FLIGHT
+        };
+        let target = Vec3::new(0.0, 0.0, -1000.0);
+        let (_, velocity) = steer_missile(Vec3::ZERO, VELOCITY, target, Vec3::X * 50.0, flight, DT);
+
+        assert!(velocity.x > 0.0);
+        assert!(velocity.x <= flight.acceleration * DT + 1e-4);
+    }
+
+    #[test]
+    fn zero_closing_velocity_stays_finite() {
+        let target = Vec3::new(0.0, 0.0, -1000.0);
+        let (position, velocity) =
+            steer_missile(Vec3::ZERO, VELOCITY, target, VELOCITY, FLIGHT, DT);
+        assert!(position.is_finite() && velocity.is_finite());
+        assert!(velocity.abs_diff_eq(VELOCITY, 1e-4));
+
+        // A missile at rest on top of a target at rest has no line of sight, nor heading.
+        let (position, velocity) =
+            steer_missile(Vec3::ZERO, Vec3::ZERO, Vec3::ZERO, Vec3::ZERO, FLIGHT, DT);
+        assert!(position.is_finite() && velocity.is_finite());
+    }
+}
@@ -0,0 +1,201 @@
+use std::time::Duration;
+
+use glam::Vec3;
+use simulation::{flight::EMPTY_TANK_RCS_FACTOR, ranges::check_range};
+
+use crate::tables::{ShipLocation, ShipType};
+
+/// The lowest frame rate we expect a client to run at. `apply_movement` on the client scales the
+/// forces it applies by the frame time, so the slower the client, the faster the ship can go.
+const MIN_CLIENT_FPS: f32 = 20.0;
+
+/// Multiplier applied to the maximum distance a ship can travel between two updates, to absorb
+/// network jitter and updates being batched together.
+const DISTANCE_TOLERANCE: f32 = 1.25;
+
+/// Distance, in world units, a ship is always allowed to move regardless of the elapsed time.
+const DISTANCE_SLACK: f32 = 5.0;
+
+/// Top speed of a player on foot, in units per second.
+///
+/// Mirrors the flying speed of the client's `local_player/flycam.rs`.
+pub const MAX_PLAYER_SPEED: f32 = 400.0;
+
+/// How far the length of a rotation quaternion may drift from 1 before it's rejected.
+const QUATERNION_TOLERANCE: f32 = 1e-3;
+
+/// Returns the terminal velocity of a ship of the given type pushed by `thrust`, in units per
+/// second, on a client running at `MIN_CLIENT_FPS`.
+fn terminal_speed(ship_type: &ShipType, thrust: f32) -> f32 {
+    simulation::flight::terminal_speed(
+        thrust,
+        *ship_type.get_mass(),
+        *ship_type.get_linear_damping(),
+        1.0 / MIN_CLIENT_FPS,
+    )
+}
+
+/// Returns the top speeds of a ship of the given type, cruising and boosting, in units per
+/// second. A ship with an empty tank is only pushed by its RCS thrusters, and can't boost.
+pub fn max_ship_speeds(ship_type: &ShipType, fueled: bool) -> (f32, f32) {
+    let rcs = ship_type.get_vertical_thrust().powi(2) + ship_type.get_lateral_thrust().powi(2);
+    if !fueled {
+        let speed = terminal_speed(ship_type, rcs.sqrt() * EMPTY_TANK_RCS_FACTOR);
+        return (speed, speed);
+    }
+
+    let cruise = terminal_speed(ship_type, (ship_type.get_thrust().powi(2) + rcs).sqrt());
+    let boost = terminal_speed(
+        ship_type,
+        ((ship_type.get_thrust() * ship_type.get_boost_factor()).powi(2) + rcs).sqrt(),
+    );
+    (cruise, boost.max(cruise))
+}
+
+/// Farthest something going at up to `speed` may be trusted to have moved in `elapsed`.
+pub fn max_move_distance(speed: f32, elapsed: Duration) -> f32 {
+    speed * elapsed.as_secs_f32() * DISTANCE_TOLERANCE + DISTANCE_SLACK
+}
+
+/// Checks that a position and rotation sent by a client for a ship are finite, and that the
+/// rotation is a unit quaternion.
+pub fn validate_ship_transform(position: [f32; 3], rotation: [f32; 4]) -> Result<(), String> {
+    if position
+        .iter()
+        .chain(rotation.iter())
+        .any(|v| !v.is_finite())
+    {
+        return Err("Ship location contains non-finite values".into());
+    }
+
+    let rotation_length = rotation.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if (rotation_length - 1.0).abs() > QUATERNION_TOLERANCE {
+        return Err(format!(
+            "Ship rotation is not normalized (length {rotation_length})"
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks that a ship going at up to `max_speed` could have moved from `previous` to `next` in
+/// `elapsed`. Returns the distance it moved.
+pub fn validate_ship_move(
+    previous: &ShipLocation,
+    next: &ShipLocation,
+    elapsed: Duration,
+    max_speed: f32,
+) -> Result<f32, String> {
+    let position = [*next.get_x(), *next.get_y(), *next.get_z()];
+    validate_ship_transform(
+        position,
+        [
+            *next.get_rot_x(),
+            *next.get_rot_y(),
+            *next.get_rot_z(),
+            *next.get_rot_w(),
+        ],
+    )?;
+
+    let max_distance = max_move_distance(max_speed, elapsed);
+    check_range(
+        Vec3::from_array(position),
+        Vec3::new(*previous.get_x(), *previous.get_y(), *previous.get_z()),
+        max_distance,
+    )
+    .map_err(|distance| {
+        format!(
+            "Ship moved {distance:.1} units in {:.3}s, more than the allowed {max_distance:.1}",
+            elapsed.as_secs_f32()
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use spacetimedb::Timestamp;
+    use spacetimedsl::Wrapper;
+
+    use super::*;
+    use crate::tables::ShipId;
+
+    /// 100 units per second: with the tolerance and the slack, up to 130 units in a second.
+    const SPEED: f32 = 100.0;
+
+    const UPRIGHT: [f32; 4] = [0.0, 0.0, 0.0, 1.0];
+
+    fn location(position: Vec3, rotation: [f32; 4]) -> ShipLocation {
+        let [rot_x, rot_y, rot_z, rot_w] = rotation;
+        ShipLocation::new(
+            ShipId::new(1),
+            position.x,
+            position.y,
+            position.z,
+            rot_x,
+            rot_y,
+            rot_z,
+            rot_w,
+            Timestamp::UNIX_EPOCH,
+        )
+    }
+
+    fn validate(next: Vec3, rotation: [f32; 4], elapsed: Duration) -> Result<f32, String> {
+        validate_ship_move(
+            &location(Vec3::ZERO, UPRIGHT),
+            &location(next, rotation),
+            elapsed,
+            SPEED,
+        )
+    }
+
+    #[test]
+    fn move_within_reach_is_accepted() {
+        assert_eq!(
+            validate(Vec3::X * 100.0, UPRIGHT, Duration::from_secs(1)),
+            Ok(100.0)
+        );
+        assert!(validate(Vec3::Y * 129.0, UPRIGHT, Duration::from_secs(1)).is_ok());
+    }
+
+    #[test]
+    fn move_beyond_reach_is_refused() {
+        assert!(validate(Vec3::Y * 131.0, UPRIGHT, Duration::from_secs(1)).is_err());
+        assert!(validate(Vec3::Y * 129.0, UPRIGHT, Duration::from_millis(500)).is_err());
+    }
+
+    #[test]
+    fn non_finite_move_is_refused() {
+        let elapsed = Duration::from_secs(1);
+        assert!(validate(Vec3::new(f32::NAN, 0.0, 0.0), UPRIGHT, elapsed).is_err());
+        assert!(validate(Vec3::new(0.0, f32::INFINITY, 0.0), UPRIGHT, elapsed).is_err());
+        assert!(validate(Vec3::ZERO, [0.0, 0.0, f32::NAN, 1.0], elapsed).is_err());
+    }
+
+    #[test]
+    fn rotation_must_be_a_unit_quaternion() {
+        let elapsed = Duration::from_secs(1);
+        assert!(validate(Vec3::ZERO, [0.0, 0.0, 0.0, 2.0], elapsed).is_err());
+        assert!(validate(Vec3::ZERO, [0.0, 0.0, 0.0, 0.0], elapsed).is_err());
+        assert!(validate(Vec3::ZERO, [0.0, 0.0, 0.0, 1.0005], elapsed).is_ok());
+        let half_turn = std::f32::consts::FRAC_1_SQRT_2;
+        assert!(validate(Vec3::ZERO, [0.0, half_turn, 0.0, half_turn], elapsed).is_ok());
+    }
+
+    #[test]
+    fn first_move_only_gets_the_slack() {
+        // A location updated at the same time, or seemingly later, leaves no time to move in.
+        assert_eq!(max_move_distance(SPEED, Duration::ZERO), DISTANCE_SLACK);
+        assert!(validate(Vec3::X * DISTANCE_SLACK, UPRIGHT, Duration::ZERO).is_ok());
+        assert!(validate(Vec3::X * (DISTANCE_SLACK + 1.0), UPRIGHT, Duration::ZERO).is_err());
+    }
+
+    #[test]
+    fn reach_grows_with_speed_and_time() {
+        assert_eq!(max_move_distance(SPEED, Duration::from_secs(1)), 130.0);
+        assert_eq!(
+            max_move_distance(SPEED * 2.0, Duration::from_secs(1)),
+            255.0
+        );
+        assert_eq!(max_move_distance(SPEED, Duration::from_secs(2)), 255.0);
+    }
+}
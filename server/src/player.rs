@@ -1,13 +1,80 @@
-use spacetimedb::{reducer, ReducerContext};
+use std::fmt::Display;
+
+use glam::{Quat, Vec3};
+use simulation::ranges::{
+    check_range, ship_spawn_distance, MAX_ENTER_SHIP_DISTANCE, STATION_BOUNDING_RADIUS,
+};
+use spacetimedb::{reducer, Identity, ReducerContext};
 use spacetimedsl::{dsl, Wrapper};
 
-use crate::tables::*;
+use crate::{
+    admin::ensure_not_banned,
+    asteroids::asteroid_obstacle,
+    docking::docked_ship_transform,
+    egress::{find_egress, Obstacle},
+    factions::can_pilot_faction_ship,
+    fuel::burn_fuel,
+    movement::{
+        max_move_distance, max_ship_speeds, validate_ship_move, validate_ship_transform,
+        MAX_PLAYER_SPEED,
+    },
+    rate_limit::rate_limited,
+    sectors::sector_of,
+    ships::spawn_ship,
+    tables::*,
+};
+
+/// How much farther than `ship_spawn_distance`, in world units, a ship may be spawned from the
+/// player, who may have moved since their location was last sent.
+const SPAWN_SHIP_TOLERANCE: f32 = 50.0;
+
+/// Reasons a player can be refused entry into a ship, sent back to the client as the reducer's
+/// error message.
+#[derive(Debug)]
+pub enum EnterShipError {
+    NotOnFoot,
+    ShipNotFound,
+    OutOfRange(f32),
+    AlreadyPiloted,
+    NotAllowed,
+}
+
+impl Display for EnterShipError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotOnFoot => write!(f, "You must be on foot to enter a ship"),
+            Self::ShipNotFound => write!(f, "This ship does not exist"),
+            Self::OutOfRange(distance) => write!(
+                f,
+                "This ship is too far away ({distance:.0}m, max {MAX_ENTER_SHIP_DISTANCE:.0}m)"
+            ),
+            Self::AlreadyPiloted => write!(f, "This ship already has a pilot"),
+            Self::NotAllowed => write!(f, "You are not allowed to pilot this ship"),
+        }
+    }
+}
+
+impl From<EnterShipError> for String {
+    fn from(error: EnterShipError) -> Self {
+        error.to_string()
+    }
+}
 
 #[reducer]
 fn player_ready(ctx: &ReducerContext) -> Result<(), String> {
+    ensure_not_banned(ctx)?;
     let dsl = dsl(ctx);
 
     let player = dsl.get_player_by_id(&PlayerId::new(ctx.sender))?;
+    if dsl
+        .get_player_location_by_player_id(player.get_id())
+        .is_ok()
+    {
+        return Ok(());
+    }
+
+    let [sector_x, sector_y, sector_z] =
+        sector_of(*player.get_x(), *player.get_y(), *player.get_z());
     dsl.create_player_location(
         player.get_id(),
         *player.get_x(),
@@ -17,6 +84,10 @@ fn player_ready(ctx: &ReducerContext) -> Result<(), String> {
         *player.get_rot_y(),
         *player.get_rot_z(),
         *player.get_rot_w(),
+        sector_x,
+        sector_y,
+        sector_z,
+        ctx.timestamp,
     )?;
 
     Ok(())
@@ -25,6 +96,7 @@ fn player_ready(ctx: &ReducerContext) -> Result<(), String> {
 #[reducer]
 fn player_spawn_ship(
     ctx: &ReducerContext,
+    ship_type_id: u64,
     x: f32,
     y: f32,
     z: f32,
@@ -33,50 +105,243 @@ fn player_spawn_ship(
     rot_z: f32,
     rot_w: f32,
 ) -> Result<(), String> {
+    rate_limited(ctx, "player_spawn_ship", || {
+        let dsl = dsl(ctx);
+
+        validate_ship_transform([x, y, z], [rot_x, rot_y, rot_z, rot_w])?;
+        let Ok(location) = dsl.get_player_location_by_player_id(&PlayerId::new(ctx.sender)) else {
+            return Err("You must be on foot to spawn a ship".into());
+        };
+
+        let Ok(ship_type) = dsl.get_ship_type_by_id(ShipTypeId::new(ship_type_id)) else {
+            return Err(format!("Unknown ship type: {ship_type_id}"));
+        };
+        if *ship_type.get_retired() {
+            return Err(format!(
+                "Ship type {} is retired and can no longer be spawned",
+                ship_type.get_name()
+            ));
+        }
+
+        // Ships are spawned in front of the player, see `ship_spawn_distance`.
+        let exit_offset = Vec3::new(
+            *ship_type.get_exit_offset_x(),
+            *ship_type.get_exit_offset_y(),
+            *ship_type.get_exit_offset_z(),
+        );
+        let max_distance = ship_spawn_distance(exit_offset) + SPAWN_SHIP_TOLERANCE;
+        check_range(
+            Vec3::new(x, y, z),
+            Vec3::new(*location.get_x(), *location.get_y(), *location.get_z()),
+            max_distance,
+        )
+        .map_err(|distance| {
+            format!(
+                "Ship spawned {distance:.1} units away from the player, more than the allowed {max_distance:.1}"
+            )
+        })?;
+
+        spawn_ship(
+            ctx,
+            &ship_type,
+            &PlayerId::new(ctx.sender),
+            Vec3::new(x, y, z),
+            Quat::from_xyzw(rot_x, rot_y, rot_z, rot_w),
+        )?;
+
+        Ok(())
+    })
+}
+
+#[reducer]
+fn player_move(
+    ctx: &ReducerContext,
+    x: f32,
+    y: f32,
+    z: f32,
+    rot_x: f32,
+    rot_y: f32,
+    rot_z: f32,
+    rot_w: f32,
+) -> Result<(), String> {
+    rate_limited(ctx, "player_move", || {
+        let dsl = dsl(ctx);
+
+        if [x, y, z, rot_x, rot_y, rot_z, rot_w]
+            .iter()
+            .any(|v| !v.is_finite())
+        {
+            return Err("Player location contains non-finite values".into());
+        }
+
+        let mut location = dsl.get_player_location_by_player_id(&PlayerId::new(ctx.sender))?;
+        let elapsed = ctx
+            .timestamp
+            .duration_since(*location.get_updated_at())
+            .unwrap_or_default();
+        let max_distance = max_move_distance(MAX_PLAYER_SPEED, elapsed);
+        check_range(
+            Vec3::new(x, y, z),
+            Vec3::new(*location.get_x(), *location.get_y(), *location.get_z()),
+            max_distance,
+        )
+        .map_err(|moved| {
+            format!(
+                "Player moved {moved:.1} units in {:.3}s, more than the allowed {max_distance:.1}",
+                elapsed.as_secs_f32()
+            )
+        })?;
+
+        location.set_x(x);
+        location.set_y(y);
+        location.set_z(z);
+        location.set_rot_x(rot_x);
+        location.set_rot_y(rot_y);
+        location.set_rot_z(rot_z);
+        location.set_rot_w(rot_w);
+
+        let [sector_x, sector_y, sector_z] = sector_of(x, y, z);
+        location.set_sector_x(sector_x);
+        location.set_sector_y(sector_y);
+        location.set_sector_z(sector_z);
+        location.set_updated_at(ctx.timestamp);
+        dsl.update_player_location_by_player_id(location)?;
+
+        Ok(())
+    })
+}
+
+#[reducer]
+fn player_enter_ship(ctx: &ReducerContext, ship_id: u64) -> Result<(), String> {
+    rate_limited(ctx, "player_enter_ship", || {
+        let dsl = dsl(ctx);
+
+        let player_id = PlayerId::new(ctx.sender);
+        let player_location = dsl
+            .get_player_location_by_player_id(&player_id)
+            .map_err(|_| EnterShipError::NotOnFoot)?;
+        let ship = dsl
+            .get_ship_by_id(ShipId::new(ship_id))
+            .map_err(|_| EnterShipError::ShipNotFound)?;
+        let ship_position = match dsl.get_ship_location_by_ship_id(ship.get_id()) {
+            Ok(ship_location) => [
+                *ship_location.get_x(),
+                *ship_location.get_y(),
+                *ship_location.get_z(),
+            ],
+            Err(_) => docked_ship_transform(ctx, &ship.get_id())
+                .ok_or(EnterShipError::ShipNotFound)?
+                .0
+                .to_array(),
+        };
+
+        check_range(
+            Vec3::new(
+                *player_location.get_x(),
+                *player_location.get_y(),
+                *player_location.get_z(),
+            ),
+            Vec3::from_array(ship_position),
+            MAX_ENTER_SHIP_DISTANCE,
+        )
+        .map_err(EnterShipError::OutOfRange)?;
+
+        if dsl.get_ship_pilot_by_ship_id(ship.get_id()).is_ok() {
+            return Err(EnterShipError::AlreadyPiloted.into());
+        }
+
+        if !can_pilot_ship(ctx, &ship, &player_id) {
+            return Err(EnterShipError::NotAllowed.into());
+        }
+
+        dsl.create_ship_pilot(ship.get_id(), &player_id)?;
+        dsl.delete_player_location_by_player_id(player_id)?;
+
+        Ok(())
+    })
+}
+
+#[reducer]
+fn player_grant_ship_access(
+    ctx: &ReducerContext,
+    ship_id: u64,
+    player_id: Identity,
+) -> Result<(), String> {
+    ensure_not_banned(ctx)?;
     let dsl = dsl(ctx);
 
-    // Create the ship and its location
-    let player_id = PlayerId::new(ctx.sender);
-    let ship = dsl.create_ship(1, &player_id)?;
-    dsl.create_ship_location(ship.get_id(), x, y, z, rot_x, rot_y, rot_z, rot_w)?;
+    let ship = dsl.get_ship_by_id(ShipId::new(ship_id))?;
+    if !owns_ship(&ship, &PlayerId::new(ctx.sender)) {
+        return Err("Only the owner of a ship can grant access to it".into());
+    }
+
+    let player_id = dsl.get_player_by_id(&PlayerId::new(player_id))?.get_id();
+    if can_pilot_ship(ctx, &ship, &player_id) {
+        return Ok(());
+    }
+
+    dsl.create_ship_access(ship.get_id(), &player_id)?;
 
     Ok(())
 }
 
 #[reducer]
-fn player_enter_ship(ctx: &ReducerContext, ship_id: u64) -> Result<(), String> {
+fn player_revoke_ship_access(
+    ctx: &ReducerContext,
+    ship_id: u64,
+    player_id: Identity,
+) -> Result<(), String> {
+    ensure_not_banned(ctx)?;
     let dsl = dsl(ctx);
 
-    let player_id = PlayerId::new(ctx.sender);
-    // TODO: Check if the player is in range of the ship
     let ship = dsl.get_ship_by_id(ShipId::new(ship_id))?;
-    dsl.delete_ship_pilot_by_player_id(&player_id).ok();
-    dsl.create_ship_pilot(ship.get_id(), &player_id)?;
+    if !owns_ship(&ship, &PlayerId::new(ctx.sender)) {
+        return Err("Only the owner of a ship can revoke access to it".into());
+    }
 
-    dsl.delete_player_location_by_player_id(player_id)?;
+    for access in dsl.get_ship_accesses_by_ship_id(ship.get_id()) {
+        if access.get_player_id().value() == player_id {
+            dsl.delete_ship_access_by_id(access.get_id())?;
+        }
+    }
 
     Ok(())
 }
 
 #[reducer]
 fn player_leave_ship(ctx: &ReducerContext) -> Result<(), String> {
+    ensure_not_banned(ctx)?;
+    leave_ship(ctx, &PlayerId::new(ctx.sender))
+}
+
+/// Gets a player out of the ship they're piloting, on foot next to it.
+pub fn leave_ship(ctx: &ReducerContext, player_id: &PlayerId) -> Result<(), String> {
     let dsl = dsl(ctx);
-    let player_id = &PlayerId::new(ctx.sender);
     let ship_pilot = dsl.get_ship_pilot_by_player_id(player_id)?;
-    let ship = dsl.get_ship_location_by_ship_id(ship_pilot.get_ship_id())?;
+    let (position, rotation) = ship_egress(ctx, ship_pilot.get_ship_id())?;
 
     dsl.delete_ship_pilot_by_player_id(player_id)?;
-    // TODO: Properly find a safe position to spawn the player at
-    // See: https://github.com/JulienLavocat/Nova9/issues/3
+    // The next pilot picks how the ship is flown.
+    if dsl
+        .get_ship_flight_by_ship_id(&ship_pilot.get_ship_id())
+        .is_ok()
+    {
+        dsl.delete_ship_flight_by_ship_id(&ship_pilot.get_ship_id())?;
+    }
+    let [sector_x, sector_y, sector_z] = sector_of(position.x, position.y, position.z);
     dsl.create_player_location(
         player_id,
-        *ship.get_x(),
-        *ship.get_y(),
-        *ship.get_z(),
-        *ship.get_rot_x(),
-        *ship.get_rot_y(),
-        *ship.get_rot_z(),
-        *ship.get_rot_w(),
+        position.x,
+        position.y,
+        position.z,
+        rotation.x,
+        rotation.y,
+        rotation.z,
+        rotation.w,
+        sector_x,
+        sector_y,
+        sector_z,
+        ctx.timestamp,
     )?;
 
     Ok(())
@@ -93,12 +358,25 @@ fn player_move_ship(
     rot_z: f32,
     rot_w: f32,
 ) -> Result<(), String> {
-    let dsl = dsl(ctx);
+    rate_limited(ctx, "player_move_ship", || {
+        let dsl = dsl(ctx);
+
+        let Ok(ship_pilot) = dsl.get_ship_pilot_by_player_id(&PlayerId::new(ctx.sender)) else {
+            return Err("Player is not piloting a ship".into());
+        };
+
+        if dsl
+            .get_ship_flight_by_ship_id(&ship_pilot.get_ship_id())
+            .is_ok()
+        {
+            return Err("Ship is flown by the server, send flight controls instead".into());
+        }
 
-    if let Ok(ship) = dsl.get_ship_pilot_by_player_id(&PlayerId::new(ctx.sender)) {
-        // TODO: Validate the new position and rotation
-        dsl.update_ship_location_by_ship_id(ShipLocation::new(
-            ship.get_ship_id(),
+        let ship = dsl.get_ship_by_id(ship_pilot.get_ship_id())?;
+        let ship_type = dsl.get_ship_type_by_id(ship.get_ship_type_id())?;
+        let previous = dsl.get_ship_location_by_ship_id(ship_pilot.get_ship_id())?;
+        let next = ShipLocation::new(
+            ship_pilot.get_ship_id(),
             x,
             y,
             z,
@@ -106,10 +384,88 @@ fn player_move_ship(
             rot_y,
             rot_z,
             rot_w,
-        ))
-        .unwrap();
-        return Ok(());
+            ctx.timestamp,
+        );
+
+        let elapsed = ctx
+            .timestamp
+            .duration_since(*previous.get_updated_at())
+            .unwrap_or_default();
+        let status = dsl.get_ship_status_by_ship_id(&ship_pilot.get_ship_id())?;
+        let (cruise_speed, boost_speed) = max_ship_speeds(&ship_type, *status.get_fuel() > 0.0);
+        let moved = validate_ship_move(&previous, &next, elapsed, boost_speed)?;
+
+        // Moving faster than the ship can cruise takes boosting.
+        let boosting = moved > max_move_distance(cruise_speed, elapsed);
+        burn_fuel(ctx, &ship_type, status, moved, elapsed, boosting)?;
+
+        dsl.update_ship_location_by_ship_id(next)?;
+
+        Ok(())
+    })
+}
+
+/// Whether the player owns the ship and hasn't handed it over to a faction. Owners of ships
+/// handed over keep no say in them, the faction ranks decide.
+pub fn owns_ship(ship: &Ship, player_id: &PlayerId) -> bool {
+    ship.get_faction_id().is_none() && ship.get_owner_id() == *player_id
+}
+
+/// Whether the player owns the ship, has been granted access to it by its owner, or is ranked
+/// high enough in the faction it was handed over to.
+fn can_pilot_ship(ctx: &ReducerContext, ship: &Ship, player_id: &PlayerId) -> bool {
+    if owns_ship(ship, player_id) {
+        return true;
     }
 
-    Err("Player is not piloting a ship".into())
+    dsl(ctx)
+        .get_ship_accesses_by_ship_id(ship.get_id())
+        .any(|access| access.get_player_id() == *player_id)
+        || can_pilot_faction_ship(ctx, ship, player_id)
+}
+
+/// Computes where the pilot of a ship should be placed when getting out of it.
+pub fn ship_egress(ctx: &ReducerContext, ship_id: ShipId) -> Result<(Vec3, Quat), String> {
+    let dsl = dsl(ctx);
+    let ship = dsl.get_ship_by_id(&ship_id)?;
+    let ship_type = dsl.get_ship_type_by_id(ship.get_ship_type_id())?;
+
+    // Docked ships have no location, their pilot gets out next to the docking bay instead.
+    let (position, rotation) = match dsl.get_ship_location_by_ship_id(&ship_id) {
+        Ok(ship_location) => (
+            Vec3::new(
+                *ship_location.get_x(),
+                *ship_location.get_y(),
+                *ship_location.get_z(),
+            ),
+            Quat::from_xyzw(
+                *ship_location.get_rot_x(),
+                *ship_location.get_rot_y(),
+                *ship_location.get_rot_z(),
+                *ship_location.get_rot_w(),
+            )
+            .normalize(),
+        ),
+        Err(err) => docked_ship_transform(ctx, &ship_id).ok_or(err)?,
+    };
+
+    let obstacles = dsl
+        .get_all_asteroids()
+        .map(|asteroid| asteroid_obstacle(&asteroid))
+        .chain(dsl.get_all_stations().map(|station| Obstacle {
+            center: Vec3::new(*station.get_x(), *station.get_y(), *station.get_z()),
+            radius: STATION_BOUNDING_RADIUS,
+        }))
+        .collect::<Vec<_>>();
+
+    Ok(find_egress(
+        position,
+        rotation,
+        Vec3::new(
+            *ship_type.get_exit_offset_x(),
+            *ship_type.get_exit_offset_y(),
+            *ship_type.get_exit_offset_z(),
+        ),
+        &obstacles,
+    ))
 }
@@ -0,0 +1,217 @@
+use std::time::Duration;
+
+use log::debug;
+use spacetimedb::ReducerContext;
+use spacetimedsl::{dsl, Wrapper};
+
+use crate::{admin::ensure_not_banned, tables::*};
+
+/// How often a reducer may be called: bursts of up to `capacity` calls, then `refill_rate`
+/// calls per second.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Limit {
+    pub capacity: f32,
+    pub refill_rate: f32,
+}
+
+/// Limits of the rate-limited reducers, unless an admin set another one, see
+/// `admin::admin_set_rate_limit`.
+///
+/// Clients send their moves, or the controls of ships flown by the server, 10 times per second.
+const DEFAULT_RATE_LIMITS: [(&str, Limit); 5] = [
+    (
+        "player_move",
+        Limit {
+            capacity: 20.0,
+            refill_rate: 15.0,
+        },
+    ),
+    (
+        "player_move_ship",
+        Limit {
+            capacity: 20.0,
+            refill_rate: 15.0,
+        },
+    ),
+    (
+        "player_set_flight_controls",
+        Limit {
+            capacity: 20.0,
+            refill_rate: 15.0,
+        },
+    ),
+    (
+        "player_enter_ship",
+        Limit {
+            capacity: 5.0,
+            refill_rate: 1.0,
+        },
+    ),
+    (
+        "player_spawn_ship",
+        Limit {
+            capacity: 3.0,
+            refill_rate: 1.0 / 30.0,
+        },
+    ),
+];
+
+/// Whether calls to a reducer are rate limited, see `rate_limited`.
+pub fn is_rate_limited(reducer: &str) -> bool {
+    DEFAULT_RATE_LIMITS.iter().any(|(name, _)| *name == reducer)
+}
+
+/// The limit currently applying to a reducer, if it's rate limited.
+fn limit_of(ctx: &ReducerContext, reducer: &str) -> Option<Limit> {
+    if let Ok(rate_limit) = dsl(ctx).get_rate_limit_by_reducer(reducer) {
+        return Some(Limit {
+            capacity: *rate_limit.get_capacity(),
+            refill_rate: *rate_limit.get_refill_rate(),
+        });
+    }
+
+    DEFAULT_RATE_LIMITS
+        .iter()
+        .find(|(name, _)| *name == reducer)
+        .map(|(_, limit)| *limit)
+}
+
+/// Refills a bucket that held `tokens` for the time `elapsed` since, and takes a token from it.
+///
+/// Returns the tokens left, or how long to wait for a token when the bucket is empty.
+pub fn take_token(limit: Limit, tokens: f32, elapsed: Duration) -> Result<f32, Duration> {
+    let tokens = (tokens + limit.refill_rate * elapsed.as_secs_f32()).min(limit.capacity);
+    if tokens >= 1.0 {
+        return Ok(tokens - 1.0);
+    }
+
+    Err(Duration::try_from_secs_f32((1.0 - tokens) / limit.refill_rate).unwrap_or(Duration::MAX))
+}
+
+/// Runs the body of a rate-limited reducer once the caller has been charged a token for the call.
+///
+/// Every identity gets a bucket per rate-limited reducer, starting full so short bursts go
+/// through. Rate-limited reducers run their whole body through this, with their own name, and
+/// banned players are refused them like other gameplay reducers, see
+/// `admin::ensure_not_banned`.
+///
+/// A call refused for its empty bucket succeeds without running `body`, recording why it was
+/// refused in `rejected_call` for the caller to see. A failing `body` fails the call as usual, and
+/// as reducers are transactions, everything it wrote is rolled back along with the token.
+pub fn rate_limited(
+    ctx: &ReducerContext,
+    reducer: &str,
+    body: impl FnOnce() -> Result<(), String>,
+) -> Result<(), String> {
+    ensure_not_banned(ctx)?;
+    if let Err(reason) = check_rate_limit(ctx, reducer) {
+        debug!("Refused call to {reducer} from {}: {reason}", ctx.sender);
+        let dsl = dsl(ctx);
+        let player_id = PlayerId::new(ctx.sender);
+        // Only the last refusal is kept, clients only need to hear about the new ones.
+        dsl.delete_rejected_calls_by_player_id(&player_id)?;
+        dsl.create_rejected_call(&player_id, reducer, &reason, ctx.timestamp)?;
+        return Ok(());
+    }
+
+    body()
+}
+
+/// Takes a token from the caller's bucket for a reducer, refusing the call if it's empty.
+fn check_rate_limit(ctx: &ReducerContext, reducer: &str) -> Result<(), String> {
+    let Some(limit) = limit_of(ctx, reducer) else {
+        return Ok(());
+    };
+    let dsl = dsl(ctx);
+
+    let player_id = PlayerId::new(ctx.sender);
+    let bucket = dsl
+        .get_rate_limit_buckets_by_player_id(&player_id)
+        .find(|bucket| bucket.get_reducer() == reducer);
+    let (tokens, elapsed) = match &bucket {
+        Some(bucket) => (
+            *bucket.get_tokens(),
+            ctx.timestamp
+                .duration_since(*bucket.get_updated_at())
+                .unwrap_or_default(),
+        ),
+        None => (limit.capacity, Duration::ZERO),
+    };
+
+    let tokens = take_token(limit, tokens, elapsed).map_err(|wait| {
+        format!(
+            "Too many calls to {reducer}, try again in {:.1}s",
+            wait.as_secs_f32()
+        )
+    })?;
+
+    match bucket {
+        Some(mut bucket) => {
+            bucket.set_tokens(tokens);
+            bucket.set_updated_at(ctx.timestamp);
+            dsl.update_rate_limit_bucket_by_id(bucket)?;
+        }
+        None => {
+            dsl.create_rate_limit_bucket(&player_id, reducer, tokens, ctx.timestamp)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIMIT: Limit = Limit {
+        capacity: 3.0,
+        refill_rate: 2.0,
+    };
+
+    #[test]
+    fn full_bucket_allows_a_burst_of_capacity_calls() {
+        let mut tokens = LIMIT.capacity;
+        for _ in 0..3 {
+            tokens = take_token(LIMIT, tokens, Duration::ZERO).unwrap();
+        }
+        assert_eq!(tokens, 0.0);
+        assert!(take_token(LIMIT, tokens, Duration::ZERO).is_err());
+    }
+
+    #[test]
+    fn empty_bucket_tells_how_long_to_wait() {
+        let wait = take_token(LIMIT, 0.0, Duration::ZERO).unwrap_err();
+        assert_eq!(wait, Duration::from_millis(500));
+
+        let wait = take_token(LIMIT, 0.5, Duration::ZERO).unwrap_err();
+        assert_eq!(wait, Duration::from_millis(250));
+    }
+
+    #[test]
+    fn bucket_refills_over_time() {
+        assert!(take_token(LIMIT, 0.0, Duration::from_millis(400)).is_err());
+        assert_eq!(take_token(LIMIT, 0.0, Duration::from_millis(500)), Ok(0.0));
+        assert_eq!(take_token(LIMIT, 0.0, Duration::from_secs(1)), Ok(1.0));
+    }
+
+    #[test]
+    fn bucket_never_holds_more_than_its_capacity() {
+        assert_eq!(
+            take_token(LIMIT, 1.0, Duration::from_secs(60)),
+            Ok(LIMIT.capacity - 1.0)
+        );
+    }
+
+    #[test]
+    fn bucket_without_refill_never_lets_calls_through_again() {
+        let limit = Limit {
+            capacity: 1.0,
+            refill_rate: 0.0,
+        };
+        assert_eq!(take_token(limit, 1.0, Duration::ZERO), Ok(0.0));
+        assert_eq!(
+            take_token(limit, 0.0, Duration::from_secs(3600)),
+            Err(Duration::MAX)
+        );
+    }
+}
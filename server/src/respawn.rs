@@ -0,0 +1,134 @@
+use glam::{Quat, Vec3};
+use simulation::ranges::STATION_BOUNDING_RADIUS;
+use spacetimedb::{reducer, ReducerContext};
+use spacetimedsl::{dsl, Wrapper};
+
+use crate::{admin::ensure_not_banned, sectors::sector_of, ships::spawn_ship, tables::*};
+
+/// Name of the ship type pilots eject in when their ship is destroyed.
+const ESCAPE_POD: &str = "Escape Pod";
+
+/// How far above the hull of their home station players respawn.
+const RESPAWN_CLEARANCE: f32 = 20.0;
+
+/// Gets the pilot of a destroyed ship to safety.
+///
+/// They eject in an escape pod where their ship was, unless it was already an escape pod, in
+/// which case they respawn at their home station.
+pub fn eject_pilot(
+    ctx: &ReducerContext,
+    player_id: &PlayerId,
+    ship_type: &ShipType,
+    position: Vec3,
+    rotation: Quat,
+) -> Result<(), String> {
+    let dsl = dsl(ctx);
+
+    let escape_pod = dsl
+        .get_all_ship_types()
+        .find(|ship_type| ship_type.get_name() == ESCAPE_POD);
+    let Some(escape_pod) = escape_pod.filter(|pod| pod.get_id() != ship_type.get_id()) else {
+        return respawn_at_home_station(ctx, player_id, position);
+    };
+
+    let pod = spawn_ship(ctx, &escape_pod, player_id, position, rotation)?;
+    dsl.create_ship_pilot(pod.get_id(), player_id)?;
+
+    Ok(())
+}
+
+/// Puts a player on foot next to their home station, or to the station nearest to `near` if
+/// they haven't chosen one.
+fn respawn_at_home_station(
+    ctx: &ReducerContext,
+    player_id: &PlayerId,
+    near: Vec3,
+) -> Result<(), String> {
+    let dsl = dsl(ctx);
+
+    let station = match dsl.get_home_station_by_player_id(player_id) {
+        Ok(home_station) => dsl.get_station_by_id(home_station.get_station_id())?,
+        Err(_) => dsl
+            .get_all_stations()
+            .min_by(|a, b| {
+                let distance_a = near.distance(Vec3::new(*a.get_x(), *a.get_y(), *a.get_z()));
+                let distance_b = near.distance(Vec3::new(*b.get_x(), *b.get_y(), *b.get_z()));
+                distance_a.total_cmp(&distance_b)
+            })
+            .ok_or("There is no station to respawn at")?,
+    };
+
+    let position = Vec3::new(*station.get_x(), *station.get_y(), *station.get_z())
+        + Vec3::Y * (STATION_BOUNDING_RADIUS + RESPAWN_CLEARANCE);
+    let [sector_x, sector_y, sector_z] = sector_of(position.x, position.y, position.z);
+    dsl.create_player_location(
+        player_id,
+        position.x,
+        position.y,
+        position.z,
+        0.0,
+        0.0,
+        0.0,
+        1.0,
+        sector_x,
+        sector_y,
+        sector_z,
+        ctx.timestamp,
+    )?;
+
+    Ok(())
+}
+
+/// Makes the station the ship the player is piloting is docked at their home station.
+#[reducer]
+pub fn set_home_station(ctx: &ReducerContext) -> Result<(), String> {
+    ensure_not_banned(ctx)?;
+    let dsl = dsl(ctx);
+
+    let player_id = PlayerId::new(ctx.sender);
+    let Ok(ship_pilot) = dsl.get_ship_pilot_by_player_id(&player_id) else {
+        return Err("Player is not piloting a ship".into());
+    };
+    let Ok(docking) = dsl.get_ship_docking_by_ship_id(&ship_pilot.get_ship_id()) else {
+        return Err("Ship must be docked at the station".into());
+    };
+
+    match dsl.get_home_station_by_player_id(&player_id) {
+        Ok(mut home_station) => {
+            home_station.set_station_id(docking.get_station_id());
+            dsl.update_home_station_by_player_id(home_station)?;
+        }
+        Err(_) => {
+            dsl.create_home_station(&player_id, docking.get_station_id())?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Leaves the escape pod the player ejected in for their home station.
+#[reducer]
+pub fn respawn_at_home(ctx: &ReducerContext) -> Result<(), String> {
+    ensure_not_banned(ctx)?;
+    let dsl = dsl(ctx);
+
+    let player_id = PlayerId::new(ctx.sender);
+    let Ok(ship_pilot) = dsl.get_ship_pilot_by_player_id(&player_id) else {
+        return Err("Player is not piloting a ship".into());
+    };
+    let ship_id = ship_pilot.get_ship_id();
+    let ship = dsl.get_ship_by_id(&ship_id)?;
+    let ship_type = dsl.get_ship_type_by_id(ship.get_ship_type_id())?;
+    if ship_type.get_name() != ESCAPE_POD {
+        return Err("Only pilots in an escape pod can respawn".into());
+    }
+
+    let ship_location = dsl.get_ship_location_by_ship_id(&ship_id);
+    let position = ship_location
+        .map(|location| Vec3::new(*location.get_x(), *location.get_y(), *location.get_z()))
+        .unwrap_or_default();
+
+    // The pod is scrapped, along with the pilot's seat in it.
+    dsl.delete_ship_by_id(&ship_id)?;
+    respawn_at_home_station(ctx, &player_id, position)
+}
@@ -0,0 +1,7 @@
+use glam::Vec3;
+
+/// Returns the coordinates of the sector containing the given position, see
+/// `simulation::sectors::SECTOR_SIZE`.
+pub fn sector_of(x: f32, y: f32, z: f32) -> [i32; 3] {
+    simulation::sectors::sector_of(Vec3::new(x, y, z)).to_array()
+}
@@ -0,0 +1,75 @@
+use serde::Deserialize;
+
+/// The ship catalog, embedded in the module so it's seeded along with the rest of the world.
+const SHIP_CATALOG: &str = include_str!("../data/ships.ron");
+
+/// A hull players can fly, as described in `data/ships.ron`.
+///
+/// Offsets are `(x, y, z)` tuples relative to the ship, and `model` and `collider` are asset
+/// paths resolved by the client.
+#[derive(Debug, Deserialize)]
+pub struct ShipDefinition {
+    pub name: String,
+    pub model: String,
+    pub collider: String,
+    pub camera_offset: (f32, f32, f32),
+    pub exit_offset: (f32, f32, f32),
+    pub mass: f32,
+    pub linear_damping: f32,
+    pub angular_damping: f32,
+    pub thrust: f32,
+    pub vertical_thrust: f32,
+    pub lateral_thrust: f32,
+    pub pitch_torque: f32,
+    pub yaw_torque: f32,
+    pub roll_torque: f32,
+    /// Volume of cargo the ship can carry.
+    pub cargo_capacity: f32,
+    /// Radius of the sphere around the ship that shots are tested against.
+    pub radius: f32,
+    /// Hull points of an undamaged ship.
+    pub hull: f32,
+    /// Energy the ship can store to fire its weapons, and how much it recharges per second.
+    pub energy: f32,
+    pub energy_recharge: f32,
+    /// Shield points of a fully charged shield, how many regenerate per second, and how many
+    /// seconds after the last hit they start to.
+    pub shield: f32,
+    pub shield_recharge: f32,
+    pub shield_delay: f32,
+    pub armor: ArmorDefinition,
+    /// Fuel the tank holds, and how much is burnt per world unit flown.
+    pub fuel: f32,
+    pub fuel_consumption: f32,
+    /// Factor applied to the main thrust while boosting, and the energy drained per second doing
+    /// so.
+    pub boost: f32,
+    pub boost_energy: f32,
+    #[serde(default)]
+    pub hardpoints: Vec<HardpointDefinition>,
+    #[serde(default)]
+    pub retired: bool,
+}
+
+/// Fraction of the damage of each type the armor of a hull stops from reaching it.
+#[derive(Debug, Deserialize)]
+pub struct ArmorDefinition {
+    pub energy: f32,
+    pub kinetic: f32,
+    pub explosive: f32,
+}
+
+/// A weapon mounted on a hull.
+#[derive(Debug, Deserialize)]
+pub struct HardpointDefinition {
+    /// Name of the weapon, from `data/weapons.ron`.
+    pub weapon: String,
+    pub offset: (f32, f32, f32),
+}
+
+/// Parses the embedded ship catalog.
+///
+/// Ship types are created in catalog order, so the n-th entry gets the ship type id n.
+pub fn ship_catalog() -> Vec<ShipDefinition> {
+    ron::from_str(SHIP_CATALOG).expect("Invalid ship catalog")
+}
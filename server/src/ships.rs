@@ -0,0 +1,117 @@
+use std::time::Duration;
+
+use glam::{Quat, Vec3};
+use spacetimedb::{reducer, table, Identity, ReducerContext, ScheduleAt, Table, TimeDuration};
+use spacetimedsl::{dsl, Wrapper};
+
+use crate::{player::leave_ship, sectors::sector_of, tables::*};
+
+/// How long the ships of a disconnected player stay parked in the world before being despawned.
+/// Set to `None` to keep them forever.
+const PARKED_SHIPS_DESPAWN_DELAY: Option<Duration> = Some(Duration::from_secs(60 * 60));
+
+#[table(name = parked_ships_despawn, scheduled(ships_despawn_parked))]
+pub struct ParkedShipsDespawn {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+    #[index(btree)]
+    owner_id: Identity,
+}
+
+/// Creates a ship in flight, in mint condition.
+pub fn spawn_ship(
+    ctx: &ReducerContext,
+    ship_type: &ShipType,
+    owner_id: &PlayerId,
+    position: Vec3,
+    rotation: Quat,
+) -> Result<Ship, String> {
+    let dsl = dsl(ctx);
+
+    let ship = dsl.create_ship(ship_type.get_id(), owner_id, None)?;
+    dsl.create_ship_status(
+        ship.get_id(),
+        *ship_type.get_max_hull(),
+        *ship_type.get_max_energy(),
+        ctx.timestamp,
+        *ship_type.get_max_fuel(),
+        *ship_type.get_max_shield(),
+        ctx.timestamp,
+        1.0,
+        1.0,
+        1.0,
+        1.0,
+    )?;
+    let [sector_x, sector_y, sector_z] = sector_of(position.x, position.y, position.z);
+    dsl.create_ship_location(
+        ship.get_id(),
+        position.x,
+        position.y,
+        position.z,
+        rotation.x,
+        rotation.y,
+        rotation.z,
+        rotation.w,
+        sector_x,
+        sector_y,
+        sector_z,
+        ctx.timestamp,
+    )?;
+
+    Ok(ship)
+}
+
+/// Schedules the despawn of every ship owned by a player that just disconnected.
+pub fn schedule_parked_ships_despawn(ctx: &ReducerContext, owner_id: &PlayerId) {
+    let Some(delay) = PARKED_SHIPS_DESPAWN_DELAY else {
+        return;
+    };
+
+    ctx.db.parked_ships_despawn().insert(ParkedShipsDespawn {
+        scheduled_id: 0,
+        scheduled_at: (ctx.timestamp + TimeDuration::from(delay)).into(),
+        owner_id: owner_id.value(),
+    });
+}
+
+/// Cancels the pending despawn of a player's ships, used when they reconnect in time.
+pub fn cancel_parked_ships_despawn(ctx: &ReducerContext, owner_id: &PlayerId) {
+    ctx.db
+        .parked_ships_despawn()
+        .owner_id()
+        .delete(owner_id.value());
+}
+
+#[reducer]
+pub fn ships_despawn_parked(
+    ctx: &ReducerContext,
+    despawn: ParkedShipsDespawn,
+) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Parked ships can only be despawned by the server".to_string());
+    }
+
+    let dsl = dsl(ctx);
+    let owner_id = PlayerId::new(despawn.owner_id);
+
+    // Reconnecting cancels the despawn, but don't take any chances with an online player's ships.
+    if *dsl.get_player_by_id(&owner_id)?.get_online() {
+        return Ok(());
+    }
+
+    // Ships handed over to a faction stay for its members. Players they gave access to may be
+    // flying the others, they're put on foot next to them first.
+    for ship in dsl.get_ships_by_owner_id(&owner_id) {
+        if ship.get_faction_id().is_some() {
+            continue;
+        }
+        if let Ok(ship_pilot) = dsl.get_ship_pilot_by_ship_id(ship.get_id()) {
+            leave_ship(ctx, &ship_pilot.get_player_id())?;
+        }
+        dsl.delete_ship_by_id(ship.get_id())?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use spacetimedb::{table, Identity, Timestamp};
+use spacetimedsl::dsl;
+
+/// A record of an action taken by an admin, kept for operators to review.
+#[dsl(plural_name = admin_audits)]
+#[table(name = admin_audit)]
+pub struct AdminAudit {
+    #[primary_key]
+    #[auto_inc]
+    #[create_wrapper]
+    id: u64,
+
+    #[index(btree)]
+    admin_id: Identity,
+
+    /// Name of the reducer the admin called, and its arguments.
+    action: String,
+    details: String,
+
+    performed_at: Timestamp,
+}
@@ -0,0 +1,15 @@
+use spacetimedb::{table, Identity, Timestamp};
+use spacetimedsl::dsl;
+
+/// An operator allowed to call the `admin_` reducers. The identity that published the module is
+/// the first one, see `init`.
+#[dsl(plural_name = admins)]
+#[table(name = admin)]
+pub struct Admin {
+    #[primary_key]
+    #[create_wrapper]
+    id: Identity,
+
+    granted_by: Identity,
+    granted_at: Timestamp,
+}
@@ -0,0 +1,26 @@
+use crate::tables::{AsteroidId, ItemTypeId};
+use spacetimedb::table;
+use spacetimedsl::dsl;
+
+/// One of the ores an asteroid is made of.
+#[dsl(plural_name = asteroid_resources)]
+#[table(name = asteroid_resource, public)]
+pub struct AsteroidResource {
+    #[primary_key]
+    #[auto_inc]
+    #[create_wrapper]
+    id: u64,
+
+    #[index(btree)]
+    #[use_wrapper(path = AsteroidId)]
+    #[foreign_key(path = crate::tables, table = asteroid, column = id, on_delete = Delete)]
+    asteroid_id: u64,
+
+    #[index(btree)]
+    #[use_wrapper(path = ItemTypeId)]
+    #[foreign_key(path = crate::tables, table = item_type, column = id, on_delete = Error)]
+    item_type_id: u64,
+
+    /// Share of the asteroid's ore this resource makes up, from 0 to 1.
+    share: f32,
+}
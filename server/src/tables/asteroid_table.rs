@@ -7,6 +7,8 @@ pub struct Asteroid {
     #[primary_key]
     #[auto_inc]
     #[create_wrapper]
+    #[referenced_by(path = crate::tables, table = asteroid_resource)]
+    #[referenced_by(path = crate::tables, table = ship_mining)]
     id: u64,
     pos_x: f32,
     pos_y: f32,
@@ -17,4 +19,13 @@ pub struct Asteroid {
     rot_w: f32,
     asteroid_type: u8,
     scale: f32,
+
+    /// Units of ore left to mine. The asteroid is removed when it reaches 0.
+    pub remaining: u32,
+
+    /// Coordinates of the sector containing this asteroid, see `sectors::sector_of`.
+    #[index(btree)]
+    sector_x: i32,
+    sector_y: i32,
+    sector_z: i32,
 }
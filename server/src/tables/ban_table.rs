@@ -0,0 +1,25 @@
+use crate::tables::PlayerId;
+use spacetimedb::{client_visibility_filter, table, Filter, Identity, Timestamp};
+use spacetimedsl::dsl;
+
+/// A player refused connections and gameplay calls, forever or until `expires_at`, see
+/// `admin::ensure_not_banned`.
+///
+/// Banned players can see their own ban, so their client can tell them why it's being
+/// disconnected, see `BAN_FILTER`.
+#[dsl(plural_name = bans)]
+#[table(name = ban, public)]
+pub struct Ban {
+    /// Identities can be banned before they ever connect, so there's no player row to refer to.
+    #[primary_key]
+    #[use_wrapper(path = PlayerId)]
+    player_id: Identity,
+
+    reason: String,
+    banned_by: Identity,
+    banned_at: Timestamp,
+    expires_at: Option<Timestamp>,
+}
+
+#[client_visibility_filter]
+const BAN_FILTER: Filter = Filter::Sql("SELECT * FROM ban WHERE player_id = :sender");
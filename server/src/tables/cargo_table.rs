@@ -0,0 +1,25 @@
+use crate::tables::{ItemTypeId, ShipId};
+use spacetimedb::table;
+use spacetimedsl::dsl;
+
+/// A stack of items in a ship's cargo hold. A ship has at most one stack per item type.
+#[dsl(plural_name = cargos)]
+#[table(name = cargo, public)]
+pub struct Cargo {
+    #[primary_key]
+    #[auto_inc]
+    #[create_wrapper]
+    id: u64,
+
+    #[index(btree)]
+    #[use_wrapper(path = ShipId)]
+    #[foreign_key(path = crate::tables, table = ship, column = id, on_delete = Delete)]
+    ship_id: u64,
+
+    #[index(btree)]
+    #[use_wrapper(path = ItemTypeId)]
+    #[foreign_key(path = crate::tables, table = item_type, column = id, on_delete = Error)]
+    item_type_id: u64,
+
+    pub quantity: u32,
+}
@@ -0,0 +1,47 @@
+use crate::tables::PlayerId;
+use spacetimedb::{client_visibility_filter, table, Filter, Identity, SpacetimeType, Timestamp};
+use spacetimedsl::dsl;
+
+/// Who a chat message is sent to.
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatChannel {
+    /// Every player online.
+    Global,
+    /// The players near the sender, see `chat::LOCAL_CHAT_RADIUS`.
+    Local,
+    /// A single player.
+    Private,
+    /// Every player online, from an admin, see `admin::admin_broadcast`.
+    Announcement,
+}
+
+/// A copy of a chat message, delivered to one of its recipients.
+///
+/// Messages are copied to each of their recipients when sent, so players can only ever see the
+/// messages meant for them, see `CHAT_MESSAGE_FILTER`.
+#[dsl(plural_name = chat_messages)]
+#[table(name = chat_message, public)]
+pub struct ChatMessage {
+    #[primary_key]
+    #[auto_inc]
+    #[create_wrapper]
+    id: u64,
+
+    #[index(btree)]
+    #[use_wrapper(path = PlayerId)]
+    #[foreign_key(path = crate::tables, table = player, column = id, on_delete = Delete)]
+    recipient_id: Identity,
+
+    sender_id: Identity,
+    channel: ChatChannel,
+
+    /// The player a private message was sent to, so the sender's own copy can show it.
+    private_to: Option<Identity>,
+
+    text: String,
+    sent_at: Timestamp,
+}
+
+#[client_visibility_filter]
+const CHAT_MESSAGE_FILTER: Filter =
+    Filter::Sql("SELECT * FROM chat_message WHERE recipient_id = :sender");
@@ -0,0 +1,28 @@
+use crate::tables::StationId;
+use spacetimedb::table;
+use spacetimedsl::dsl;
+
+/// A spot on a station where a single ship can dock.
+#[dsl(plural_name = docking_bays)]
+#[table(name = docking_bay, public)]
+pub struct DockingBay {
+    #[primary_key]
+    #[auto_inc]
+    #[create_wrapper]
+    #[referenced_by(path = crate::tables, table = ship_docking)]
+    id: u64,
+
+    #[index(btree)]
+    #[use_wrapper(path = StationId)]
+    #[foreign_key(path = crate::tables, table = station, column = id, on_delete = Delete)]
+    station_id: u64,
+
+    /// Position of docked ships relative to the station's center, in the station's rotating
+    /// frame.
+    offset_x: f32,
+    offset_y: f32,
+    offset_z: f32,
+
+    /// Rotation of docked ships around the station's axis, relative to the station, in radians.
+    heading: f32,
+}
@@ -0,0 +1,25 @@
+use crate::tables::{FactionId, PlayerId};
+use spacetimedb::{table, Identity, Timestamp};
+use spacetimedsl::dsl;
+
+/// An invitation for a player to join a faction, pending until they join or decline it.
+#[dsl(plural_name = faction_invites)]
+#[table(name = faction_invite, public)]
+pub struct FactionInvite {
+    #[primary_key]
+    #[auto_inc]
+    #[create_wrapper]
+    id: u64,
+
+    #[index(btree)]
+    #[use_wrapper(path = FactionId)]
+    #[foreign_key(path = crate::tables, table = faction, column = id, on_delete = Delete)]
+    faction_id: Identity,
+
+    #[index(btree)]
+    #[use_wrapper(path = PlayerId)]
+    #[foreign_key(path = crate::tables, table = player, column = id, on_delete = Delete)]
+    player_id: Identity,
+
+    invited_at: Timestamp,
+}
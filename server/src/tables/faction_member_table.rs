@@ -0,0 +1,31 @@
+use crate::tables::{FactionId, PlayerId};
+use spacetimedb::{table, Identity, SpacetimeType, Timestamp};
+use spacetimedsl::dsl;
+
+/// Rank of a faction member, from lowest to highest. What each rank may do is set in
+/// `factions.rs`.
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FactionRank {
+    Recruit,
+    Pilot,
+    Officer,
+    Leader,
+}
+
+/// A player's membership of a faction. Players belong to one faction at most.
+#[dsl(plural_name = faction_members)]
+#[table(name = faction_member, public)]
+pub struct FactionMember {
+    #[primary_key]
+    #[use_wrapper(path = PlayerId)]
+    #[foreign_key(path = crate::tables, table = player, column = id, on_delete = Delete)]
+    player_id: Identity,
+
+    #[index(btree)]
+    #[use_wrapper(path = FactionId)]
+    #[foreign_key(path = crate::tables, table = faction, column = id, on_delete = Delete)]
+    faction_id: Identity,
+
+    pub rank: FactionRank,
+    joined_at: Timestamp,
+}
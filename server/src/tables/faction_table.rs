@@ -0,0 +1,23 @@
+use spacetimedb::{table, Identity, Timestamp};
+use spacetimedsl::dsl;
+
+/// A player organisation, whose members can share the ships it owns.
+#[dsl(plural_name = factions)]
+#[table(name = faction, public)]
+pub struct Faction {
+    /// Ships handed over to the faction have it as their `faction_id`.
+    #[primary_key]
+    #[create_wrapper]
+    #[referenced_by(path = crate::tables, table = faction_member)]
+    #[referenced_by(path = crate::tables, table = faction_invite)]
+    id: Identity,
+
+    #[unique]
+    name: String,
+
+    /// Short tag shown next to the names of the faction's members.
+    #[unique]
+    tag: String,
+
+    created_at: Timestamp,
+}
@@ -0,0 +1,29 @@
+use crate::tables::{ShipTypeId, WeaponTypeId};
+use spacetimedb::table;
+use spacetimedsl::dsl;
+
+/// A weapon mounted on every ship of a type.
+#[dsl(plural_name = hardpoints)]
+#[table(name = hardpoint, public)]
+pub struct Hardpoint {
+    #[primary_key]
+    #[auto_inc]
+    #[create_wrapper]
+    #[referenced_by(path = crate::tables, table = weapon_fire)]
+    id: u64,
+
+    #[index(btree)]
+    #[use_wrapper(path = ShipTypeId)]
+    #[foreign_key(path = crate::tables, table = ship_type, column = id, on_delete = Delete)]
+    ship_type_id: u64,
+
+    #[index(btree)]
+    #[use_wrapper(path = WeaponTypeId)]
+    #[foreign_key(path = crate::tables, table = weapon_type, column = id, on_delete = Error)]
+    weapon_type_id: u64,
+
+    /// Where shots leave from, relative to the ship.
+    offset_x: f32,
+    offset_y: f32,
+    offset_z: f32,
+}
@@ -0,0 +1,18 @@
+use crate::tables::{PlayerId, StationId};
+use spacetimedb::{table, Identity};
+use spacetimedsl::dsl;
+
+/// The station a player respawns at after losing their ship.
+#[dsl(plural_name = home_stations)]
+#[table(name = home_station, public)]
+pub struct HomeStation {
+    #[primary_key]
+    #[use_wrapper(path = PlayerId)]
+    #[foreign_key(path = crate::tables, table = player, column = id, on_delete = Delete)]
+    player_id: Identity,
+
+    #[index(btree)]
+    #[use_wrapper(path = StationId)]
+    #[foreign_key(path = crate::tables, table = station, column = id, on_delete = Delete)]
+    pub station_id: u64,
+}
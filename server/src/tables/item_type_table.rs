@@ -0,0 +1,24 @@
+use spacetimedb::table;
+use spacetimedsl::dsl;
+
+#[dsl(plural_name = item_types)]
+#[table(name = item_type, public)]
+pub struct ItemType {
+    #[primary_key]
+    #[auto_inc]
+    #[create_wrapper]
+    #[referenced_by(path = crate::tables, table = cargo)]
+    #[referenced_by(path = crate::tables, table = asteroid_resource)]
+    #[referenced_by(path = crate::tables, table = market)]
+    id: u64,
+    #[unique]
+    name: String,
+    /// Volume taken by one unit of the item in a cargo hold.
+    volume: f32,
+    /// Mass of one unit of the item, in the same unit as `ShipType::mass`.
+    mass: f32,
+    /// Reference price of one unit of the item, in credits.
+    base_price: u64,
+    /// Whether the item can be mined out of asteroids.
+    ore: bool,
+}
@@ -0,0 +1,24 @@
+use crate::tables::MarketId;
+use spacetimedb::{table, Timestamp};
+use spacetimedsl::dsl;
+
+/// The state of a market after an economy tick, kept for a while so clients can chart trends.
+#[dsl(plural_name = market_price_histories)]
+#[table(name = market_price_history, public)]
+pub struct MarketPriceHistory {
+    #[primary_key]
+    #[auto_inc]
+    #[create_wrapper]
+    id: u64,
+
+    #[index(btree)]
+    #[use_wrapper(path = MarketId)]
+    #[foreign_key(path = crate::tables, table = market, column = id, on_delete = Delete)]
+    market_id: u64,
+
+    recorded_at: Timestamp,
+
+    price: f32,
+    stock: u32,
+    demand: u32,
+}
@@ -0,0 +1,41 @@
+use crate::tables::{ItemTypeId, StationId};
+use spacetimedb::table;
+use spacetimedsl::dsl;
+
+/// A commodity traded at a station. A station has at most one market per item type.
+#[dsl(plural_name = markets)]
+#[table(name = market, public)]
+pub struct Market {
+    #[primary_key]
+    #[auto_inc]
+    #[create_wrapper]
+    #[referenced_by(path = crate::tables, table = market_price_history)]
+    id: u64,
+
+    #[index(btree)]
+    #[use_wrapper(path = StationId)]
+    #[foreign_key(path = crate::tables, table = station, column = id, on_delete = Delete)]
+    station_id: u64,
+
+    #[index(btree)]
+    #[use_wrapper(path = ItemTypeId)]
+    #[foreign_key(path = crate::tables, table = item_type, column = id, on_delete = Error)]
+    item_type_id: u64,
+
+    /// Price, in credits, players pay the station for one unit.
+    pub buy_price: u64,
+    /// Price, in credits, the station pays players for one unit.
+    pub sell_price: u64,
+    /// Units the station has available for players to buy, its supply.
+    pub stock: u32,
+    /// Stock the station wants to keep. Prices rise while the stock is below it and fall while
+    /// it's above.
+    pub demand: u32,
+    /// Price, in credits, the buy and sell prices are spread around. Drifts with supply and
+    /// demand, see `economy::step_market`.
+    pub price: f32,
+    /// Units the station produces on each economy tick.
+    production: u32,
+    /// Units the station consumes on each economy tick.
+    consumption: u32,
+}
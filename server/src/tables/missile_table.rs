@@ -0,0 +1,50 @@
+use crate::tables::WeaponTypeId;
+use spacetimedb::{table, Timestamp};
+use spacetimedsl::dsl;
+
+/// A missile in flight, advanced by `missiles::world_update_missiles` until it detonates.
+#[dsl(plural_name = missiles)]
+#[table(name = missile, public)]
+pub struct Missile {
+    #[primary_key]
+    #[auto_inc]
+    #[create_wrapper]
+    id: u64,
+
+    #[index(btree)]
+    #[use_wrapper(path = WeaponTypeId)]
+    #[foreign_key(path = crate::tables, table = weapon_type, column = id, on_delete = Error)]
+    weapon_type_id: u64,
+
+    /// The ships that launched the missile and that it's homing on. Missiles keep flying when
+    /// either is gone, so these are not foreign keys.
+    launcher_ship_id: u64,
+    target_ship_id: u64,
+
+    /// Damage dealt at the center of the blast, as the launcher could deliver it at launch.
+    damage: f32,
+
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+
+    pub vel_x: f32,
+    pub vel_y: f32,
+    pub vel_z: f32,
+
+    /// Where the target was on the previous update, to estimate its velocity.
+    pub target_x: f32,
+    pub target_y: f32,
+    pub target_z: f32,
+
+    /// Seconds of flight left.
+    pub fuel: f32,
+
+    /// Coordinates of the sector containing this missile, see `sectors::sector_of`.
+    #[index(btree)]
+    pub sector_x: i32,
+    pub sector_y: i32,
+    pub sector_z: i32,
+
+    pub updated_at: Timestamp,
+}
@@ -0,0 +1,20 @@
+use crate::tables::WeaponTypeId;
+use spacetimedb::table;
+use spacetimedsl::dsl;
+
+/// The missile launched by a weapon type, for the weapon types that are launchers.
+#[dsl(plural_name = missile_types)]
+#[table(name = missile_type, public)]
+pub struct MissileType {
+    #[primary_key]
+    #[use_wrapper(path = WeaponTypeId)]
+    #[foreign_key(path = crate::tables, table = weapon_type, column = id, on_delete = Delete)]
+    weapon_type_id: u64,
+    model: String,
+    speed: f32,
+    acceleration: f32,
+    fuel: f32,
+    navigation_gain: f32,
+    proximity_radius: f32,
+    blast_radius: f32,
+}
@@ -1,17 +1,79 @@
+mod admin_audit_table;
+mod admin_table;
+mod asteroid_resource_table;
 mod asteroid_table;
+mod ban_table;
+mod cargo_table;
+mod chat_message_table;
+mod docking_bay_table;
+mod faction_invite_table;
+mod faction_member_table;
+mod faction_table;
+mod hardpoint_table;
+mod home_station_table;
+mod item_type_table;
+mod market_price_history_table;
+mod market_table;
+mod missile_table;
+mod missile_type_table;
+mod mute_table;
 mod player_location;
 mod player_table;
+mod rate_limit_bucket_table;
+mod rate_limit_table;
+mod rejected_call_table;
+mod ship_access_table;
+mod ship_docking_table;
+mod ship_flight_table;
 mod ship_location_table;
+mod ship_mining_table;
 mod ship_pilot_table;
+mod ship_status_table;
 mod ship_table;
 mod ship_type_table;
+mod station_rotation_table;
 mod station_table;
+mod wallet_table;
+mod weapon_fire_table;
+mod weapon_type_table;
+mod wreck_table;
 
+pub use admin_audit_table::*;
+pub use admin_table::*;
+pub use asteroid_resource_table::*;
 pub use asteroid_table::*;
+pub use ban_table::*;
+pub use cargo_table::*;
+pub use chat_message_table::*;
+pub use docking_bay_table::*;
+pub use faction_invite_table::*;
+pub use faction_member_table::*;
+pub use faction_table::*;
+pub use hardpoint_table::*;
+pub use home_station_table::*;
+pub use item_type_table::*;
+pub use market_price_history_table::*;
+pub use market_table::*;
+pub use missile_table::*;
+pub use missile_type_table::*;
+pub use mute_table::*;
 pub use player_location::*;
 pub use player_table::*;
+pub use rate_limit_bucket_table::*;
+pub use rate_limit_table::*;
+pub use rejected_call_table::*;
+pub use ship_access_table::*;
+pub use ship_docking_table::*;
+pub use ship_flight_table::*;
 pub use ship_location_table::*;
+pub use ship_mining_table::*;
 pub use ship_pilot_table::*;
+pub use ship_status_table::*;
 pub use ship_table::*;
 pub use ship_type_table::*;
+pub use station_rotation_table::*;
 pub use station_table::*;
+pub use wallet_table::*;
+pub use weapon_fire_table::*;
+pub use weapon_type_table::*;
+pub use wreck_table::*;
@@ -0,0 +1,17 @@
+use crate::tables::PlayerId;
+use spacetimedb::{table, Identity, Timestamp};
+use spacetimedsl::dsl;
+
+/// A player kept from sending chat messages, forever or until `expires_at`.
+#[dsl(plural_name = mutes)]
+#[table(name = mute)]
+pub struct Mute {
+    #[primary_key]
+    #[use_wrapper(path = PlayerId)]
+    #[foreign_key(path = crate::tables, table = player, column = id, on_delete = Delete)]
+    player_id: Identity,
+
+    muted_by: Identity,
+    muted_at: Timestamp,
+    expires_at: Option<Timestamp>,
+}
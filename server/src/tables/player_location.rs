@@ -1,5 +1,5 @@
 use crate::tables::PlayerId;
-use spacetimedb::{table, Identity};
+use spacetimedb::{table, Identity, Timestamp};
 use spacetimedsl::dsl;
 
 #[dsl(plural_name = player_locations)]
@@ -18,4 +18,13 @@ pub struct PlayerLocation {
     pub rot_y: f32,
     pub rot_z: f32,
     pub rot_w: f32,
+
+    /// Coordinates of the sector containing this location, see `sectors::sector_of`.
+    #[index(btree)]
+    pub sector_x: i32,
+    pub sector_y: i32,
+    pub sector_z: i32,
+
+    /// When the player last moved, to bound how far they can go by the next move.
+    pub updated_at: Timestamp,
 }
@@ -1,4 +1,4 @@
-use spacetimedb::{table, Identity};
+use spacetimedb::{table, Identity, Timestamp};
 use spacetimedsl::dsl;
 
 #[dsl(plural_name = players)]
@@ -8,6 +8,13 @@ pub struct Player {
     #[create_wrapper]
     #[referenced_by(path = crate::tables, table = ship_pilot)]
     #[referenced_by(path = crate::tables, table = player_location)]
+    #[referenced_by(path = crate::tables, table = ship_access)]
+    #[referenced_by(path = crate::tables, table = wallet)]
+    #[referenced_by(path = crate::tables, table = home_station)]
+    #[referenced_by(path = crate::tables, table = chat_message)]
+    #[referenced_by(path = crate::tables, table = faction_member)]
+    #[referenced_by(path = crate::tables, table = faction_invite)]
+    #[referenced_by(path = crate::tables, table = mute)]
     id: Identity,
 
     pub x: f32,
@@ -18,4 +25,7 @@ pub struct Player {
     pub rot_y: f32,
     pub rot_z: f32,
     pub rot_w: f32,
+
+    pub online: bool,
+    pub last_seen: Timestamp,
 }
@@ -0,0 +1,24 @@
+use crate::tables::PlayerId;
+use spacetimedb::{table, Identity, Timestamp};
+use spacetimedsl::dsl;
+
+/// The calls a player has left to a rate-limited reducer, see `rate_limit.rs`.
+#[dsl(plural_name = rate_limit_buckets)]
+#[table(name = rate_limit_bucket)]
+pub struct RateLimitBucket {
+    #[primary_key]
+    #[auto_inc]
+    #[create_wrapper]
+    id: u64,
+
+    /// Buckets are kept per identity, whether or not it made it to being a player.
+    #[index(btree)]
+    #[use_wrapper(path = PlayerId)]
+    player_id: Identity,
+
+    reducer: String,
+
+    /// Tokens in the bucket when it was last updated, each call taking one.
+    pub tokens: f32,
+    pub updated_at: Timestamp,
+}
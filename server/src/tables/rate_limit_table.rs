@@ -0,0 +1,19 @@
+use spacetimedb::table;
+use spacetimedsl::dsl;
+
+/// Limit set by an admin on how often players may call a reducer, in place of its default from
+/// `rate_limit::DEFAULT_RATE_LIMITS`.
+#[dsl(plural_name = rate_limits)]
+#[table(name = rate_limit)]
+pub struct RateLimit {
+    #[primary_key]
+    #[auto_inc]
+    #[create_wrapper]
+    id: u64,
+
+    #[unique]
+    reducer: String,
+
+    pub capacity: f32,
+    pub refill_rate: f32,
+}
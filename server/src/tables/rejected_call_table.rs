@@ -0,0 +1,30 @@
+use crate::tables::PlayerId;
+use spacetimedb::{client_visibility_filter, table, Filter, Identity, Timestamp};
+use spacetimedsl::dsl;
+
+/// The last call of a player to a rate-limited reducer that was refused for going over its rate
+/// limit, and why.
+///
+/// Refused calls succeed without doing anything, see `rate_limit::rate_limited`, so their reason
+/// is reported here instead. Players only see their own, see `REJECTED_CALL_FILTER`.
+#[dsl(plural_name = rejected_calls)]
+#[table(name = rejected_call, public)]
+pub struct RejectedCall {
+    #[primary_key]
+    #[auto_inc]
+    #[create_wrapper]
+    id: u64,
+
+    /// Calls are rate limited per identity, whether or not it made it to being a player.
+    #[index(btree)]
+    #[use_wrapper(path = PlayerId)]
+    player_id: Identity,
+
+    reducer: String,
+    reason: String,
+    rejected_at: Timestamp,
+}
+
+#[client_visibility_filter]
+const REJECTED_CALL_FILTER: Filter =
+    Filter::Sql("SELECT * FROM rejected_call WHERE player_id = :sender");
@@ -0,0 +1,23 @@
+use crate::tables::{PlayerId, ShipId};
+use spacetimedb::{table, Identity};
+use spacetimedsl::dsl;
+
+/// Grants a player other than the owner the right to pilot a ship.
+#[dsl(plural_name = ship_accesses)]
+#[table(name = ship_access, public)]
+pub struct ShipAccess {
+    #[primary_key]
+    #[auto_inc]
+    #[create_wrapper]
+    id: u64,
+
+    #[index(btree)]
+    #[use_wrapper(path = ShipId)]
+    #[foreign_key(path = crate::tables, table = ship, column = id, on_delete = Delete)]
+    ship_id: u64,
+
+    #[index(btree)]
+    #[use_wrapper(path = PlayerId)]
+    #[foreign_key(path = crate::tables, table = player, column = id, on_delete = Delete)]
+    player_id: Identity,
+}
@@ -0,0 +1,33 @@
+use crate::tables::{DockingBayId, ShipId, StationId};
+use spacetimedb::{table, Timestamp};
+use spacetimedsl::dsl;
+
+/// A ship docked at a station. Docked ships have no `ShipLocation`, they are attached to their
+/// docking bay and rotate along with the station until they undock.
+#[dsl(plural_name = ship_dockings)]
+#[table(name = ship_docking, public)]
+pub struct ShipDocking {
+    #[primary_key]
+    #[use_wrapper(path = ShipId)]
+    #[foreign_key(path = crate::tables, table = ship, column = id, on_delete = Delete)]
+    ship_id: u64,
+
+    #[unique]
+    #[use_wrapper(path = DockingBayId)]
+    #[foreign_key(path = crate::tables, table = docking_bay, column = id, on_delete = Error)]
+    docking_bay_id: u64,
+
+    #[index(btree)]
+    #[use_wrapper(path = StationId)]
+    #[foreign_key(path = crate::tables, table = station, column = id, on_delete = Error)]
+    station_id: u64,
+
+    /// Coordinates of the sector containing the station, so docked ships can be subscribed to
+    /// along with everything else in it.
+    #[index(btree)]
+    sector_x: i32,
+    sector_y: i32,
+    sector_z: i32,
+
+    docked_at: Timestamp,
+}
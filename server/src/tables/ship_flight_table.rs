@@ -0,0 +1,53 @@
+use crate::tables::ShipId;
+use spacetimedb::{table, SpacetimeType};
+use spacetimedsl::dsl;
+
+/// What the pilot of a ship flown by the server asks of it. Every axis goes from -1 to 1.
+#[derive(SpacetimeType, Debug, Clone, Copy, Default, PartialEq)]
+pub struct FlightControls {
+    pub thrust: f32,
+    pub lateral_thrust: f32,
+    pub vertical_thrust: f32,
+    pub roll: f32,
+    pub pitch: f32,
+    pub yaw: f32,
+    pub boost: bool,
+}
+
+impl From<FlightControls> for simulation::flight::FlightControls {
+    fn from(controls: FlightControls) -> Self {
+        Self {
+            thrust: controls.thrust,
+            lateral_thrust: controls.lateral_thrust,
+            vertical_thrust: controls.vertical_thrust,
+            roll: controls.roll,
+            pitch: controls.pitch,
+            yaw: controls.yaw,
+            boost: controls.boost,
+        }
+    }
+}
+
+/// A ship flown by the server from its pilot's controls, instead of moved by the pilot's client
+/// through `player_move_ship`. Advanced by `flight::world_update_ship_flights`, which keeps its
+/// location up to date.
+#[dsl(plural_name = ship_flights)]
+#[table(name = ship_flight, public)]
+pub struct ShipFlight {
+    #[primary_key]
+    #[use_wrapper(path = ShipId)]
+    #[foreign_key(path = crate::tables, table = ship, column = id, on_delete = Delete)]
+    ship_id: u64,
+
+    /// Latest controls sent by the pilot, see `flight::player_set_flight_controls`.
+    pub controls: FlightControls,
+
+    pub vel_x: f32,
+    pub vel_y: f32,
+    pub vel_z: f32,
+
+    /// Angular velocity, in radians per second around each world axis.
+    pub ang_vel_x: f32,
+    pub ang_vel_y: f32,
+    pub ang_vel_z: f32,
+}
@@ -1,5 +1,5 @@
-use crate::tables::ShipId;
-use spacetimedb::table;
+use crate::{sectors::sector_of, tables::ShipId};
+use spacetimedb::{table, Timestamp};
 use spacetimedsl::{dsl, Wrapper};
 
 #[dsl(plural_name = ship_locations)]
@@ -18,6 +18,16 @@ pub struct ShipLocation {
     pub rot_y: f32,
     pub rot_z: f32,
     pub rot_w: f32,
+
+    /// Coordinates of the sector containing this location, see `sectors::sector_of`.
+    #[index(btree)]
+    pub sector_x: i32,
+    pub sector_y: i32,
+    pub sector_z: i32,
+
+    /// When this location was last accepted by the server, used to bound how far the ship
+    /// could have travelled before the next update.
+    pub updated_at: Timestamp,
 }
 
 impl ShipLocation {
@@ -30,7 +40,9 @@ impl ShipLocation {
         rot_y: f32,
         rot_z: f32,
         rot_w: f32,
+        updated_at: Timestamp,
     ) -> Self {
+        let [sector_x, sector_y, sector_z] = sector_of(x, y, z);
         Self {
             ship_id: ship_id.value(),
             x,
@@ -40,6 +52,10 @@ impl ShipLocation {
             rot_y,
             rot_z,
             rot_w,
+            sector_x,
+            sector_y,
+            sector_z,
+            updated_at,
         }
     }
 }
@@ -0,0 +1,21 @@
+use crate::tables::{AsteroidId, ShipId};
+use spacetimedb::{table, Timestamp};
+use spacetimedsl::dsl;
+
+/// The last asteroid a ship extracted ore from, used to pace extractions and to show mining
+/// lasers to nearby players.
+#[dsl(plural_name = ship_minings)]
+#[table(name = ship_mining, public)]
+pub struct ShipMining {
+    #[primary_key]
+    #[use_wrapper(path = ShipId)]
+    #[foreign_key(path = crate::tables, table = ship, column = id, on_delete = Delete)]
+    ship_id: u64,
+
+    #[index(btree)]
+    #[use_wrapper(path = AsteroidId)]
+    #[foreign_key(path = crate::tables, table = asteroid, column = id, on_delete = Delete)]
+    asteroid_id: u64,
+
+    last_mined_at: Timestamp,
+}
@@ -0,0 +1,38 @@
+use crate::tables::ShipId;
+use spacetimedb::{table, Timestamp};
+use spacetimedsl::dsl;
+
+/// The condition of a ship, which changes as it fights.
+#[dsl(plural_name = ship_statuses)]
+#[table(name = ship_status, public)]
+pub struct ShipStatus {
+    #[primary_key]
+    #[use_wrapper(path = ShipId)]
+    #[foreign_key(path = crate::tables, table = ship, column = id, on_delete = Delete)]
+    ship_id: u64,
+
+    pub hull: f32,
+
+    /// Energy stored in the capacitor as of `energy_updated_at`, drawn by weapons and boosting.
+    /// It recharges over time, see `weapons::current_energy`.
+    pub energy: f32,
+    pub energy_updated_at: Timestamp,
+
+    /// Fuel left in the tank, burnt as the ship flies, see `fuel::burn_fuel`.
+    pub fuel: f32,
+
+    /// Shield points as of `damaged_at`, when the ship was last hit. It regenerates after a
+    /// delay, see `damage::current_shield`.
+    pub shield: f32,
+    pub damaged_at: Timestamp,
+
+    /// Condition of each subsystem, from 1 when intact down to 0 when wrecked. Damaged
+    /// subsystems perform worse, see `damage::efficiency`.
+    ///
+    /// Engines drive the main thrust, thrusters the vertical and lateral thrust and the
+    /// rotation, weapons scale the damage of every shot and sensors the range of missile locks.
+    pub engines: f32,
+    pub thrusters: f32,
+    pub weapons: f32,
+    pub sensors: f32,
+}
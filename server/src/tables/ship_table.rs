@@ -1,4 +1,4 @@
-use crate::tables::PlayerId;
+use crate::tables::{PlayerId, ShipTypeId};
 use spacetimedb::{table, Identity};
 use spacetimedsl::dsl;
 
@@ -10,12 +10,26 @@ pub struct Ship {
     #[create_wrapper]
     #[referenced_by(path = crate::tables, table = ship_pilot)]
     #[referenced_by(path = crate::tables, table = ship_location)]
+    #[referenced_by(path = crate::tables, table = ship_access)]
+    #[referenced_by(path = crate::tables, table = cargo)]
+    #[referenced_by(path = crate::tables, table = ship_mining)]
+    #[referenced_by(path = crate::tables, table = ship_docking)]
+    #[referenced_by(path = crate::tables, table = ship_status)]
+    #[referenced_by(path = crate::tables, table = weapon_fire)]
+    #[referenced_by(path = crate::tables, table = ship_flight)]
     id: u64,
 
-    // TODO: Set foreign keys
+    #[index(btree)]
+    #[use_wrapper(path = ShipTypeId)]
+    #[foreign_key(path = crate::tables, table = ship_type, column = id, on_delete = Error)]
     ship_type_id: u64,
 
+    /// The player owning the ship, or who handed it over to its faction.
     #[index(btree)]
     #[use_wrapper(path = PlayerId)]
-    owner_id: Identity,
+    pub owner_id: Identity,
+
+    /// The faction the ship has been handed over to, if any, see `Faction::id`. Its ranks then
+    /// decide who pilots the ship, rather than its owner.
+    pub faction_id: Option<Identity>,
 }
@@ -7,11 +7,18 @@ pub struct ShipType {
     #[primary_key]
     #[auto_inc]
     #[create_wrapper]
+    #[referenced_by(path = crate::tables, table = ship)]
+    #[referenced_by(path = crate::tables, table = hardpoint)]
     id: u64,
     name: String,
+    model: String,
+    collider: String,
     camera_offset_x: f32,
     camera_offset_y: f32,
     camera_offset_z: f32,
+    exit_offset_x: f32,
+    exit_offset_y: f32,
+    exit_offset_z: f32,
     mass: f32,
     linear_damping: f32,
     angular_damping: f32,
@@ -21,4 +28,36 @@ pub struct ShipType {
     pitch_torque: f32,
     yaw_torque: f32,
     roll_torque: f32,
+    /// Volume of cargo ships of this type can carry.
+    cargo_capacity: f32,
+    /// Radius of the sphere around ships of this type that shots are tested against.
+    radius: f32,
+    /// Hull points of an undamaged ship.
+    max_hull: f32,
+    /// Energy ships of this type can store to fire their weapons.
+    max_energy: f32,
+    /// Energy recharged per second.
+    energy_recharge: f32,
+    /// Shield points of a fully charged shield, which takes damage before the hull.
+    max_shield: f32,
+    /// Shield points regenerated per second, once the ship hasn't been hit for `shield_delay`
+    /// seconds.
+    shield_recharge: f32,
+    shield_delay: f32,
+    /// Fraction of the damage of each type the armor stops from reaching the hull.
+    armor_energy: f32,
+    armor_kinetic: f32,
+    armor_explosive: f32,
+    /// Fuel the tank of ships of this type holds.
+    max_fuel: f32,
+    /// Fuel burnt per world unit flown.
+    fuel_consumption: f32,
+    /// Factor applied to the main thrust while boosting.
+    boost_factor: f32,
+    /// Energy drained from the capacitor per second of boosting.
+    boost_energy_drain: f32,
+
+    /// Retired ship types can no longer be spawned, but existing ships of that type keep flying.
+    /// Ship types can't be deleted while ships reference them.
+    pub retired: bool,
 }
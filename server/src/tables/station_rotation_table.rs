@@ -0,0 +1,27 @@
+use crate::tables::StationId;
+use spacetimedb::{table, SpacetimeType, Timestamp};
+use spacetimedsl::dsl;
+
+/// An axis a station rotates around, in world space. It doesn't need to be normalized.
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+pub struct RotationAxis {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+/// How a station rotates: it was at `epoch_angle` around its axis at `epoch`, and turns `speed`
+/// radians per second around `axis`, the vertical axis if `None`. See `world::station_rotation`.
+#[dsl(plural_name = station_rotations)]
+#[table(name = station_rotation, public)]
+pub struct StationRotation {
+    #[primary_key]
+    #[use_wrapper(path = StationId)]
+    #[foreign_key(path = crate::tables, table = station, column = id, on_delete = Delete)]
+    station_id: u64,
+
+    pub epoch: Timestamp,
+    pub epoch_angle: f32,
+    pub speed: f32,
+    axis: Option<RotationAxis>,
+}
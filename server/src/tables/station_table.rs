@@ -7,12 +7,29 @@ pub struct Station {
     #[primary_key]
     #[auto_inc]
     #[create_wrapper]
+    #[referenced_by(path = crate::tables, table = docking_bay)]
+    #[referenced_by(path = crate::tables, table = ship_docking)]
+    #[referenced_by(path = crate::tables, table = market)]
+    #[referenced_by(path = crate::tables, table = home_station)]
+    #[referenced_by(path = crate::tables, table = station_rotation)]
     id: u64,
     name: String,
     x: f32,
     y: f32,
     z: f32,
+
+    /// Rotation of the station as it was kept before `station_rotation`: it reaches
+    /// `target_angle` around the vertical axis at `reach_angle_at`, in milliseconds since the
+    /// Unix epoch, turning `rotation_speed` radians per second. Nothing updates these anymore,
+    /// they're only read to give the stations of older databases their `station_rotation`, see
+    /// `world::world_update_stations_rotation`.
     rotation_speed: f32,
-    pub target_angle: f32,
-    pub reach_angle_at: u128,
+    target_angle: f32,
+    reach_angle_at: u128,
+
+    /// Coordinates of the sector containing this station, see `sectors::sector_of`.
+    #[index(btree)]
+    sector_x: i32,
+    sector_y: i32,
+    sector_z: i32,
 }
@@ -0,0 +1,15 @@
+use crate::tables::PlayerId;
+use spacetimedb::{table, Identity};
+use spacetimedsl::dsl;
+
+/// The credits a player has to spend at station markets.
+#[dsl(plural_name = wallets)]
+#[table(name = wallet, public)]
+pub struct Wallet {
+    #[primary_key]
+    #[use_wrapper(path = PlayerId)]
+    #[foreign_key(path = crate::tables, table = player, column = id, on_delete = Delete)]
+    player_id: Identity,
+
+    pub credits: u64,
+}
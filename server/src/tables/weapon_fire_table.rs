@@ -0,0 +1,39 @@
+use crate::tables::{HardpointId, ShipId};
+use spacetimedb::{table, Timestamp};
+use spacetimedsl::dsl;
+
+/// The last shot of each hardpoint of a ship, used to pace shots and to show them to nearby
+/// players.
+#[dsl(plural_name = weapon_fires)]
+#[table(name = weapon_fire, public)]
+pub struct WeaponFire {
+    #[primary_key]
+    #[auto_inc]
+    #[create_wrapper]
+    id: u64,
+
+    #[index(btree)]
+    #[use_wrapper(path = ShipId)]
+    #[foreign_key(path = crate::tables, table = ship, column = id, on_delete = Delete)]
+    ship_id: u64,
+
+    #[index(btree)]
+    #[use_wrapper(path = HardpointId)]
+    #[foreign_key(path = crate::tables, table = hardpoint, column = id, on_delete = Delete)]
+    hardpoint_id: u64,
+
+    /// Where the shot left from, in the world.
+    origin_x: f32,
+    origin_y: f32,
+    origin_z: f32,
+
+    /// Where the shot stopped, either on the ship it hit or at the end of the weapon's range.
+    end_x: f32,
+    end_y: f32,
+    end_z: f32,
+
+    /// The ship hit by the shot, if any.
+    hit_ship_id: Option<u64>,
+
+    fired_at: Timestamp,
+}
@@ -0,0 +1,34 @@
+use serde::Deserialize;
+use spacetimedb::{table, SpacetimeType};
+use spacetimedsl::dsl;
+
+/// How a weapon deals damage, which decides how much of it the armor of the ship hit stops.
+#[derive(SpacetimeType, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DamageType {
+    Energy,
+    Kinetic,
+    Explosive,
+}
+
+#[dsl(plural_name = weapon_types)]
+#[table(name = weapon_type, public)]
+pub struct WeaponType {
+    #[primary_key]
+    #[auto_inc]
+    #[create_wrapper]
+    #[referenced_by(path = crate::tables, table = hardpoint)]
+    #[referenced_by(path = crate::tables, table = missile_type)]
+    #[referenced_by(path = crate::tables, table = missile)]
+    id: u64,
+    #[unique]
+    name: String,
+    damage_type: DamageType,
+    /// Damage dealt to the ship hit by a shot, before its shield and armor.
+    damage: f32,
+    /// How far a shot reaches, in world units.
+    range: f32,
+    /// Seconds between two shots of the same hardpoint.
+    cooldown: f32,
+    /// Energy drawn from the ship for each shot.
+    energy_cost: f32,
+}
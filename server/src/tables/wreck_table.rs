@@ -0,0 +1,38 @@
+use spacetimedb::{table, Timestamp};
+use spacetimedsl::dsl;
+
+/// A piece of debris left where a ship was destroyed, removed after a while by
+/// `destruction::world_clear_wrecks`.
+#[dsl(plural_name = wrecks)]
+#[table(name = wreck, public)]
+pub struct Wreck {
+    #[primary_key]
+    #[auto_inc]
+    #[create_wrapper]
+    id: u64,
+
+    /// The ship the debris comes from. Not a foreign key, the ship is gone.
+    #[index(btree)]
+    ship_id: u64,
+
+    pos_x: f32,
+    pos_y: f32,
+    pos_z: f32,
+    rot_x: f32,
+    rot_y: f32,
+    rot_z: f32,
+    rot_w: f32,
+
+    /// Which of the debris models the piece is: 0 to 7 are `debris_shell_*`, 8 to 10
+    /// `debris_structure_*`. Mirrors the client's `world/wrecks.rs`.
+    debris_type: u8,
+    scale: f32,
+
+    /// Coordinates of the sector containing this piece, see `sectors::sector_of`.
+    #[index(btree)]
+    sector_x: i32,
+    sector_y: i32,
+    sector_z: i32,
+
+    created_at: Timestamp,
+}
@@ -0,0 +1,50 @@
+use serde::Deserialize;
+
+use crate::tables::DamageType;
+
+/// The weapon catalog, embedded in the module so it's seeded along with the rest of the world.
+const WEAPON_CATALOG: &str = include_str!("../data/weapons.ron");
+
+/// A weapon that can be mounted on a hardpoint, as described in `data/weapons.ron`.
+#[derive(Debug, Deserialize)]
+pub struct WeaponDefinition {
+    pub name: String,
+    pub damage_type: DamageType,
+    /// Damage dealt to the ship hit by a shot, before its shield and armor.
+    pub damage: f32,
+    /// How far a shot reaches, in world units.
+    pub range: f32,
+    /// Seconds between two shots.
+    pub cooldown: f32,
+    /// Energy drawn from the ship for each shot.
+    pub energy_cost: f32,
+    /// The missile launched by the weapon, if it's a launcher.
+    #[serde(default)]
+    pub missile: Option<MissileDefinition>,
+}
+
+/// A guided missile, steered towards its target by `missiles::world_update_missiles`.
+#[derive(Debug, Deserialize)]
+pub struct MissileDefinition {
+    /// Asset path of the missile's model, resolved by the client.
+    pub model: String,
+    /// Top speed of the missile, in world units per second.
+    pub speed: f32,
+    /// Most acceleration the missile's motor can put out, to reach its speed and to turn.
+    pub acceleration: f32,
+    /// Seconds of flight before the missile runs dry and blows up.
+    pub fuel: f32,
+    /// How hard the missile turns to cancel the rotation of its line of sight to the target.
+    pub navigation_gain: f32,
+    /// How close to the hull of a ship the missile must pass to detonate.
+    pub proximity_radius: f32,
+    /// Radius of the explosion, ships within it take less damage the further they are.
+    pub blast_radius: f32,
+}
+
+/// Parses the embedded weapon catalog.
+///
+/// Weapon types are created in catalog order, so the n-th entry gets the weapon type id n.
+pub fn weapon_catalog() -> Vec<WeaponDefinition> {
+    ron::from_str(WEAPON_CATALOG).expect("Invalid weapon catalog")
+}
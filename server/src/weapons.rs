@@ -0,0 +1,190 @@
+use std::time::Duration;
+
+use glam::{Quat, Vec3};
+use simulation::{damage::efficiency, ranges::ray_sphere_intersection};
+use spacetimedb::{reducer, ReducerContext, Timestamp};
+use spacetimedsl::{dsl, Wrapper};
+
+use crate::{admin::ensure_not_banned, damage::damage_ship, tables::*};
+
+/// Energy a ship has at `now`, having recharged since its status was last updated.
+pub fn current_energy(status: &ShipStatus, ship_type: &ShipType, now: Timestamp) -> f32 {
+    let elapsed = now
+        .duration_since(*status.get_energy_updated_at())
+        .unwrap_or_default();
+    simulation::damage::current_energy(
+        *status.get_energy(),
+        elapsed.as_secs_f32(),
+        *ship_type.get_max_energy(),
+        *ship_type.get_energy_recharge(),
+    )
+}
+
+/// A weapon of the ship the player is piloting, cleared to fire by `ready_weapon`.
+pub struct ReadyWeapon {
+    pub ship_id: ShipId,
+    pub hardpoint: Hardpoint,
+    pub weapon_type: WeaponType,
+    /// Damage the shot deals, lessened by damage to the ship's weapons.
+    pub damage: f32,
+    /// How far the weapon reaches, lessened by damage to the ship's sensors.
+    pub range: f32,
+    /// Where the shot leaves the hardpoint, in world space.
+    pub origin: Vec3,
+    /// Where the ship, and so the weapon, is pointing.
+    pub direction: Vec3,
+}
+
+/// Checks that a weapon of the ship the player is piloting can fire, and charges the ship's
+/// energy for the shot.
+///
+/// The previous shot of the weapon is removed, the caller records the new one in `weapon_fire`.
+pub fn ready_weapon(ctx: &ReducerContext, hardpoint_id: u64) -> Result<ReadyWeapon, String> {
+    let dsl = dsl(ctx);
+
+    let Ok(ship_pilot) = dsl.get_ship_pilot_by_player_id(&PlayerId::new(ctx.sender)) else {
+        return Err("Player is not piloting a ship".into());
+    };
+    let ship_id = ship_pilot.get_ship_id();
+    let ship = dsl.get_ship_by_id(&ship_id)?;
+    let ship_type = dsl.get_ship_type_by_id(ship.get_ship_type_id())?;
+
+    let Ok(hardpoint) = dsl.get_hardpoint_by_id(HardpointId::new(hardpoint_id)) else {
+        return Err("Hardpoint not found".into());
+    };
+    if hardpoint.get_ship_type_id() != ship.get_ship_type_id() {
+        return Err("This hardpoint is not on the ship".into());
+    }
+    let weapon_type = dsl.get_weapon_type_by_id(hardpoint.get_weapon_type_id())?;
+
+    let Ok(ship_location) = dsl.get_ship_location_by_ship_id(&ship_id) else {
+        return Err("Weapons cannot be fired while docked".into());
+    };
+
+    let previous = dsl
+        .get_weapon_fires_by_ship_id(&ship_id)
+        .find(|fire| fire.get_hardpoint_id() == hardpoint.get_id());
+    if let Some(previous) = previous {
+        let elapsed = ctx
+            .timestamp
+            .duration_since(*previous.get_fired_at())
+            .unwrap_or_default();
+        // Accepted slightly early to absorb network jitter.
+        if elapsed < Duration::from_secs_f32(*weapon_type.get_cooldown()).mul_f32(0.9) {
+            return Err("Weapon is still cooling down".into());
+        }
+
+        dsl.delete_weapon_fire_by_id(previous.get_id())?;
+    }
+
+    let mut status = dsl.get_ship_status_by_ship_id(&ship_id)?;
+    let energy = current_energy(&status, &ship_type, ctx.timestamp);
+    if energy < *weapon_type.get_energy_cost() {
+        return Err("Not enough energy to fire".into());
+    }
+    let damage = weapon_type.get_damage() * efficiency(*status.get_weapons());
+    let range = weapon_type.get_range() * efficiency(*status.get_sensors());
+    status.set_energy(energy - weapon_type.get_energy_cost());
+    status.set_energy_updated_at(ctx.timestamp);
+    dsl.update_ship_status_by_ship_id(status)?;
+
+    let rotation = Quat::from_xyzw(
+        *ship_location.get_rot_x(),
+        *ship_location.get_rot_y(),
+        *ship_location.get_rot_z(),
+        *ship_location.get_rot_w(),
+    )
+    .normalize();
+    let origin = Vec3::new(
+        *ship_location.get_x(),
+        *ship_location.get_y(),
+        *ship_location.get_z(),
+    ) + rotation
+        * Vec3::new(
+            *hardpoint.get_offset_x(),
+            *hardpoint.get_offset_y(),
+            *hardpoint.get_offset_z(),
+        );
+
+    Ok(ReadyWeapon {
+        ship_id,
+        hardpoint,
+        weapon_type,
+        damage,
+        range,
+        origin,
+        direction: rotation * Vec3::NEG_Z,
+    })
+}
+
+/// Fires one of the weapons of the ship the player is piloting, straight ahead of the ship.
+///
+/// Shots are resolved right away against the bounding spheres of the other ships in flight,
+/// the closest one taking the damage. Missile launchers are fired with
+/// `missiles::launch_missile` instead.
+#[reducer]
+pub fn fire_weapon(ctx: &ReducerContext, hardpoint_id: u64) -> Result<(), String> {
+    ensure_not_banned(ctx)?;
+    let dsl = dsl(ctx);
+
+    let ReadyWeapon {
+        ship_id,
+        hardpoint,
+        weapon_type,
+        damage,
+        range,
+        origin,
+        direction,
+    } = ready_weapon(ctx, hardpoint_id)?;
+    if dsl
+        .get_missile_type_by_weapon_type_id(weapon_type.get_id())
+        .is_ok()
+    {
+        return Err("Missiles must be launched at a target".into());
+    }
+
+    let mut hit: Option<(ShipId, f32)> = None;
+    for target_location in dsl.get_all_ship_locations() {
+        let target_id = target_location.get_ship_id();
+        if target_id == ship_id {
+            continue;
+        }
+
+        let target = dsl.get_ship_by_id(&target_id)?;
+        let target_type = dsl.get_ship_type_by_id(target.get_ship_type_id())?;
+        let center = Vec3::new(
+            *target_location.get_x(),
+            *target_location.get_y(),
+            *target_location.get_z(),
+        );
+        let Some(distance) =
+            ray_sphere_intersection(origin, direction, center, *target_type.get_radius())
+        else {
+            continue;
+        };
+
+        if distance <= range && hit.as_ref().is_none_or(|(_, closest)| distance < *closest) {
+            hit = Some((target_id, distance));
+        }
+    }
+
+    let end = origin + direction * hit.as_ref().map_or(range, |(_, distance)| *distance);
+    if let Some((target_id, _)) = &hit {
+        damage_ship(ctx, target_id, damage, *weapon_type.get_damage_type())?;
+    }
+
+    dsl.create_weapon_fire(
+        &ship_id,
+        hardpoint.get_id(),
+        origin.x,
+        origin.y,
+        origin.z,
+        end.x,
+        end.y,
+        end.z,
+        hit.map(|(target_id, _)| target_id.value()),
+        ctx.timestamp,
+    )?;
+
+    Ok(())
+}
@@ -1,10 +1,19 @@
-use std::{f32::consts::TAU, ops::Add};
+use glam::{Quat, Vec3};
+use log::info;
+use simulation::stations;
+use spacetimedb::{reducer, table, ReducerContext, ScheduleAt, Timestamp};
+use spacetimedsl::{dsl, Wrapper};
 
-use spacetimedb::{reducer, table, ScheduleAt};
-use spacetimedsl::dsl;
+use crate::{
+    docking::create_docking_bays, market::create_station_markets, sectors::sector_of, tables::*,
+};
 
-use crate::tables::{GetAllStationRows, UpdateStationRowById};
+/// The speed at which stations rotate in the world, in radians per second.
+const STATIONS_ROTATION_SPEED: f32 = 0.01;
 
+/// Moved the stations along their rotation before they had a `station_rotation`. Nothing
+/// schedules it anymore, the table is kept for databases it was scheduled in: the first update
+/// after publishing over one of them migrates its stations and removes the schedule.
 #[table(name = station_rotation_update, scheduled(world_update_stations_rotation))]
 pub struct StationRotationUpdate {
     #[primary_key]
@@ -13,42 +22,117 @@ pub struct StationRotationUpdate {
     scheduled_at: ScheduleAt,
 }
 
-impl StationRotationUpdate {
-    pub fn new(scheduled_at: ScheduleAt) -> Self {
-        Self {
-            scheduled_id: 0,
-            scheduled_at,
-        }
-    }
+/// Builds a station, with its docking bays and markets.
+pub fn spawn_station(ctx: &ReducerContext, name: &str, position: Vec3) -> Result<Station, String> {
+    let dsl = dsl(ctx);
+
+    let [sector_x, sector_y, sector_z] = sector_of(position.x, position.y, position.z);
+    let station = dsl.create_station(
+        name,
+        position.x,
+        position.y,
+        position.z,
+        STATIONS_ROTATION_SPEED,
+        0.0,
+        ctx.timestamp.to_micros_since_unix_epoch() as u128 / 1000,
+        sector_x,
+        sector_y,
+        sector_z,
+    )?;
+    dsl.create_station_rotation(
+        station.get_id(),
+        ctx.timestamp,
+        0.0,
+        STATIONS_ROTATION_SPEED,
+        None,
+    )?;
+    create_docking_bays(ctx, &station.get_id())?;
+    create_station_markets(ctx, &station.get_id())?;
+
+    Ok(station)
 }
 
 #[reducer]
 pub fn world_update_stations_rotation(
-    ctx: &spacetimedb::ReducerContext,
+    ctx: &ReducerContext,
     update: StationRotationUpdate,
-) {
+) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Stations can only be migrated by the server".to_string());
+    }
+
+    migrate_station_rotations(ctx)?;
+    ctx.db
+        .station_rotation_update()
+        .scheduled_id()
+        .delete(update.scheduled_id);
+
+    Ok(())
+}
+
+/// Gives the stations that don't have a `station_rotation` yet the one they were turning along,
+/// from the target angle kept on their row.
+pub fn migrate_station_rotations(ctx: &ReducerContext) -> Result<(), String> {
     let dsl = dsl(ctx);
 
-    let update_interval = match update.scheduled_at {
-        ScheduleAt::Interval(interval) => interval.to_duration().unwrap(),
-        ScheduleAt::Time(_) => panic!("Station rotation update should be scheduled as an interval"),
-    };
-
-    let reach_angle_at = ctx
-        .timestamp
-        .to_duration_since_unix_epoch()
-        .unwrap()
-        .add(update_interval)
-        .as_millis();
-
-    for mut station in dsl.get_all_stations() {
-        let target_angle = (station.get_target_angle()
-            + station.get_rotation_speed() * update_interval.as_secs_f32())
-        .rem_euclid(TAU);
-
-        station.set_target_angle(target_angle);
-        station.set_reach_angle_at(reach_angle_at);
-        dsl.update_station_by_id(station)
-            .expect("Failed to update station rotation");
+    for station in dsl.get_all_stations() {
+        if dsl
+            .get_station_rotation_by_station_id(&station.get_id())
+            .is_ok()
+        {
+            continue;
+        }
+
+        let rotation = legacy_station_rotation(&station);
+        dsl.create_station_rotation(
+            station.get_id(),
+            Timestamp::from_micros_since_unix_epoch(rotation.epoch),
+            rotation.epoch_angle,
+            rotation.speed,
+            None,
+        )?;
+        info!(
+            "Migrated the rotation of station {}",
+            station.get_id().value()
+        );
     }
+
+    Ok(())
+}
+
+/// How a station rotates according to the target angle kept on its row.
+fn legacy_station_rotation(station: &Station) -> stations::StationRotation {
+    stations::StationRotation::from_target(
+        *station.get_target_angle(),
+        *station.get_rotation_speed(),
+        *station.get_reach_angle_at(),
+    )
+}
+
+/// How a station rotates. Stations of a database that hasn't been migrated yet turn as their row
+/// says until it is.
+pub fn station_rotation(ctx: &ReducerContext, station: &Station) -> stations::StationRotation {
+    dsl(ctx)
+        .get_station_rotation_by_station_id(&station.get_id())
+        .map(|rotation| (&rotation).into())
+        .unwrap_or_else(|_| legacy_station_rotation(station))
+}
+
+impl From<&StationRotation> for stations::StationRotation {
+    fn from(rotation: &StationRotation) -> Self {
+        Self {
+            epoch: rotation.get_epoch().to_micros_since_unix_epoch(),
+            epoch_angle: *rotation.get_epoch_angle(),
+            speed: *rotation.get_speed(),
+            axis: rotation
+                .get_axis()
+                .as_ref()
+                .map(|axis| Vec3::new(axis.x, axis.y, axis.z)),
+        }
+    }
+}
+
+/// The orientation of a station at the given time.
+pub fn station_orientation(ctx: &ReducerContext, station: &Station, at: Timestamp) -> Quat {
+    station_rotation(ctx, station).orientation_at(at.to_micros_since_unix_epoch())
 }
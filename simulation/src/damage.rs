@@ -0,0 +1,152 @@
+/// Share of its performance a wrecked subsystem keeps, so a crippled ship can still limp back to
+/// a station.
+pub const MIN_EFFICIENCY: f32 = 0.25;
+
+/// Condition a subsystem loses for every share of the ship's hull points the hull loses, the
+/// subsystem taking the hit being picked at random.
+pub const SUBSYSTEM_DAMAGE_FACTOR: f32 = 2.0;
+
+/// Credits charged at stations per hull point repaired.
+pub const HULL_REPAIR_COST: f32 = 2.0;
+
+/// Credits charged at stations to bring a subsystem from wrecked back to intact, per hull point
+/// of the ship, bigger ships having bigger subsystems.
+pub const SUBSYSTEM_REPAIR_COST: f32 = 0.5;
+
+/// How well a subsystem in the given condition performs, as a factor of its full performance.
+pub fn efficiency(condition: f32) -> f32 {
+    MIN_EFFICIENCY + (1.0 - MIN_EFFICIENCY) * condition.clamp(0.0, 1.0)
+}
+
+/// Shield points of a shield that had `shield` points when last hit, `since_hit` seconds ago. It
+/// regenerates `recharge` points per second once `delay` seconds have passed, up to `max`.
+pub fn current_shield(shield: f32, since_hit: f32, max: f32, recharge: f32, delay: f32) -> f32 {
+    let recharging = (since_hit - delay).max(0.0);
+    (shield + recharge * recharging).min(max)
+}
+
+/// Energy in a capacitor that held `energy`, `elapsed` seconds ago, recharging `recharge` per
+/// second up to `max`.
+pub fn current_energy(energy: f32, elapsed: f32, max: f32, recharge: f32) -> f32 {
+    (energy + recharge * elapsed).min(max)
+}
+
+/// Splits damage between a shield and the hull behind it.
+///
+/// The shield soaks up as much of the damage as it has points left, and the armor stops its
+/// share of the rest. Returns the shield points left and the damage dealt to the hull.
+pub fn absorb_damage(shield: f32, damage: f32, resistance: f32) -> (f32, f32) {
+    let absorbed = damage.min(shield);
+    let hull_damage = (damage - absorbed) * (1.0 - resistance.clamp(0.0, 1.0));
+    (shield - absorbed, hull_damage)
+}
+
+/// Condition a subsystem loses when the hull of a ship with `max_hull` points takes
+/// `hull_damage`.
+pub fn subsystem_wear(hull_damage: f32, max_hull: f32) -> f32 {
+    hull_damage / max_hull.max(1.0) * SUBSYSTEM_DAMAGE_FACTOR
+}
+
+/// Credits it costs to repair a hull down to `hull` points out of `max_hull`, along with
+/// subsystems in the given conditions.
+pub fn repair_cost(hull: f32, max_hull: f32, subsystems: [f32; 4]) -> u64 {
+    let missing_hull = (max_hull - hull).max(0.0);
+    let missing_subsystems = subsystems
+        .into_iter()
+        .map(|condition| 1.0 - condition.clamp(0.0, 1.0))
+        .sum::<f32>();
+
+    (missing_hull * HULL_REPAIR_COST + missing_subsystems * max_hull * SUBSYSTEM_REPAIR_COST).ceil()
+        as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn wrecked_subsystems_keep_some_efficiency() {
+        assert_eq!(efficiency(0.0), MIN_EFFICIENCY);
+        assert_eq!(efficiency(1.0), 1.0);
+        assert_eq!(efficiency(0.5), 0.625);
+    }
+
+    #[test]
+    fn shield_waits_for_its_delay_before_regenerating() {
+        assert_eq!(current_shield(10.0, 2.0, 100.0, 5.0, 3.0), 10.0);
+        assert_eq!(current_shield(10.0, 3.0, 100.0, 5.0, 3.0), 10.0);
+        assert_eq!(current_shield(10.0, 5.0, 100.0, 5.0, 3.0), 20.0);
+        assert_eq!(current_shield(10.0, 60.0, 100.0, 5.0, 3.0), 100.0);
+    }
+
+    #[test]
+    fn capacitor_recharges_up_to_its_capacity() {
+        assert_eq!(current_energy(10.0, 2.0, 100.0, 5.0), 20.0);
+        assert_eq!(current_energy(10.0, 60.0, 100.0, 5.0), 100.0);
+    }
+
+    #[test]
+    fn shield_soaks_damage_before_the_armor() {
+        assert_eq!(absorb_damage(30.0, 20.0, 0.5), (10.0, 0.0));
+        assert_eq!(absorb_damage(30.0, 50.0, 0.5), (0.0, 10.0));
+        assert_eq!(absorb_damage(0.0, 50.0, 0.0), (0.0, 50.0));
+        assert_eq!(absorb_damage(0.0, 50.0, 1.0), (0.0, 0.0));
+    }
+
+    #[test]
+    fn intact_ships_cost_nothing_to_repair() {
+        assert_eq!(repair_cost(100.0, 100.0, [1.0; 4]), 0);
+        assert_eq!(repair_cost(90.0, 100.0, [1.0; 4]), 20);
+        assert_eq!(repair_cost(100.0, 100.0, [0.0, 1.0, 1.0, 1.0]), 50);
+    }
+
+    proptest! {
+        #[test]
+        fn efficiency_stays_between_min_and_full(condition in -10.0f32..10.0) {
+            let efficiency = efficiency(condition);
+            prop_assert!((MIN_EFFICIENCY..=1.0).contains(&efficiency));
+        }
+
+        #[test]
+        fn efficiency_grows_with_condition(a in 0.0f32..1.0, b in 0.0f32..1.0) {
+            let (worse, better) = if a <= b { (a, b) } else { (b, a) };
+            prop_assert!(efficiency(worse) <= efficiency(better));
+        }
+
+        #[test]
+        fn damage_is_never_created(
+            shield in 0.0f32..1000.0,
+            damage in 0.0f32..1000.0,
+            resistance in -1.0f32..2.0,
+        ) {
+            let (shield_left, hull_damage) = absorb_damage(shield, damage, resistance);
+            prop_assert!(shield_left >= 0.0 && shield_left <= shield);
+            prop_assert!(hull_damage >= 0.0);
+            prop_assert!((shield - shield_left) + hull_damage <= damage + 1e-3);
+        }
+
+        #[test]
+        fn shield_never_exceeds_its_maximum(
+            shield in 0.0f32..100.0,
+            since_hit in 0.0f32..1000.0,
+            recharge in 0.0f32..50.0,
+            delay in 0.0f32..10.0,
+        ) {
+            let current = current_shield(shield, since_hit, 100.0, recharge, delay);
+            prop_assert!(current >= shield && current <= 100.0);
+        }
+
+        #[test]
+        fn repairs_cost_more_for_more_damage(
+            hull in 0.0f32..100.0,
+            more_damage in 0.0f32..100.0,
+            condition in 0.0f32..1.0,
+        ) {
+            let damaged = repair_cost(hull, 100.0, [condition; 4]);
+            let more_damaged = repair_cost((hull - more_damage).max(0.0), 100.0, [condition; 4]);
+            prop_assert!(more_damaged >= damaged);
+        }
+    }
+}
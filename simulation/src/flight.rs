@@ -0,0 +1,466 @@
+use std::time::Duration;
+
+use glam::{Quat, Vec3};
+
+use crate::damage::efficiency;
+
+/// Duration of a step of the flight simulation. Ships flown by the server are advanced by whole
+/// steps, and clients predicting them step at the same rate to get the same results.
+pub const FLIGHT_STEP: Duration = Duration::from_millis(50);
+
+/// Share of their power the RCS thrusters, vertical and lateral thrust and rotation, keep once
+/// the tank is empty. The main thrust is cut entirely.
+pub const EMPTY_TANK_RCS_FACTOR: f32 = 0.5;
+
+/// Speed, in units or radians per second, below which a ship left alone by its pilot comes to
+/// rest rather than drifting ever slower.
+pub const REST_SPEED: f32 = 1e-3;
+
+/// What the pilot asks of the ship. Every axis goes from -1 to 1.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FlightControls {
+    pub thrust: f32,
+    pub lateral_thrust: f32,
+    pub vertical_thrust: f32,
+    pub roll: f32,
+    pub pitch: f32,
+    pub yaw: f32,
+    pub boost: bool,
+}
+
+impl FlightControls {
+    /// Whether none of the axes are pushed, boosting alone doing nothing.
+    pub fn is_idle(&self) -> bool {
+        [
+            self.thrust,
+            self.lateral_thrust,
+            self.vertical_thrust,
+            self.roll,
+            self.pitch,
+            self.yaw,
+        ]
+        .iter()
+        .all(|value| *value == 0.0)
+    }
+
+    /// The controls with every axis brought back between -1 and 1, non-finite values counting
+    /// as 0.
+    pub fn clamped(self) -> Self {
+        let clamp = |value: f32| {
+            if value.is_finite() {
+                value.clamp(-1.0, 1.0)
+            } else {
+                0.0
+            }
+        };
+
+        Self {
+            thrust: clamp(self.thrust),
+            lateral_thrust: clamp(self.lateral_thrust),
+            vertical_thrust: clamp(self.vertical_thrust),
+            roll: clamp(self.roll),
+            pitch: clamp(self.pitch),
+            yaw: clamp(self.yaw),
+            boost: self.boost,
+        }
+    }
+}
+
+/// How a ship responds to its controls, from its ship type and what it carries.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ShipDynamics {
+    /// Mass of the ship, cargo included.
+    pub mass: f32,
+    /// Radius of the ship, which gives its moment of inertia.
+    pub radius: f32,
+    pub linear_damping: f32,
+    pub angular_damping: f32,
+    pub thrust: f32,
+    pub vertical_thrust: f32,
+    pub lateral_thrust: f32,
+    pub pitch_torque: f32,
+    pub yaw_torque: f32,
+    pub roll_torque: f32,
+}
+
+impl ShipDynamics {
+    /// Moment of inertia of the ship, taken as a solid sphere of its mass and radius.
+    pub fn inertia(&self) -> f32 {
+        (0.4 * self.mass * self.radius * self.radius).max(f32::EPSILON)
+    }
+}
+
+/// Factors applied to the forces a ship's controls produce: `engines` to the main thrust,
+/// boosting included, and `thrusters` to the vertical and lateral thrust and the rotation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Power {
+    pub engines: f32,
+    pub thrusters: f32,
+}
+
+impl Power {
+    pub const FULL: Self = Self {
+        engines: 1.0,
+        thrusters: 1.0,
+    };
+
+    /// Power of a ship whose engines and thrusters are in the given condition, see
+    /// `damage::efficiency`, multiplying its main thrust by `boost`.
+    ///
+    /// Once the tank is empty the main thrust is cut and the RCS thrusters only trickle.
+    pub fn new(engines: f32, thrusters: f32, fueled: bool, boost: f32) -> Self {
+        if !fueled {
+            return Self {
+                engines: 0.0,
+                thrusters: efficiency(thrusters) * EMPTY_TANK_RCS_FACTOR,
+            };
+        }
+
+        Self {
+            engines: efficiency(engines) * boost,
+            thrusters: efficiency(thrusters),
+        }
+    }
+}
+
+/// Where a ship is and how it moves.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FlightState {
+    pub position: Vec3,
+    pub rotation: Quat,
+    pub linear_velocity: Vec3,
+    pub angular_velocity: Vec3,
+}
+
+impl FlightState {
+    /// A ship at rest.
+    pub fn at_rest(position: Vec3, rotation: Quat) -> Self {
+        Self {
+            position,
+            rotation,
+            linear_velocity: Vec3::ZERO,
+            angular_velocity: Vec3::ZERO,
+        }
+    }
+}
+
+/// The force and torque, in world space, the controls of a ship rotated by `rotation` produce.
+///
+/// They push the ship along and around its own axes: the main thrust forward, the vertical and
+/// lateral thrust up and right, and the roll, pitch and yaw around its back, right and up axes.
+pub fn forces(
+    dynamics: &ShipDynamics,
+    rotation: Quat,
+    controls: &FlightControls,
+    power: Power,
+) -> (Vec3, Vec3) {
+    let forward = rotation * Vec3::NEG_Z;
+    let right = rotation * Vec3::X;
+    let up = rotation * Vec3::Y;
+    let back = rotation * Vec3::Z;
+
+    let force = forward * controls.thrust * dynamics.thrust * power.engines
+        + up * controls.vertical_thrust * dynamics.vertical_thrust * power.thrusters
+        + right * controls.lateral_thrust * dynamics.lateral_thrust * power.thrusters;
+    let torque = (back * controls.roll * dynamics.roll_torque
+        + right * controls.pitch * dynamics.pitch_torque
+        + up * controls.yaw * dynamics.yaw_torque)
+        * power.thrusters;
+
+    (force, torque)
+}
+
+/// Speed a ship of the given mass and linear damping settles at when pushed by a constant
+/// `force`, stepped every `dt` seconds. A ship without any linear damping has no terminal
+/// velocity.
+///
+/// The forces being scaled by the step, see `step`, a ship pushed by `F` settles where damping
+/// takes away what the force adds: `F * dt / (m * d)`.
+pub fn terminal_speed(force: f32, mass: f32, linear_damping: f32, dt: f32) -> f32 {
+    let damping = mass * linear_damping;
+    if damping <= 0.0 {
+        return f32::INFINITY;
+    }
+
+    force * dt / damping
+}
+
+/// Advances a ship by `dt` seconds under the given controls.
+///
+/// This is the model the client's physics engine applies to the ships it flies: the `forces` of
+/// the controls are scaled by the step like the client scales them by the frame time, then the
+/// velocities are integrated and damped. A ship left alone slows down until it comes to rest,
+/// see `REST_SPEED`.
+pub fn step(
+    dynamics: &ShipDynamics,
+    state: &FlightState,
+    controls: &FlightControls,
+    power: Power,
+    dt: f32,
+) -> FlightState {
+    let (force, torque) = forces(dynamics, state.rotation, controls, power);
+    let (force, torque) = (force * dt, torque * dt);
+
+    let mut linear_velocity = (state.linear_velocity
+        + force / dynamics.mass.max(f32::EPSILON) * dt)
+        / (1.0 + dt * dynamics.linear_damping);
+    let mut angular_velocity = (state.angular_velocity + torque / dynamics.inertia() * dt)
+        / (1.0 + dt * dynamics.angular_damping);
+    if controls.is_idle()
+        && linear_velocity.length() < REST_SPEED
+        && angular_velocity.length() < REST_SPEED
+    {
+        linear_velocity = Vec3::ZERO;
+        angular_velocity = Vec3::ZERO;
+    }
+
+    let spin = Quat::from_xyzw(
+        angular_velocity.x,
+        angular_velocity.y,
+        angular_velocity.z,
+        0.0,
+    );
+    let rotation = (state.rotation + spin * state.rotation * (0.5 * dt)).normalize();
+
+    FlightState {
+        position: state.position + linear_velocity * dt,
+        rotation,
+        linear_velocity,
+        angular_velocity,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const DT: f32 = 0.05;
+
+    fn dynamics() -> ShipDynamics {
+        ShipDynamics {
+            mass: 100.0,
+            radius: 5.0,
+            linear_damping: 0.5,
+            angular_damping: 1.0,
+            thrust: 20_000.0,
+            vertical_thrust: 10_000.0,
+            lateral_thrust: 10_000.0,
+            pitch_torque: 50_000.0,
+            yaw_torque: 50_000.0,
+            roll_torque: 50_000.0,
+        }
+    }
+
+    fn controls() -> impl Strategy<Value = FlightControls> {
+        (
+            -2.0f32..2.0,
+            -2.0f32..2.0,
+            -2.0f32..2.0,
+            -2.0f32..2.0,
+            -2.0f32..2.0,
+            -2.0f32..2.0,
+            any::<bool>(),
+        )
+            .prop_map(
+                |(thrust, lateral_thrust, vertical_thrust, roll, pitch, yaw, boost)| {
+                    FlightControls {
+                        thrust,
+                        lateral_thrust,
+                        vertical_thrust,
+                        roll,
+                        pitch,
+                        yaw,
+                        boost,
+                    }
+                },
+            )
+    }
+
+    #[test]
+    fn clamped_controls_drop_non_finite_values() {
+        let controls = FlightControls {
+            thrust: f32::NAN,
+            lateral_thrust: f32::INFINITY,
+            vertical_thrust: 3.0,
+            roll: -3.0,
+            pitch: 0.5,
+            ..Default::default()
+        }
+        .clamped();
+
+        assert_eq!(controls.thrust, 0.0);
+        assert_eq!(controls.lateral_thrust, 0.0);
+        assert_eq!(controls.vertical_thrust, 1.0);
+        assert_eq!(controls.roll, -1.0);
+        assert_eq!(controls.pitch, 0.5);
+    }
+
+    #[test]
+    fn empty_tank_cuts_main_thrust() {
+        let power = Power::new(1.0, 1.0, false, 2.0);
+        assert_eq!(power.engines, 0.0);
+        assert_eq!(power.thrusters, EMPTY_TANK_RCS_FACTOR);
+        assert_eq!(Power::new(1.0, 1.0, true, 1.0), Power::FULL);
+    }
+
+    #[test]
+    fn thrust_pushes_the_ship_forward() {
+        let controls = FlightControls {
+            thrust: 1.0,
+            ..Default::default()
+        };
+        let state = FlightState::at_rest(Vec3::ZERO, Quat::IDENTITY);
+        let next = step(&dynamics(), &state, &controls, Power::FULL, DT);
+
+        assert!(next.position.z < 0.0);
+        assert_eq!(next.position.x, 0.0);
+        assert_eq!(next.position.y, 0.0);
+    }
+
+    #[test]
+    fn ship_at_rest_stays_at_rest() {
+        let state = FlightState::at_rest(Vec3::ONE, Quat::from_rotation_y(1.0));
+        let next = step(
+            &dynamics(),
+            &state,
+            &FlightControls::default(),
+            Power::FULL,
+            DT,
+        );
+        assert_eq!(next, state);
+    }
+
+    #[test]
+    fn speed_settles_at_terminal_speed() {
+        let dynamics = dynamics();
+        let controls = FlightControls {
+            thrust: 1.0,
+            ..Default::default()
+        };
+        let mut state = FlightState::at_rest(Vec3::ZERO, Quat::IDENTITY);
+        for _ in 0..2000 {
+            state = step(&dynamics, &state, &controls, Power::FULL, DT);
+        }
+
+        let terminal = terminal_speed(dynamics.thrust, dynamics.mass, dynamics.linear_damping, DT);
+        assert!((state.linear_velocity.length() - terminal).abs() < terminal * 1e-3);
+    }
+
+    #[test]
+    fn undamped_ships_have_no_terminal_speed() {
+        assert_eq!(terminal_speed(100.0, 10.0, 0.0, DT), f32::INFINITY);
+    }
+
+    #[test]
+    fn drifting_ship_comes_to_rest() {
+        let mut state = FlightState {
+            linear_velocity: Vec3::new(10.0, 0.0, 0.0),
+            angular_velocity: Vec3::new(0.0, 1.0, 0.0),
+            ..FlightState::at_rest(Vec3::ZERO, Quat::IDENTITY)
+        };
+        for _ in 0..10_000 {
+            state = step(
+                &dynamics(),
+                &state,
+                &FlightControls::default(),
+                Power::FULL,
+                DT,
+            );
+        }
+
+        assert_eq!(state.linear_velocity, Vec3::ZERO);
+        assert_eq!(state.angular_velocity, Vec3::ZERO);
+    }
+
+    proptest! {
+        #[test]
+        fn clamped_controls_stay_within_bounds(controls in controls()) {
+            let controls = controls.clamped();
+            for value in [
+                controls.thrust,
+                controls.lateral_thrust,
+                controls.vertical_thrust,
+                controls.roll,
+                controls.pitch,
+                controls.yaw,
+            ] {
+                prop_assert!((-1.0..=1.0).contains(&value));
+            }
+        }
+
+        #[test]
+        fn rotation_stays_normalized(controls in controls(), steps in 1usize..200) {
+            let controls = controls.clamped();
+            let mut state = FlightState::at_rest(Vec3::ZERO, Quat::IDENTITY);
+            for _ in 0..steps {
+                state = step(&dynamics(), &state, &controls, Power::FULL, DT);
+            }
+            prop_assert!(state.rotation.is_normalized());
+        }
+
+        #[test]
+        fn stepping_is_deterministic(controls in controls(), steps in 1usize..100) {
+            let controls = controls.clamped();
+            let mut a = FlightState::at_rest(Vec3::ZERO, Quat::IDENTITY);
+            let mut b = a;
+            for _ in 0..steps {
+                a = step(&dynamics(), &a, &controls, Power::FULL, DT);
+                b = step(&dynamics(), &b, &controls, Power::FULL, DT);
+            }
+            prop_assert_eq!(a, b);
+        }
+
+        #[test]
+        fn speed_never_exceeds_terminal_speed(controls in controls(), steps in 1usize..500) {
+            let controls = FlightControls {
+                boost: false,
+                ..controls.clamped()
+            };
+            let dynamics = dynamics();
+            let mut state = FlightState::at_rest(Vec3::ZERO, Quat::IDENTITY);
+            for _ in 0..steps {
+                state = step(&dynamics, &state, &controls, Power::FULL, DT);
+            }
+
+            let force = dynamics.thrust + dynamics.vertical_thrust + dynamics.lateral_thrust;
+            let terminal = terminal_speed(force, dynamics.mass, dynamics.linear_damping, DT);
+            prop_assert!(state.linear_velocity.length() <= terminal);
+        }
+
+        #[test]
+        fn idle_ships_slow_down(
+            vx in -100.0f32..100.0,
+            vy in -100.0f32..100.0,
+            vz in -100.0f32..100.0,
+        ) {
+            let state = FlightState {
+                linear_velocity: Vec3::new(vx, vy, vz),
+                ..FlightState::at_rest(Vec3::ZERO, Quat::IDENTITY)
+            };
+            let next = step(&dynamics(), &state, &FlightControls::default(), Power::FULL, DT);
+            prop_assert!(next.linear_velocity.length() <= state.linear_velocity.length());
+        }
+
+        #[test]
+        fn damage_weakens_thrust(engines in 0.0f32..1.0, thrusters in 0.0f32..1.0) {
+            let controls = FlightControls {
+                thrust: 1.0,
+                vertical_thrust: 1.0,
+                yaw: 1.0,
+                ..Default::default()
+            };
+            let (full_force, full_torque) =
+                forces(&dynamics(), Quat::IDENTITY, &controls, Power::FULL);
+            let (force, torque) = forces(
+                &dynamics(),
+                Quat::IDENTITY,
+                &controls,
+                Power::new(engines, thrusters, true, 1.0),
+            );
+            prop_assert!(force.length() <= full_force.length() + 1e-2);
+            prop_assert!(torque.length() <= full_torque.length() + 1e-2);
+        }
+    }
+}
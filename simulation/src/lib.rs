@@ -0,0 +1,5 @@
+pub mod damage;
+pub mod flight;
+pub mod ranges;
+pub mod sectors;
+pub mod stations;
@@ -0,0 +1,182 @@
+use glam::Vec3;
+
+/// Radius of the bounding sphere around a station, in world units.
+pub const STATION_BOUNDING_RADIUS: f32 = 650.0;
+
+/// How far from the center of a station, in world units, a ship can be to request docking or to
+/// trade with the station.
+pub const MAX_DOCKING_DISTANCE: f32 = STATION_BOUNDING_RADIUS + 300.0;
+
+/// How far, in world units, a ship can be from the surface of an asteroid to mine it.
+pub const MAX_MINING_DISTANCE: f32 = 150.0;
+
+/// How far from a ship, in world units, a player can be to enter it.
+pub const MAX_ENTER_SHIP_DISTANCE: f32 = 60.0;
+
+/// How far apart, in world units, two ships can be to transfer cargo between them.
+pub const MAX_TRANSFER_DISTANCE: f32 = 100.0;
+
+/// How far in front of a player, in world units, a ship is spawned given its exit offset: twice
+/// as far as the offset, so larger hulls don't spawn on top of the player.
+pub fn ship_spawn_distance(exit_offset: Vec3) -> f32 {
+    (exit_offset.length() * 2.0).max(20.0)
+}
+
+/// Checks that two points are at most `max` apart. Returns how far apart they are, or the
+/// distance in excess as an error.
+pub fn check_range(a: Vec3, b: Vec3, max: f32) -> Result<f32, f32> {
+    let distance = a.distance(b);
+    if distance > max {
+        return Err(distance);
+    }
+
+    Ok(distance)
+}
+
+/// Distance from a point to the surface of a sphere, 0 inside of it.
+pub fn distance_to_surface(point: Vec3, center: Vec3, radius: f32) -> f32 {
+    (point.distance(center) - radius).max(0.0)
+}
+
+/// Distance along a ray to where it enters a sphere, or `None` if it misses the sphere.
+///
+/// `direction` must be normalized. A ray starting inside the sphere hits it right away.
+pub fn ray_sphere_intersection(
+    origin: Vec3,
+    direction: Vec3,
+    center: Vec3,
+    radius: f32,
+) -> Option<f32> {
+    let to_center = center - origin;
+    let projection = to_center.dot(direction);
+    let distance_squared = to_center.length_squared() - projection * projection;
+    let radius_squared = radius * radius;
+    if distance_squared > radius_squared {
+        return None;
+    }
+
+    let half_chord = (radius_squared - distance_squared).sqrt();
+    let enter = projection - half_chord;
+    let exit = projection + half_chord;
+    if exit < 0.0 {
+        // The sphere is behind the ray.
+        return None;
+    }
+
+    Some(enter.max(0.0))
+}
+
+/// Point of the segment from `start` to `end` closest to `point`.
+pub fn closest_point_on_segment(start: Vec3, end: Vec3, point: Vec3) -> Vec3 {
+    let segment = end - start;
+    let t =
+        ((point - start).dot(segment) / segment.length_squared().max(f32::EPSILON)).clamp(0.0, 1.0);
+    start + segment * t
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn vec3() -> impl Strategy<Value = Vec3> {
+        (-1000.0f32..1000.0, -1000.0f32..1000.0, -1000.0f32..1000.0)
+            .prop_map(|(x, y, z)| Vec3::new(x, y, z))
+    }
+
+    #[test]
+    fn range_is_inclusive() {
+        assert_eq!(check_range(Vec3::ZERO, Vec3::X * 100.0, 100.0), Ok(100.0));
+        assert_eq!(check_range(Vec3::ZERO, Vec3::X * 101.0, 100.0), Err(101.0));
+    }
+
+    #[test]
+    fn small_hulls_spawn_at_a_minimum_distance() {
+        assert_eq!(ship_spawn_distance(Vec3::X * 45.0), 90.0);
+        assert_eq!(ship_spawn_distance(Vec3::X * 5.0), 20.0);
+    }
+
+    #[test]
+    fn surface_distance_is_zero_inside() {
+        assert_eq!(
+            distance_to_surface(Vec3::X * 150.0, Vec3::ZERO, 100.0),
+            50.0
+        );
+        assert_eq!(distance_to_surface(Vec3::X * 50.0, Vec3::ZERO, 100.0), 0.0);
+    }
+
+    #[test]
+    fn ray_hits_sphere_ahead() {
+        let hit = ray_sphere_intersection(Vec3::ZERO, Vec3::NEG_Z, Vec3::NEG_Z * 100.0, 10.0);
+        assert_eq!(hit, Some(90.0));
+    }
+
+    #[test]
+    fn ray_misses_sphere_behind_or_aside() {
+        assert_eq!(
+            ray_sphere_intersection(Vec3::ZERO, Vec3::NEG_Z, Vec3::Z * 100.0, 10.0),
+            None
+        );
+        assert_eq!(
+            ray_sphere_intersection(Vec3::ZERO, Vec3::NEG_Z, Vec3::new(20.0, 0.0, -100.0), 10.0),
+            None
+        );
+    }
+
+    #[test]
+    fn ray_from_inside_hits_right_away() {
+        assert_eq!(
+            ray_sphere_intersection(Vec3::ZERO, Vec3::NEG_Z, Vec3::X, 10.0),
+            Some(0.0)
+        );
+    }
+
+    #[test]
+    fn closest_point_is_clamped_to_the_segment() {
+        let (start, end) = (Vec3::ZERO, Vec3::X * 10.0);
+        assert_eq!(
+            closest_point_on_segment(start, end, Vec3::new(5.0, 3.0, 0.0)),
+            Vec3::X * 5.0
+        );
+        assert_eq!(closest_point_on_segment(start, end, Vec3::X * -5.0), start);
+        assert_eq!(closest_point_on_segment(start, end, Vec3::X * 15.0), end);
+        assert_eq!(closest_point_on_segment(start, start, Vec3::Y), start);
+    }
+
+    proptest! {
+        #[test]
+        fn range_check_is_symmetric(a in vec3(), b in vec3(), max in 0.0f32..2000.0) {
+            prop_assert_eq!(check_range(a, b, max).is_ok(), check_range(b, a, max).is_ok());
+        }
+
+        #[test]
+        fn ray_hits_land_on_the_sphere(
+            origin in vec3(),
+            direction in vec3(),
+            center in vec3(),
+            radius in 1.0f32..500.0,
+        ) {
+            prop_assume!(direction.length() > 1.0);
+            let direction = direction.normalize();
+            prop_assume!(origin.distance(center) > radius);
+
+            if let Some(distance) = ray_sphere_intersection(origin, direction, center, radius) {
+                let hit = origin + direction * distance;
+                prop_assert!((hit.distance(center) - radius).abs() < 0.05);
+            }
+        }
+
+        #[test]
+        fn closest_point_is_no_farther_than_the_ends(
+            start in vec3(),
+            end in vec3(),
+            point in vec3(),
+        ) {
+            let closest = closest_point_on_segment(start, end, point);
+            let distance = closest.distance(point);
+            prop_assert!(distance <= start.distance(point) + 1e-2);
+            prop_assert!(distance <= end.distance(point) + 1e-2);
+        }
+    }
+}
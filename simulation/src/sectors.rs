@@ -0,0 +1,34 @@
+use glam::{IVec3, Vec3};
+
+/// Edge length, in world units, of the cubic sectors the world is divided into.
+///
+/// Rows with a location carry the coordinates of the sector they're in, so clients can subscribe
+/// only to the sectors around them.
+pub const SECTOR_SIZE: f32 = 2000.0;
+
+/// Coordinates of the sector containing the given position.
+pub fn sector_of(position: Vec3) -> IVec3 {
+    (position / SECTOR_SIZE).floor().as_ivec3()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sectors_start_at_multiples_of_their_size() {
+        assert_eq!(sector_of(Vec3::ZERO), IVec3::ZERO);
+        assert_eq!(sector_of(Vec3::splat(SECTOR_SIZE - 1.0)), IVec3::ZERO);
+        assert_eq!(sector_of(Vec3::new(SECTOR_SIZE, 0.0, 0.0)), IVec3::X);
+    }
+
+    #[test]
+    fn negative_positions_round_down() {
+        assert_eq!(sector_of(Vec3::new(-1.0, 0.0, 0.0)), IVec3::NEG_X);
+        assert_eq!(sector_of(Vec3::new(0.0, -SECTOR_SIZE, 0.0)), IVec3::NEG_Y);
+        assert_eq!(
+            sector_of(Vec3::new(0.0, 0.0, -SECTOR_SIZE - 1.0)),
+            IVec3::new(0, 0, -2)
+        );
+    }
+}
@@ -0,0 +1,221 @@
+use std::f64::consts::TAU;
+
+use glam::{Quat, Vec3};
+
+/// Axis stations rotate around unless they're given another one.
+pub const DEFAULT_ROTATION_AXIS: Vec3 = Vec3::Y;
+
+/// How a station rotates: it was at `epoch_angle` around its axis at `epoch`, in microseconds
+/// since the Unix epoch, and turns `speed` radians per second. Its orientation at any time follows
+/// from these, without anything to update as time passes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StationRotation {
+    pub epoch: i64,
+    pub epoch_angle: f32,
+    pub speed: f32,
+    /// Axis the station rotates around, `DEFAULT_ROTATION_AXIS` if `None`. It doesn't need to be
+    /// normalized.
+    pub axis: Option<Vec3>,
+}
+
+impl StationRotation {
+    /// The rotation of a station as it used to be kept, before it had an epoch: it reaches
+    /// `target_angle` around the vertical axis at `reach_angle_at`, in milliseconds since the
+    /// Unix epoch, turning `speed` radians per second.
+    pub fn from_target(target_angle: f32, speed: f32, reach_angle_at: u128) -> Self {
+        Self {
+            epoch: reach_angle_at as i64 * 1000,
+            epoch_angle: target_angle,
+            speed,
+            axis: None,
+        }
+    }
+
+    /// Axis the station rotates around, normalized. A degenerate axis falls back to
+    /// `DEFAULT_ROTATION_AXIS`.
+    pub fn axis(&self) -> Vec3 {
+        self.axis
+            .and_then(Vec3::try_normalize)
+            .unwrap_or(DEFAULT_ROTATION_AXIS)
+    }
+
+    /// Angle of the station around its axis at `now`, in microseconds since the Unix epoch,
+    /// between 0 and a full turn.
+    pub fn angle_at(&self, now: i64) -> f32 {
+        // In double precision, for stations that have been turning for a long time.
+        let elapsed = (now - self.epoch) as f64 / 1_000_000.0;
+        (self.epoch_angle as f64 + self.speed as f64 * elapsed).rem_euclid(TAU) as f32
+    }
+
+    /// Orientation of the station at `now`, in microseconds since the Unix epoch.
+    pub fn orientation_at(&self, now: i64) -> Quat {
+        Quat::from_axis_angle(self.axis(), self.angle_at(now))
+    }
+
+    /// Angular velocity of the station, in radians per second around each world axis.
+    pub fn angular_velocity(&self) -> Vec3 {
+        self.axis() * self.speed
+    }
+
+    /// The same rotation, described from a new epoch. Stations keep turning exactly as before.
+    pub fn rebased(&self, epoch: i64) -> Self {
+        Self {
+            epoch,
+            epoch_angle: self.angle_at(epoch),
+            ..*self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::{FRAC_PI_2, TAU};
+
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const SECOND: i64 = 1_000_000;
+
+    fn rotation(epoch_angle: f32, speed: f32) -> StationRotation {
+        StationRotation {
+            epoch: 5 * SECOND,
+            epoch_angle,
+            speed,
+            axis: None,
+        }
+    }
+
+    fn axis() -> impl Strategy<Value = Option<Vec3>> {
+        proptest::option::of(
+            (-1.0f32..1.0, -1.0f32..1.0, -1.0f32..1.0).prop_map(|(x, y, z)| Vec3::new(x, y, z)),
+        )
+    }
+
+    #[test]
+    fn station_is_at_its_epoch_angle_at_its_epoch() {
+        assert_eq!(rotation(1.0, 0.1).angle_at(5 * SECOND), 1.0);
+    }
+
+    #[test]
+    fn station_angle_extrapolates_both_ways() {
+        assert!((rotation(1.0, 0.1).angle_at(3 * SECOND) - 0.8).abs() < 1e-6);
+        assert!((rotation(1.0, 0.1).angle_at(7 * SECOND) - 1.2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn station_angle_wraps_around() {
+        assert!((rotation(0.1, 0.1).angle_at(3 * SECOND) - (TAU - 0.1)).abs() < 1e-5);
+    }
+
+    #[test]
+    fn station_angle_stays_precise_after_years() {
+        let rotation = rotation(0.0, 0.01);
+        let year = 365 * 24 * 3600 * SECOND;
+        let turns = 0.01f32 as f64 * (year / SECOND) as f64 / std::f64::consts::TAU;
+        let expected = (turns.fract() * std::f64::consts::TAU) as f32;
+        assert!((rotation.angle_at(5 * SECOND + year) - expected).abs() < 1e-4);
+    }
+
+    #[test]
+    fn stations_turn_around_the_vertical_axis_by_default() {
+        let rotation = rotation(FRAC_PI_2, 0.0);
+        assert!(
+            rotation
+                .orientation_at(0)
+                .abs_diff_eq(Quat::from_rotation_y(FRAC_PI_2), 1e-6)
+        );
+        assert_eq!(rotation.angular_velocity(), Vec3::ZERO);
+        assert_eq!(
+            StationRotation {
+                speed: 0.5,
+                ..rotation
+            }
+            .angular_velocity(),
+            Vec3::Y * 0.5
+        );
+    }
+
+    #[test]
+    fn stations_kept_by_target_reach_it_when_expected() {
+        let rotation = StationRotation::from_target(1.0, 0.1, 7_000);
+        assert!((rotation.angle_at(7 * SECOND) - 1.0).abs() < 1e-6);
+        assert!((rotation.angle_at(5 * SECOND) - 0.8).abs() < 1e-6);
+        assert_eq!(rotation.axis(), DEFAULT_ROTATION_AXIS);
+    }
+
+    #[test]
+    fn degenerate_axes_fall_back_to_the_default() {
+        let rotation = StationRotation {
+            axis: Some(Vec3::ZERO),
+            ..rotation(0.0, 0.1)
+        };
+        assert_eq!(rotation.axis(), DEFAULT_ROTATION_AXIS);
+        let rotation = StationRotation {
+            axis: Some(Vec3::X * 3.0),
+            ..rotation
+        };
+        assert_eq!(rotation.axis(), Vec3::X);
+    }
+
+    proptest! {
+        #[test]
+        fn station_angle_stays_within_a_turn(
+            epoch_angle in -10.0f32..10.0,
+            speed in -1.0f32..1.0,
+            epoch in 0i64..10_000_000_000,
+            now in 0i64..10_000_000_000,
+        ) {
+            let angle = StationRotation { epoch, ..rotation(epoch_angle, speed) }.angle_at(now);
+            prop_assert!((0.0..=TAU).contains(&angle));
+        }
+
+        #[test]
+        fn station_angle_only_depends_on_time_since_epoch(
+            epoch_angle in 0.0f32..TAU,
+            speed in -1.0f32..1.0,
+            elapsed in -100_000_000i64..100_000_000,
+            shift in 0i64..1_000_000_000_000,
+        ) {
+            let rotation = rotation(epoch_angle, speed);
+            let shifted = StationRotation { epoch: rotation.epoch + shift, ..rotation };
+            prop_assert_eq!(
+                rotation.angle_at(rotation.epoch + elapsed),
+                shifted.angle_at(shifted.epoch + elapsed)
+            );
+        }
+
+        #[test]
+        fn rebasing_keeps_the_rotation(
+            epoch_angle in 0.0f32..TAU,
+            speed in -1.0f32..1.0,
+            axis in axis(),
+            new_epoch in 0i64..1_000_000_000,
+            now in 0i64..1_000_000_000,
+        ) {
+            let rotation = StationRotation { axis, ..rotation(epoch_angle, speed) };
+            let rebased = rotation.rebased(new_epoch);
+            prop_assert!(
+                rotation
+                    .orientation_at(now)
+                    .abs_diff_eq(rebased.orientation_at(now), 1e-3)
+                    || rotation
+                        .orientation_at(now)
+                        .abs_diff_eq(-rebased.orientation_at(now), 1e-3)
+            );
+        }
+
+        #[test]
+        fn orientation_is_normalized_and_keeps_the_axis(
+            epoch_angle in 0.0f32..TAU,
+            speed in -1.0f32..1.0,
+            axis in axis(),
+            now in 0i64..1_000_000_000,
+        ) {
+            let rotation = StationRotation { axis, ..rotation(epoch_angle, speed) };
+            let orientation = rotation.orientation_at(now);
+            prop_assert!(orientation.is_normalized());
+            prop_assert!((orientation * rotation.axis()).abs_diff_eq(rotation.axis(), 1e-4));
+        }
+    }
+}